@@ -3,6 +3,12 @@ use clap::CommandFactory;
 include!("src/cli.rs");
 
 fn main() {
+    // The updater downloads the release archive named after this target
+    // (`tr300-<target>.tar.xz`, `.zip` on Windows).
+    if let Ok(target) = std::env::var("TARGET") {
+        println!("cargo:rustc-env=TR300_BUILD_TARGET={}", target);
+    }
+
     let out_dir = match std::env::var("OUT_DIR") {
         Ok(dir) => std::path::PathBuf::from(dir),
         Err(_) => return,
@@ -0,0 +1,74 @@
+#!/usr/bin/env bash
+# Sign release assets with the TR-300 minisign key before they are uploaded.
+#
+# Writes `<asset>.minisig` beside every asset given on the command line
+# (`.sha256` and `.minisig` files are skipped), then verifies each signature
+# against TR300_RELEASE_PUBLIC_KEY, the key the release binaries embed. The
+# updater refuses unsigned downloads, so a missing key or a signature that
+# does not verify fails the release instead of publishing it.
+#
+# Required environment variables are GitHub Actions secrets/variables. Never
+# print them or persist the secret key outside the ephemeral work directory.
+
+set -euo pipefail
+
+if [[ $# -eq 0 ]]; then
+    echo "usage: $0 <asset>..." >&2
+    exit 64
+fi
+
+required_vars=(
+    TR300_MINISIGN_SECRET_KEY
+    TR300_MINISIGN_PASSWORD
+    TR300_RELEASE_PUBLIC_KEY
+)
+for name in "${required_vars[@]}"; do
+    if [[ -z ${!name:-} ]]; then
+        echo "required release signing credential is unavailable: $name" >&2
+        exit 78
+    fi
+done
+
+if ! command -v minisign >/dev/null 2>&1; then
+    echo "minisign is not installed" >&2
+    exit 69
+fi
+
+# The variable may hold the bare key line or the whole minisign.pub file.
+public_key=$(grep -v '^untrusted comment:' <<< "$TR300_RELEASE_PUBLIC_KEY" | grep -m 1 -v '^[[:space:]]*$' || true)
+if [[ -z $public_key ]]; then
+    echo "TR300_RELEASE_PUBLIC_KEY does not contain a minisign public key" >&2
+    exit 78
+fi
+
+runner_temp=${RUNNER_TEMP:-${TMPDIR:-/tmp}}
+work_dir=$(mktemp -d "${runner_temp%/}/tr300-minisign.XXXXXX")
+chmod 700 "$work_dir"
+trap 'rm -rf "$work_dir"' EXIT
+secret_key="${work_dir}/tr300.key"
+(umask 077 && printf '%s\n' "$TR300_MINISIGN_SECRET_KEY" > "$secret_key")
+
+signed=0
+for asset in "$@"; do
+    case "$asset" in
+        *.sha256 | *.minisig) continue ;;
+    esac
+    if [[ ! -f $asset ]]; then
+        echo "release asset not found: $asset" >&2
+        exit 66
+    fi
+    name=$(basename "$asset")
+    printf '%s\n' "$TR300_MINISIGN_PASSWORD" |
+        minisign -S -s "$secret_key" -m "$asset" -t "tr300 release asset ${name}" >/dev/null
+    if ! minisign -V -q -P "$public_key" -m "$asset"; then
+        echo "signature for $name does not verify against TR300_RELEASE_PUBLIC_KEY" >&2
+        exit 1
+    fi
+    echo "signed $name"
+    signed=$((signed + 1))
+done
+
+if [[ $signed -eq 0 ]]; then
+    echo "no release assets were signed" >&2
+    exit 66
+fi
@@ -0,0 +1,481 @@
+//! Threshold health check (`tr300 check`)
+//!
+//! Evaluates collected metrics against [`Thresholds`] and reports the result
+//! the way Nagios/Icinga plugins do: one summary line with performance data,
+//! an optional line per metric, and exit code 0 (OK), 1 (WARNING),
+//! 2 (CRITICAL) or 3 (UNKNOWN).
+
+use crate::collectors::platform::zfs_rank;
+use crate::collectors::{CollectionError, SystemInfo};
+use crate::config::{Threshold, ThresholdLevel, Thresholds};
+
+/// Overall or per-metric check result
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+impl Status {
+    /// Nagios plugin exit code
+    pub fn exit_code(self) -> i32 {
+        match self {
+            Self::Ok => 0,
+            Self::Warning => 1,
+            Self::Critical => 2,
+            Self::Unknown => 3,
+        }
+    }
+
+    /// Upper-case label used in the summary line
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::Warning => "WARNING",
+            Self::Critical => "CRITICAL",
+            Self::Unknown => "UNKNOWN",
+        }
+    }
+
+    /// Aggregation order: CRITICAL > WARNING > UNKNOWN > OK
+    fn severity(self) -> u8 {
+        match self {
+            Self::Ok => 0,
+            Self::Unknown => 1,
+            Self::Warning => 2,
+            Self::Critical => 3,
+        }
+    }
+}
+
+impl From<ThresholdLevel> for Status {
+    fn from(level: ThresholdLevel) -> Self {
+        match level {
+            ThresholdLevel::Ok => Self::Ok,
+            ThresholdLevel::Warning => Self::Warning,
+            ThresholdLevel::Critical => Self::Critical,
+        }
+    }
+}
+
+/// One evaluated metric
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    /// Metric name, also the perfdata label (`disk`, `zfs`, `reboot`, ...)
+    pub metric: &'static str,
+    pub status: Status,
+    /// Human-readable value, e.g. `91.2%` or `DEGRADED`
+    pub summary: String,
+    /// Numeric value and the limits it was held to, for perfdata
+    pub value: Option<f64>,
+    pub threshold: Option<Threshold>,
+}
+
+/// Result of evaluating every applicable metric
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckReport {
+    pub status: Status,
+    pub findings: Vec<Finding>,
+}
+
+/// Evaluate `info` against `thresholds`. Metrics this host cannot report
+/// (no swap, no battery, no ZFS, no load average on Windows) are skipped;
+/// a core metric that came back empty, or whose collector failed, is UNKNOWN.
+pub fn evaluate(info: &SystemInfo, thresholds: &Thresholds) -> CheckReport {
+    let mut findings = Vec::new();
+    let failed = |collector: &str| {
+        info.collection_errors
+            .iter()
+            .find(|error| error.collector == collector)
+    };
+
+    findings.push(match failed("disk") {
+        Some(error) => collector_failure("disk", error, thresholds.disk),
+        None => percent_finding(
+            "disk",
+            (info.disk_total_bytes > 0).then_some(info.disk_percent),
+            thresholds.disk,
+        ),
+    });
+    // Swap is read by the memory collector too; its failure is reported once.
+    match failed("memory") {
+        Some(error) => findings.push(collector_failure("memory", error, thresholds.memory)),
+        None => {
+            findings.push(percent_finding(
+                "memory",
+                (info.mem_total_bytes > 0).then_some(info.mem_percent),
+                thresholds.memory,
+            ));
+            if info.swap_total_bytes > 0 {
+                findings.push(percent_finding(
+                    "swap",
+                    Some(info.swap_percent),
+                    thresholds.swap,
+                ));
+            }
+        }
+    }
+    match (failed("cpu"), info.load_5m) {
+        (Some(error), _) => findings.push(collector_failure("load", error, thresholds.load)),
+        (None, Some(load)) => findings.push(percent_finding("load", Some(load), thresholds.load)),
+        (None, None) => {}
+    }
+    if let Some(health) = info.battery.as_deref().and_then(battery_health_percent) {
+        findings.push(percent_finding("battery", Some(health), thresholds.battery));
+    }
+    if let Some(ref state) = info.zfs_health {
+        let status = match zfs_rank(state) {
+            1 => Status::Ok,
+            2 | 3 => Status::Warning,
+            _ => Status::Critical,
+        };
+        findings.push(Finding {
+            metric: "zfs",
+            status,
+            summary: state.clone(),
+            value: None,
+            threshold: None,
+        });
+    }
+    if let Some(pending) = info.pending_reboot {
+        findings.push(Finding {
+            metric: "reboot",
+            status: if pending { Status::Warning } else { Status::Ok },
+            summary: if pending { "required" } else { "not required" }.to_string(),
+            value: Some(if pending { 1.0 } else { 0.0 }),
+            threshold: None,
+        });
+    }
+
+    let status = findings
+        .iter()
+        .map(|finding| finding.status)
+        .max_by_key(|status| status.severity())
+        .unwrap_or(Status::Ok);
+    CheckReport { status, findings }
+}
+
+fn percent_finding(metric: &'static str, value: Option<f64>, threshold: Threshold) -> Finding {
+    match value.filter(|v| v.is_finite()) {
+        Some(value) => Finding {
+            metric,
+            status: threshold.level(value).into(),
+            summary: format!("{:.1}%", value),
+            value: Some(value),
+            threshold: Some(threshold),
+        },
+        None => Finding {
+            metric,
+            status: Status::Unknown,
+            summary: "not measured".to_string(),
+            value: None,
+            threshold: Some(threshold),
+        },
+    }
+}
+
+/// UNKNOWN for a metric whose collector failed, naming the error.
+fn collector_failure(
+    metric: &'static str,
+    error: &CollectionError,
+    threshold: Threshold,
+) -> Finding {
+    Finding {
+        metric,
+        status: Status::Unknown,
+        summary: format!("{} collector failed: {}", error.collector, error.message),
+        value: None,
+        threshold: Some(threshold),
+    }
+}
+
+/// Health percentage from a battery summary: `health 80%` (Linux) or
+/// `max 87%` (macOS).
+pub(crate) fn battery_health_percent(battery: &str) -> Option<f64> {
+    let (_, details) = battery.split_once("; ")?;
+    details.split(", ").find_map(|part| {
+        let part = part.trim();
+        part.strip_prefix("health ")
+            .or_else(|| part.strip_prefix("max "))?
+            .strip_suffix('%')?
+            .trim()
+            .parse()
+            .ok()
+    })
+}
+
+/// Plugin output: the summary line with perfdata, then one line per metric.
+pub fn render(report: &CheckReport) -> String {
+    let problems: Vec<String> = report
+        .findings
+        .iter()
+        .filter(|finding| finding.status != Status::Ok)
+        .map(|finding| format!("{} {}", finding.metric, finding.summary))
+        .collect();
+    let text = if problems.is_empty() {
+        format!("{} metrics within thresholds", report.findings.len())
+    } else {
+        problems.join(", ")
+    };
+    let mut output = format!("TR300 {} - {}", report.status.label(), text);
+    let perfdata = perfdata(report);
+    if !perfdata.is_empty() {
+        output.push_str(" | ");
+        output.push_str(&perfdata);
+    }
+    output.push('\n');
+    for finding in &report.findings {
+        output.push_str(&format!(
+            "[{}] {}: {}",
+            finding.status.label(),
+            finding.metric,
+            finding.summary
+        ));
+        if let Some(threshold) = finding.threshold {
+            output.push_str(&format!(
+                " (warn {}, crit {})",
+                limit(threshold.lower_is_worse, threshold.warn),
+                limit(threshold.lower_is_worse, threshold.crit)
+            ));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+fn limit(lower_is_worse: bool, value: f64) -> String {
+    format!("{}{}%", if lower_is_worse { "<" } else { ">" }, value)
+}
+
+/// `label=value[UOM];warn;crit;min;max` for each numeric metric. A floor
+/// threshold is written as the Nagios range `N:` (alert below N).
+fn perfdata(report: &CheckReport) -> String {
+    report
+        .findings
+        .iter()
+        .filter_map(|finding| {
+            let value = finding.value?;
+            Some(match finding.threshold {
+                Some(t) if t.lower_is_worse => format!(
+                    "{}={:.1}%;{}:;{}:;0;100",
+                    finding.metric, value, t.warn, t.crit
+                ),
+                // Load can exceed 100% of CPU capacity, so it has no max.
+                Some(t) if finding.metric == "load" => {
+                    format!("{}={:.1}%;{};{};0", finding.metric, value, t.warn, t.crit)
+                }
+                Some(t) => format!(
+                    "{}={:.1}%;{};{};0;100",
+                    finding.metric, value, t.warn, t.crit
+                ),
+                None => format!("{}={};;;0;1", finding.metric, value),
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// `--json` form of a check result
+pub fn json(report: &CheckReport) -> serde_json::Value {
+    serde_json::json!({
+        "status": report.status.label(),
+        "exit_code": report.status.exit_code(),
+        "checks": report.findings.iter().map(|finding| serde_json::json!({
+            "metric": finding.metric,
+            "status": finding.status.label(),
+            "summary": finding.summary,
+            "value": finding.value,
+            "warn": finding.threshold.map(|t| t.warn),
+            "crit": finding.threshold.map(|t| t.crit),
+            "lower_is_worse": finding.threshold.map(|t| t.lower_is_worse),
+        })).collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::tests::fixture_info;
+
+    fn info() -> SystemInfo {
+        let mut info = fixture_info();
+        info.disk_total_bytes = 100;
+        info.disk_percent = 40.0;
+        info.mem_total_bytes = 100;
+        info.mem_percent = 50.0;
+        info.swap_total_bytes = 0;
+        info.load_5m = Some(20.0);
+        info.battery = None;
+        info.zfs_health = None;
+        info.pending_reboot = None;
+        info
+    }
+
+    fn status_of(report: &CheckReport, metric: &str) -> Status {
+        report
+            .findings
+            .iter()
+            .find(|finding| finding.metric == metric)
+            .map(|finding| finding.status)
+            .unwrap()
+    }
+
+    #[test]
+    fn healthy_host_is_ok_and_skips_absent_metrics() {
+        let report = evaluate(&info(), &Thresholds::default());
+        assert_eq!(report.status, Status::Ok);
+        let metrics: Vec<_> = report.findings.iter().map(|f| f.metric).collect();
+        assert_eq!(metrics, ["disk", "memory", "load"]);
+        let output = render(&report);
+        assert!(
+            output.starts_with("TR300 OK - 3 metrics within thresholds | disk=40.0%;80;90;0;100")
+        );
+        assert!(output.contains(" load=20.0%;100;200;0\n[OK] disk: 40.0% (warn >80%, crit >90%)\n"));
+    }
+
+    #[test]
+    fn worst_finding_sets_the_exit_code() {
+        let mut info = info();
+        info.disk_percent = 91.2;
+        info.pending_reboot = Some(true);
+        let report = evaluate(&info, &Thresholds::default());
+        assert_eq!(status_of(&report, "disk"), Status::Critical);
+        assert_eq!(status_of(&report, "reboot"), Status::Warning);
+        assert_eq!(report.status.exit_code(), 2);
+        assert!(render(&report).starts_with("TR300 CRITICAL - disk 91.2%, reboot required | "));
+
+        info.disk_percent = 90.0;
+        let report = evaluate(&info, &Thresholds::default());
+        assert_eq!(report.status, Status::Warning);
+        info.disk_percent = 80.0;
+        info.pending_reboot = Some(false);
+        assert_eq!(evaluate(&info, &Thresholds::default()).status, Status::Ok);
+    }
+
+    #[test]
+    fn unmeasured_core_metric_is_unknown_but_never_masks_warnings() {
+        let mut info = info();
+        info.mem_total_bytes = 0;
+        let report = evaluate(&info, &Thresholds::default());
+        assert_eq!(report.status, Status::Unknown);
+        assert_eq!(report.status.exit_code(), 3);
+
+        info.disk_percent = 85.0;
+        assert_eq!(
+            evaluate(&info, &Thresholds::default()).status,
+            Status::Warning
+        );
+    }
+
+    #[test]
+    fn failed_collector_makes_its_metrics_unknown() {
+        let mut info = info();
+        info.load_5m = None;
+        info.swap_total_bytes = 100;
+        info.swap_percent = 10.0;
+        info.collection_errors = vec![
+            CollectionError {
+                collector: "cpu".to_string(),
+                message: "sysinfo refresh failed".to_string(),
+                elapsed: std::time::Duration::from_millis(3),
+            },
+            CollectionError {
+                collector: "memory".to_string(),
+                message: "meminfo unreadable".to_string(),
+                elapsed: std::time::Duration::from_millis(1),
+            },
+        ];
+        let report = evaluate(&info, &Thresholds::default());
+        assert_eq!(report.status, Status::Unknown);
+        assert_eq!(report.status.exit_code(), 3);
+
+        let metrics: Vec<(&str, Status)> = report
+            .findings
+            .iter()
+            .map(|f| (f.metric, f.status))
+            .collect();
+        assert_eq!(
+            metrics,
+            [
+                ("disk", Status::Ok),
+                ("memory", Status::Unknown),
+                ("load", Status::Unknown),
+            ]
+        );
+        let output = render(&report);
+        assert!(output.contains("[UNKNOWN] load: cpu collector failed: sysinfo refresh failed"));
+        assert!(output.contains("memory memory collector failed: meminfo unreadable"));
+    }
+
+    #[test]
+    fn zfs_state_follows_pool_severity() {
+        let mut info = info();
+        for (state, expected) in [
+            ("ONLINE", Status::Ok),
+            ("DEGRADED", Status::Warning),
+            ("FAULTED", Status::Critical),
+            ("SUSPENDED", Status::Critical),
+        ] {
+            info.zfs_health = Some(state.to_string());
+            let report = evaluate(&info, &Thresholds::default());
+            assert_eq!(status_of(&report, "zfs"), expected, "{}", state);
+        }
+    }
+
+    #[test]
+    fn battery_health_is_read_from_either_summary_format() {
+        assert_eq!(
+            battery_health_percent("73% (Discharging); health 80%"),
+            Some(80.0)
+        );
+        assert_eq!(
+            battery_health_percent("49% (Discharging); Good, max 88%, 114 cycles"),
+            Some(88.0)
+        );
+        assert_eq!(battery_health_percent("100% (Full)"), None);
+
+        let mut info = info();
+        info.battery = Some("73% (Discharging); health 55%".to_string());
+        let report = evaluate(&info, &Thresholds::default());
+        assert_eq!(status_of(&report, "battery"), Status::Critical);
+        assert!(render(&report).contains("battery=55.0%;80:;60:;0;100"));
+    }
+
+    #[test]
+    fn threshold_specs_override_only_named_metrics() {
+        let thresholds = Thresholds::default()
+            .with_spec("disk=85:95, battery=70%:50%")
+            .unwrap();
+        assert_eq!(thresholds.disk, Threshold::new(85.0, 95.0));
+        assert_eq!(thresholds.battery, Threshold::floor(70.0, 50.0));
+        assert_eq!(thresholds.memory, Thresholds::default().memory);
+
+        for bad in [
+            "disk",
+            "disk=90",
+            "disk=x:90",
+            "cpu=1:2",
+            "disk=95:85",
+            "battery=50:70",
+        ] {
+            assert!(Thresholds::default().with_spec(bad).is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn json_mirrors_the_findings() {
+        let mut info = info();
+        info.swap_total_bytes = 10;
+        info.swap_percent = 60.0;
+        let value = json(&evaluate(&info, &Thresholds::default()));
+        assert_eq!(value["status"], "WARNING");
+        assert_eq!(value["exit_code"], 1);
+        assert_eq!(value["checks"][2]["metric"], "swap");
+        assert_eq!(value["checks"][2]["warn"], 50.0);
+    }
+}
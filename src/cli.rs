@@ -16,6 +16,11 @@ pub enum Action {
     Update,
     Install,
     Uninstall,
+    // Threshold health check with Nagios exit codes. Left without a doc
+    // comment so `--help` keeps the compact possible-values line.
+    Check,
+    // Policy assertions from a TOML file given as the next argument.
+    Assert,
     /// Cross-method install cleanup. HIDDEN — invoked by the Windows installers
     /// (and the silent self-update path) to consolidate to a single install:
     /// remove a shadowing older `cargo install` copy and/or the other Windows
@@ -35,6 +40,11 @@ pub enum Action {
     /// parent invokes this through ShellExecuteExW with the `runas` verb.
     #[value(hide = true)]
     UpdateWorker,
+    /// Refresh the cached latest-release version behind `--update-notice`.
+    /// HIDDEN — spawned detached by a report whose cached check has expired,
+    /// so the report itself never waits on the network.
+    #[value(hide = true)]
+    UpdateNoticeRefresh,
 }
 
 /// TR-300: Cross-platform system information report
@@ -52,10 +62,14 @@ pub enum Action {
     After installation with --install, you can also use the 'report' alias."
 )]
 pub struct Cli {
-    /// Optional action command: update, install, or uninstall
+    /// Optional action command: update, install, uninstall, check, or assert
     #[arg(value_enum, conflicts_with_all = ["update", "install", "uninstall"])]
     pub action: Option<Action>,
 
+    /// With assert: policy file (TOML) declaring the facts this machine must have
+    #[arg(value_name = "POLICY", requires = "action")]
+    pub policy: Option<std::path::PathBuf>,
+
     /// Use ASCII characters instead of Unicode box-drawing
     #[arg(long)]
     pub ascii: bool,
@@ -64,6 +78,35 @@ pub struct Cli {
     #[arg(long)]
     pub json: bool,
 
+    /// Collect and show only these sections, comma-separated: os, system, network, cpu, disk, memory, session, services, time, containers
+    #[arg(
+        long,
+        value_name = "SECTIONS",
+        conflicts_with_all = ["action", "update", "install", "uninstall"]
+    )]
+    pub fields: Option<String>,
+
+    /// Print one value by its JSON path, e.g. memory.total_bytes (collects only that section)
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = [
+            "fields",
+            "json",
+            "format",
+            "save_report",
+            "action",
+            "update",
+            "install",
+            "uninstall"
+        ]
+    )]
+    pub get: Option<String>,
+
+    /// Output format: table (default), json, html (a self-contained page), env (TR300_*=value lines), csv, or yaml
+    #[arg(long, value_name = "FORMAT", conflicts_with = "json")]
+    pub format: Option<String>,
+
     /// Install tr300 to shell profile (adds 'report' alias and auto-run)
     #[arg(long, conflicts_with_all = ["update", "uninstall", "action"])]
     pub install: bool,
@@ -92,6 +135,112 @@ pub struct Cli {
     #[arg(long)]
     pub no_elevation_hint: bool,
 
+    /// List the N heaviest processes by CPU and by memory (ignored with --fast)
+    #[arg(long = "top", value_name = "N", env = "TR300_TOP")]
+    pub top: Option<usize>,
+
+    /// Auto-run trigger written by install: always, ssh, login, or an interval like 6h
+    #[arg(long = "autorun", value_name = "WHEN", env = "TR300_AUTORUN")]
+    pub autorun: Option<String>,
+
+    /// Flags the installed auto-run passes to tr300 (default: --fast)
+    #[arg(
+        long = "autorun-args",
+        value_name = "FLAGS",
+        env = "TR300_AUTORUN_ARGS",
+        allow_hyphen_values = true
+    )]
+    pub autorun_args: Option<String>,
+
+    /// Skip this run unless HOURS have passed since the last one. HIDDEN —
+    /// written into the shell hook by `install --autorun <N>h`.
+    #[arg(long = "autorun-interval", value_name = "HOURS", hide = true)]
+    pub autorun_interval: Option<u32>,
+
+    /// Mention a newer release under the report, checking in the background at most once per INTERVAL (e.g. 24h)
+    #[arg(
+        long = "update-notice",
+        value_name = "INTERVAL",
+        env = "TR300_UPDATE_NOTICE"
+    )]
+    pub update_notice: Option<String>,
+
+    /// Warning/critical limits for `check` and the table's bar colours, e.g. disk=85:95,load=150:300 (metrics: disk, memory, swap, load, battery)
+    #[arg(long, value_name = "SPEC", env = "TR300_THRESHOLDS")]
+    pub thresholds: Option<String>,
+
+    /// With assert: evaluate a report saved with `tr300 --json` instead of this machine
+    #[arg(long, value_name = "PATH")]
+    pub input: Option<std::path::PathBuf>,
+
+    /// With assert: also write the results as JUnit XML to PATH
+    #[arg(long, value_name = "PATH")]
+    pub junit: Option<std::path::PathBuf>,
+
+    /// With install/uninstall: manage the hook for every user via /etc/profile.d (needs root)
+    #[arg(long, conflicts_with = "update")]
+    pub system: bool,
+
+    /// With install: check the shell integration (profiles, `report` alias, PATH) instead of installing
+    #[arg(long, conflicts_with_all = ["dry_run", "system", "uninstall", "update"])]
+    pub status: bool,
+
+    /// With update: install exactly release X.Y.Z instead of the latest (allows downgrades)
+    #[arg(long, value_name = "X.Y.Z")]
+    pub pin: Option<String>,
+
+    /// With update: restore the binary kept from the last successful update
+    #[arg(long, conflicts_with_all = ["install", "uninstall", "check", "pin"])]
+    pub rollback: bool,
+
+    /// With update: releases API base URL of an internal mirror (default: GitHub)
+    #[arg(long = "releases-url", value_name = "URL", env = "TR300_RELEASES_URL")]
+    pub releases_url: Option<String>,
+
+    /// With update: release asset download base URL of an internal mirror (default: GitHub)
+    #[arg(
+        long = "asset-base-url",
+        value_name = "URL",
+        env = "TR300_ASSET_BASE_URL"
+    )]
+    pub asset_base_url: Option<String>,
+
+    /// With update: minisign public key trusted for installer signatures in addition to the built-in release key
+    #[arg(long = "trusted-key", value_name = "KEY", env = "TR300_TRUSTED_KEY")]
+    pub trusted_key: Option<String>,
+
+    /// With update: release channel to follow, stable (default) or beta (includes prereleases)
+    #[arg(long, value_name = "CHANNEL", env = "TR300_UPDATE_CHANNEL")]
+    pub channel: Option<String>,
+
+    /// With update: only report whether a newer release exists (exit 0 = current, 10 = available, 2 = error)
+    #[arg(long, conflicts_with_all = ["install", "uninstall", "dry_run", "status", "pin"])]
+    pub check: bool,
+
+    /// With update: install a release archive or installer copied onto this machine (needs its .sha256 and .minisig beside it)
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["install", "uninstall", "check", "rollback"]
+    )]
+    pub from: Option<std::path::PathBuf>,
+
+    /// Show what install/uninstall would change (as a diff) without changing anything
+    #[arg(long = "dry-run")]
+    pub dry_run: bool,
+
+    /// With uninstall: remove only the shell profile changes, without prompting
+    #[arg(long = "profile-only", group = "uninstall_mode")]
+    pub profile_only: bool,
+
+    /// With uninstall: remove the profile changes and the binary, without the menu
+    #[arg(long, group = "uninstall_mode", conflicts_with = "system")]
+    pub complete: bool,
+
+    /// With uninstall: skip the confirmation prompt (requires --profile-only or --complete)
+    #[arg(short = 'y', long, requires = "uninstall_mode")]
+    pub yes: bool,
+
     /// Save this full table report as Markdown in Downloads
     #[arg(
         short = 'r',
@@ -110,13 +259,18 @@ pub struct Cli {
     )]
     pub save_report: bool,
 
+    /// With the save flag: file format, markdown (default) or html
+    #[arg(long = "save-format", value_name = "FORMAT", requires = "save_report")]
+    pub save_format: Option<String>,
+
     /// Deprecated compatibility no-op; reports are no longer saved by default
     #[arg(long, hide = true)]
     pub no_save: bool,
 
     // ── Cross-method consolidation options (used only with the hidden
-    //    `migrate-cleanup` action; all hidden from help). `--json` reuses the
-    //    existing output flag above. Mirrors ND-300's `migrate-cleanup` flags. ──
+    //    `migrate-cleanup` action; all hidden from help). `--json` and
+    //    `--dry-run` reuse the public flags above. Mirrors ND-300's
+    //    `migrate-cleanup` flags. ──
     /// Remove a shadowing older `cargo install` copy in `.cargo\bin`.
     #[arg(long = "cargo-copy", hide = true)]
     pub cargo_copy: bool,
@@ -129,10 +283,6 @@ pub struct Cli {
     #[arg(long = "quiet", hide = true)]
     pub quiet: bool,
 
-    /// Detect and report what WOULD be removed without deleting anything.
-    #[arg(long = "dry-run", hide = true)]
-    pub dry_run: bool,
-
     /// Require every requested cleanup target to converge; installer-internal.
     #[arg(long = "strict", hide = true)]
     pub strict_cleanup: bool,
@@ -240,6 +390,50 @@ mod tests {
         assert_eq!(cli.update_version.as_deref(), Some("4.1.3"));
     }
 
+    #[test]
+    fn parses_update_notice_interval_and_refresh_action() {
+        let cli = Cli::try_parse_from(["tr300", "--fast", "--update-notice=24h"])
+            .expect("--update-notice should parse");
+        assert_eq!(cli.update_notice.as_deref(), Some("24h"));
+        let cli = Cli::try_parse_from(["tr300", "update-notice-refresh"])
+            .expect("internal notice refresh action should parse");
+        assert_eq!(cli.action, Some(Action::UpdateNoticeRefresh));
+    }
+
+    #[test]
+    fn parses_check_action_with_thresholds() {
+        let cli = Cli::try_parse_from(["tr300", "check", "--thresholds", "disk=85:95", "--json"])
+            .expect("check action should parse");
+        assert_eq!(cli.action, Some(Action::Check));
+        assert_eq!(cli.thresholds.as_deref(), Some("disk=85:95"));
+        // `--check` still belongs to `update`.
+        assert!(!cli.check);
+    }
+
+    #[test]
+    fn parses_assert_action_with_policy_path() {
+        let cli = Cli::try_parse_from([
+            "tr300",
+            "assert",
+            "policy.toml",
+            "--input",
+            "report.json",
+            "--junit",
+            "out.xml",
+        ])
+        .expect("assert action should parse");
+        assert_eq!(cli.action, Some(Action::Assert));
+        assert_eq!(
+            cli.policy.as_deref(),
+            Some(std::path::Path::new("policy.toml"))
+        );
+        assert_eq!(
+            cli.input.as_deref(),
+            Some(std::path::Path::new("report.json"))
+        );
+        assert_eq!(cli.junit.as_deref(), Some(std::path::Path::new("out.xml")));
+    }
+
     #[test]
     fn rejects_positional_and_flag_action_conflict() {
         let err = Cli::try_parse_from(["tr300", "update", "--install"]).unwrap_err();
@@ -264,6 +458,170 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_output_and_save_formats() {
+        let cli = Cli::try_parse_from(["tr300", "--format", "html"]).expect("--format html");
+        assert_eq!(cli.format.as_deref(), Some("html"));
+        let cli = Cli::try_parse_from(["tr300", "--save", "--save-format", "html"])
+            .expect("--save --save-format html");
+        assert_eq!(cli.save_format.as_deref(), Some("html"));
+
+        let error = Cli::try_parse_from(["tr300", "--save-format", "html"])
+            .expect_err("--save-format without the save flag");
+        assert_eq!(
+            error.kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+        let error = Cli::try_parse_from(["tr300", "--json", "--format", "html"])
+            .expect_err("--json and --format both choose the output");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn parses_field_selection_and_get() {
+        let cli = Cli::try_parse_from(["tr300", "--fields", "cpu,memory", "--json"])
+            .expect("--fields with --json");
+        assert_eq!(cli.fields.as_deref(), Some("cpu,memory"));
+        let cli = Cli::try_parse_from(["tr300", "--get", "memory.total_bytes", "--fast"])
+            .expect("--get with --fast");
+        assert_eq!(cli.get.as_deref(), Some("memory.total_bytes"));
+
+        for conflicting in [["--json", ""], ["--fields", "cpu"], ["--save", ""]] {
+            let args: Vec<&str> = ["tr300", "--get", "cpu.cores"]
+                .into_iter()
+                .chain(conflicting.into_iter().filter(|a| !a.is_empty()))
+                .collect();
+            let error = Cli::try_parse_from(args).expect_err("--get prints a single value");
+            assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+        }
+    }
+
+    #[test]
+    fn parses_top_process_count() {
+        let cli = Cli::try_parse_from(["tr300", "--top", "5"]).expect("--top 5 should parse");
+        assert_eq!(cli.top, Some(5));
+        // Fast mode cannot sample per-process CPU usage; main ignores --top
+        // there so a TR300_TOP setting doesn't break the --fast auto-run.
+        let cli = Cli::try_parse_from(["tr300", "--top", "5", "--fast"])
+            .expect("--top is ignored rather than rejected with --fast");
+        assert!(cli.fast && cli.top == Some(5));
+    }
+
+    #[test]
+    fn parses_autorun_policy_options() {
+        let cli = Cli::try_parse_from([
+            "tr300",
+            "install",
+            "--autorun",
+            "6h",
+            "--autorun-args",
+            "--fast --no-color",
+        ])
+        .expect("install with an auto-run policy should parse");
+        assert_eq!(cli.autorun.as_deref(), Some("6h"));
+        assert_eq!(cli.autorun_args.as_deref(), Some("--fast --no-color"));
+
+        let hook = Cli::try_parse_from(["tr300", "--fast", "--autorun-interval", "6"])
+            .expect("the rendered interval hook should parse");
+        assert_eq!(hook.autorun_interval, Some(6));
+    }
+
+    #[test]
+    fn parses_non_interactive_uninstall_and_dry_run() {
+        let cli = Cli::try_parse_from(["tr300", "uninstall", "--complete", "--yes"])
+            .expect("scripted complete uninstall should parse");
+        assert!(cli.complete && cli.yes);
+        let cli = Cli::try_parse_from(["tr300", "uninstall", "--profile-only", "--dry-run"])
+            .expect("profile-only dry run should parse");
+        assert!(cli.profile_only && cli.dry_run);
+
+        let error = Cli::try_parse_from(["tr300", "uninstall", "--yes"])
+            .expect_err("--yes needs an explicit uninstall mode");
+        assert_eq!(
+            error.kind(),
+            clap::error::ErrorKind::MissingRequiredArgument
+        );
+        let error = Cli::try_parse_from(["tr300", "uninstall", "--profile-only", "--complete"])
+            .expect_err("the two uninstall modes are exclusive");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn parses_install_status() {
+        let cli = Cli::try_parse_from(["tr300", "install", "--status", "--json"])
+            .expect("install --status --json should parse");
+        assert!(cli.status && cli.json);
+        let error = Cli::try_parse_from(["tr300", "install", "--status", "--dry-run"])
+            .expect_err("--status is already read-only");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn parses_update_check() {
+        let cli = Cli::try_parse_from(["tr300", "update", "--check", "--json"])
+            .expect("update --check --json should parse");
+        assert!(cli.check && cli.json);
+        assert_eq!(cli.action, Some(Action::Update));
+        let cli = Cli::try_parse_from(["tr300", "--update", "--check"])
+            .expect("--update --check should parse");
+        assert!(cli.update && cli.check);
+        let error = Cli::try_parse_from(["tr300", "--install", "--check"])
+            .expect_err("--check only applies to update");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn parses_offline_update_from_file() {
+        let cli = Cli::try_parse_from([
+            "tr300",
+            "update",
+            "--from",
+            "/media/usb/tr300-x86_64-unknown-linux-gnu.tar.xz",
+            "--pin",
+            "4.1.0",
+        ])
+        .expect("update --from PATH --pin X.Y.Z should parse");
+        assert_eq!(
+            cli.from.as_deref(),
+            Some(std::path::Path::new(
+                "/media/usb/tr300-x86_64-unknown-linux-gnu.tar.xz"
+            ))
+        );
+        assert_eq!(cli.pin.as_deref(), Some("4.1.0"));
+        let error = Cli::try_parse_from(["tr300", "update", "--check", "--from", "x.msi"])
+            .expect_err("--from installs, --check only looks");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn parses_pinned_update_rollback_and_plain_version() {
+        let cli = Cli::try_parse_from(["tr300", "update", "--pin", "4.1.0"])
+            .expect("update --pin X.Y.Z should parse");
+        assert_eq!(cli.pin.as_deref(), Some("4.1.0"));
+        for flag in ["--version", "-V"] {
+            let error = Cli::try_parse_from(["tr300", flag]).expect_err("clap prints the version");
+            assert_eq!(error.kind(), clap::error::ErrorKind::DisplayVersion);
+        }
+        let cli = Cli::try_parse_from(["tr300", "update", "--rollback", "--json"])
+            .expect("update --rollback should parse");
+        assert!(cli.rollback && cli.json);
+        let error = Cli::try_parse_from(["tr300", "update", "--rollback", "--pin", "4.1.0"])
+            .expect_err("rollback restores the kept binary, not a chosen release");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn parses_system_wide_install_and_uninstall() {
+        for action in ["install", "uninstall"] {
+            let cli = Cli::try_parse_from(["tr300", action, "--system"])
+                .expect("--system should combine with install and uninstall");
+            assert!(cli.system);
+        }
+        let error = Cli::try_parse_from(["tr300", "--update", "--system"])
+            .expect_err("--system has no meaning for update");
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
     #[test]
     fn retains_no_save_as_a_hidden_compatibility_no_op() {
         let cli = Cli::try_parse_from(["tr300", "--no-save"])
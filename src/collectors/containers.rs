@@ -0,0 +1,317 @@
+//! Local container runtime inventory
+//!
+//! Queries the Docker Engine API (or Podman's Docker-compatible API) over
+//! its Unix socket with plain HTTP/1.0. This reports what the *host* runs;
+//! `detect_virtualization` separately reports whether TR-300 itself is
+//! running inside a container.
+
+use crate::collectors::CollectMode;
+#[cfg(unix)]
+use std::path::{Path, PathBuf};
+
+/// Container runtime summary
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerInfo {
+    /// Runtime family (`Docker` or `Podman`)
+    pub runtime: String,
+    /// Engine version reported by `/version`
+    pub version: Option<String>,
+    /// Socket the summary was read from
+    pub socket: String,
+    /// Containers in the `running` or `restarting` state
+    pub running: usize,
+    /// Containers in the `paused` state
+    pub paused: usize,
+    /// All other containers (`exited`, `created`, `dead`, ...)
+    pub stopped: usize,
+    /// Disk used by image layers, when `/system/df` answers in time
+    pub image_bytes: Option<u64>,
+}
+
+/// Collect the container inventory from the first reachable runtime socket.
+///
+/// Returns `None` in fast mode, on platforms without Unix sockets, and when
+/// no runtime socket exists or none accepts the connection (for example a
+/// Docker socket restricted to the `docker` group).
+pub fn collect(mode: CollectMode) -> Option<ContainerInfo> {
+    if mode == CollectMode::Fast {
+        return None;
+    }
+
+    #[cfg(unix)]
+    {
+        candidate_sockets()
+            .into_iter()
+            .filter(|socket| socket.exists())
+            .find_map(|socket| query_socket(&socket))
+    }
+
+    #[cfg(not(unix))]
+    {
+        None
+    }
+}
+
+/// Runtime sockets in probe order: an explicit `unix://` `DOCKER_HOST`,
+/// rootful Docker, rootless Podman, then rootful Podman.
+#[cfg(unix)]
+fn candidate_sockets() -> Vec<PathBuf> {
+    let mut sockets = Vec::new();
+    if let Some(path) = std::env::var("DOCKER_HOST")
+        .ok()
+        .and_then(|host| host.strip_prefix("unix://").map(PathBuf::from))
+    {
+        sockets.push(path);
+    }
+    sockets.push(PathBuf::from("/var/run/docker.sock"));
+    if let Some(runtime_dir) = std::env::var_os("XDG_RUNTIME_DIR") {
+        sockets.push(PathBuf::from(runtime_dir).join("podman/podman.sock"));
+    }
+    sockets.push(PathBuf::from("/run/podman/podman.sock"));
+    sockets
+}
+
+#[cfg(unix)]
+fn query_socket(socket: &Path) -> Option<ContainerInfo> {
+    use std::time::Duration;
+
+    let version: serde_json::Value =
+        serde_json::from_slice(&http_get(socket, "/version", Duration::from_millis(1500))?).ok()?;
+    let containers: serde_json::Value = serde_json::from_slice(&http_get(
+        socket,
+        "/containers/json?all=true",
+        Duration::from_millis(1500),
+    )?)
+    .ok()?;
+    // `/system/df` walks every layer and can be slow on image-heavy hosts;
+    // give it the slow-probe budget and treat a miss as "unknown".
+    let image_bytes = http_get(socket, "/system/df", Duration::from_secs(5))
+        .and_then(|body| serde_json::from_slice::<serde_json::Value>(&body).ok())
+        .and_then(|df| parse_image_bytes(&df));
+
+    let (running, paused, stopped) = count_states(&containers)?;
+    Some(ContainerInfo {
+        runtime: runtime_name(&version).to_string(),
+        version: version["Version"].as_str().map(str::to_string),
+        socket: socket.display().to_string(),
+        running,
+        paused,
+        stopped,
+        image_bytes,
+    })
+}
+
+/// Issue one `GET` over the Unix socket and return the body of a 200 reply.
+#[cfg(unix)]
+fn http_get(socket: &Path, path: &str, timeout: std::time::Duration) -> Option<Vec<u8>> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    let mut stream = UnixStream::connect(socket).ok()?;
+    stream.set_read_timeout(Some(timeout)).ok()?;
+    stream.set_write_timeout(Some(timeout)).ok()?;
+    // HTTP/1.0 with `Connection: close` keeps the reply unchunked on both
+    // engines and lets EOF delimit the body.
+    let request = format!(
+        "GET {} HTTP/1.0\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+        path
+    );
+    stream.write_all(request.as_bytes()).ok()?;
+
+    let mut response = Vec::new();
+    stream
+        .take(8 * 1024 * 1024)
+        .read_to_end(&mut response)
+        .ok()?;
+    parse_http_response(&response)
+}
+
+/// Split a raw HTTP response, require a 200 status, and undo chunked
+/// transfer encoding if the server used it anyway.
+#[cfg_attr(not(any(unix, test)), allow(dead_code))]
+fn parse_http_response(response: &[u8]) -> Option<Vec<u8>> {
+    let header_end = response.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&response[..header_end]);
+    let body = &response[header_end + 4..];
+
+    let mut lines = head.lines();
+    let status = lines.next()?.split_whitespace().nth(1)?;
+    if status != "200" {
+        return None;
+    }
+    let chunked = lines.any(|line| {
+        line.split_once(':').is_some_and(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding")
+                && value.trim().eq_ignore_ascii_case("chunked")
+        })
+    });
+    if chunked {
+        decode_chunked(body)
+    } else {
+        Some(body.to_vec())
+    }
+}
+
+#[cfg_attr(not(any(unix, test)), allow(dead_code))]
+fn decode_chunked(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = Vec::new();
+    loop {
+        let line_end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size_text = std::str::from_utf8(&body[..line_end]).ok()?;
+        let size_text = size_text.split(';').next()?.trim();
+        let size = usize::from_str_radix(size_text, 16).ok()?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded.extend_from_slice(body.get(..size)?);
+        body = body.get(size + 2..)?;
+    }
+}
+
+#[cfg_attr(not(any(unix, test)), allow(dead_code))]
+fn runtime_name(version: &serde_json::Value) -> &'static str {
+    let is_podman = version["Components"].as_array().is_some_and(|components| {
+        components.iter().any(|component| {
+            component["Name"]
+                .as_str()
+                .is_some_and(|name| name.to_ascii_lowercase().contains("podman"))
+        })
+    });
+    if is_podman {
+        "Podman"
+    } else {
+        "Docker"
+    }
+}
+
+/// Count `(running, paused, stopped)` from a `/containers/json?all=true` array.
+#[cfg_attr(not(any(unix, test)), allow(dead_code))]
+fn count_states(containers: &serde_json::Value) -> Option<(usize, usize, usize)> {
+    let mut counts = (0, 0, 0);
+    for container in containers.as_array()? {
+        match container["State"].as_str().unwrap_or_default() {
+            "running" | "restarting" => counts.0 += 1,
+            "paused" => counts.1 += 1,
+            _ => counts.2 += 1,
+        }
+    }
+    Some(counts)
+}
+
+/// Image disk usage from `/system/df`: Docker's deduplicated `LayersSize`,
+/// else the sum of per-image sizes (older Podman compat responses).
+#[cfg_attr(not(any(unix, test)), allow(dead_code))]
+fn parse_image_bytes(df: &serde_json::Value) -> Option<u64> {
+    df["LayersSize"].as_u64().or_else(|| {
+        df["Images"]
+            .as_array()
+            .map(|images| images.iter().filter_map(|i| i["Size"].as_u64()).sum())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn container_states_are_bucketed() {
+        let containers = serde_json::json!([
+            {"State": "running"},
+            {"State": "restarting"},
+            {"State": "paused"},
+            {"State": "exited"},
+            {"State": "created"},
+        ]);
+        assert_eq!(count_states(&containers), Some((2, 1, 2)));
+        assert_eq!(count_states(&serde_json::json!({"message": "x"})), None);
+    }
+
+    #[test]
+    fn podman_is_identified_by_engine_component() {
+        let podman = serde_json::json!({
+            "Version": "4.9.3",
+            "Components": [{"Name": "Podman Engine", "Version": "4.9.3"}]
+        });
+        let docker = serde_json::json!({
+            "Version": "24.0.7",
+            "Components": [{"Name": "Engine", "Version": "24.0.7"}]
+        });
+        assert_eq!(runtime_name(&podman), "Podman");
+        assert_eq!(runtime_name(&docker), "Docker");
+    }
+
+    #[test]
+    fn image_bytes_prefer_deduplicated_layer_size() {
+        let docker = serde_json::json!({"LayersSize": 100, "Images": [{"Size": 80}, {"Size": 70}]});
+        let podman = serde_json::json!({"Images": [{"Size": 80}, {"Size": 70}]});
+        assert_eq!(parse_image_bytes(&docker), Some(100));
+        assert_eq!(parse_image_bytes(&podman), Some(150));
+        assert_eq!(parse_image_bytes(&serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn http_response_parser_handles_chunked_bodies_and_errors() {
+        let plain = b"HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{\"a\":1}";
+        assert_eq!(
+            parse_http_response(plain).as_deref(),
+            Some(&b"{\"a\":1}"[..])
+        );
+
+        let chunked =
+            b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n{\"a\"\r\n3\r\n:1}\r\n0\r\n\r\n";
+        assert_eq!(
+            parse_http_response(chunked).as_deref(),
+            Some(&b"{\"a\":1}"[..])
+        );
+
+        let forbidden = b"HTTP/1.0 403 Forbidden\r\n\r\n{}";
+        assert_eq!(parse_http_response(forbidden), None);
+        assert_eq!(parse_http_response(b"garbage"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn inventory_is_read_from_a_stand_in_engine_socket() {
+        use std::io::{Read, Write};
+        use std::os::unix::net::UnixListener;
+
+        let dir = tempfile::tempdir().unwrap();
+        let socket = dir.path().join("docker.sock");
+        let listener = UnixListener::bind(&socket).unwrap();
+
+        let server = std::thread::spawn(move || {
+            for _ in 0..3 {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut byte = [0u8; 1];
+                while !request.ends_with(b"\r\n\r\n") {
+                    stream.read_exact(&mut byte).unwrap();
+                    request.push(byte[0]);
+                }
+                let request = String::from_utf8_lossy(&request).to_string();
+                let body = if request.starts_with("GET /version ") {
+                    r#"{"Version":"24.0.7","Components":[{"Name":"Engine"}]}"#
+                } else if request.starts_with("GET /containers/json?all=true ") {
+                    r#"[{"State":"running"},{"State":"exited"},{"State":"paused"}]"#
+                } else {
+                    r#"{"LayersSize":2147483648}"#
+                };
+                write!(
+                    stream,
+                    "HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{}",
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        let info = query_socket(&socket).expect("stand-in engine should answer");
+        server.join().unwrap();
+        assert_eq!(info.runtime, "Docker");
+        assert_eq!(info.version.as_deref(), Some("24.0.7"));
+        assert_eq!((info.running, info.paused, info.stopped), (1, 1, 1));
+        assert_eq!(info.image_bytes, Some(2_147_483_648));
+    }
+}
@@ -7,6 +7,7 @@ use crate::collectors::command::CommandTimeout;
 // finding F19) and Windows uses platform-native APIs.
 #[cfg(target_os = "macos")]
 use crate::collectors::command::run_stdout;
+use crate::collectors::process::{self, ProcessInfo};
 use crate::collectors::CollectMode;
 use crate::error::Result;
 use std::thread;
@@ -24,7 +25,7 @@ type LoadAverages = (
 
 /// CPU information
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct CpuInfo {
     /// CPU brand/model name
     pub brand: String,
@@ -52,18 +53,40 @@ pub struct CpuInfo {
     pub raw_load_1m: Option<f64>,
     pub raw_load_5m: Option<f64>,
     pub raw_load_15m: Option<f64>,
+    /// Top-process snapshot sampled over the same window as `usage_percent`.
+    /// Empty unless requested, and always empty in fast mode.
+    pub top_processes: Vec<ProcessInfo>,
 }
 
 /// Collect CPU information
 pub fn collect(mode: CollectMode) -> Result<CpuInfo> {
+    collect_with_top_processes(mode, 0)
+}
+
+/// Collect CPU information plus the `top` heaviest processes by CPU and by
+/// resident memory. Per-process CPU usage needs two samples, so the snapshot
+/// is only taken in full mode, where the 200ms usage window already exists.
+pub fn collect_with_top_processes(mode: CollectMode, top: usize) -> Result<CpuInfo> {
+    let sample_processes = mode == CollectMode::Full && top > 0;
     let mut sys = System::new();
     sys.refresh_cpu_all();
+    if sample_processes {
+        process::refresh(&mut sys);
+    }
 
     // In fast mode, skip the 200ms sleep for accurate CPU usage measurement
     if mode == CollectMode::Full {
         thread::sleep(Duration::from_millis(200));
         sys.refresh_cpu_all();
+        if sample_processes {
+            process::refresh(&mut sys);
+        }
     }
+    let top_processes = if sample_processes {
+        process::top_processes(&sys, top)
+    } else {
+        Vec::new()
+    };
 
     let cpus = sys.cpus();
     // Unknown physical topology must stay unknown. Falling back to the logical
@@ -141,6 +164,7 @@ pub fn collect(mode: CollectMode) -> Result<CpuInfo> {
         raw_load_1m,
         raw_load_5m,
         raw_load_15m,
+        top_processes,
     })
 }
 
@@ -536,6 +560,7 @@ mod cpu_info_tests {
             raw_load_1m: None,
             raw_load_5m: None,
             raw_load_15m: None,
+            top_processes: Vec::new(),
         };
         assert_eq!(info.cores_string(), "8 logical processors");
     }
@@ -6,7 +6,7 @@ use sysinfo::System;
 
 /// Memory information
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct MemoryInfo {
     /// Total physical memory in bytes
     pub total_bytes: u64,
@@ -4,15 +4,22 @@
 //! of system information in a platform-agnostic way.
 
 pub mod command;
+pub mod containers;
 pub mod cpu;
 pub mod disk;
 pub mod memory;
 pub mod network;
 pub mod os;
 pub mod platform;
+pub mod process;
+pub mod services;
 pub mod session;
+pub mod time;
 
-use crate::error::Result;
+use crate::error::{AppError, Result};
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
 
 /// Controls how much data to collect
 #[non_exhaustive]
@@ -24,6 +31,117 @@ pub enum CollectMode {
     Fast,
 }
 
+/// A report section: the unit of selective collection and rendering. Names
+/// match the top-level JSON keys; `processes` belongs to [`Section::Cpu`]
+/// and `sessions` to [`Section::Session`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Section {
+    /// OS name, version, kernel, architecture, and model
+    Os,
+    /// Firmware, board, boot mode, desktop, and display
+    System,
+    Network,
+    Cpu,
+    Disk,
+    Memory,
+    /// User, logins, uptime, shell, battery, encryption, and reboot state
+    Session,
+    Services,
+    Time,
+    Containers,
+}
+
+impl Section {
+    /// Every section, in report order
+    pub const ALL: [Section; 10] = [
+        Section::Os,
+        Section::System,
+        Section::Network,
+        Section::Cpu,
+        Section::Disk,
+        Section::Memory,
+        Section::Session,
+        Section::Services,
+        Section::Time,
+        Section::Containers,
+    ];
+
+    /// The section's name, as used by `--fields` and in JSON
+    pub fn name(self) -> &'static str {
+        match self {
+            Section::Os => "os",
+            Section::System => "system",
+            Section::Network => "network",
+            Section::Cpu => "cpu",
+            Section::Disk => "disk",
+            Section::Memory => "memory",
+            Section::Session => "session",
+            Section::Services => "services",
+            Section::Time => "time",
+            Section::Containers => "containers",
+        }
+    }
+
+    /// The section that owns a top-level JSON key, or `None` for report
+    /// metadata such as `schema_version`
+    pub fn from_json_key(key: &str) -> Option<Self> {
+        match key {
+            "processes" => Some(Section::Cpu),
+            "sessions" => Some(Section::Session),
+            _ => Section::ALL.into_iter().find(|s| s.name() == key),
+        }
+    }
+
+    /// Whether this section needs the platform collector, the slowest one
+    /// (lspci, dmidecode, WMI). The [`PLATFORM_FIELDS`] it adds to other
+    /// sections are only collected along with one of these.
+    fn needs_platform(self) -> bool {
+        matches!(self, Section::Os | Section::System | Section::Session)
+    }
+
+    /// Whether this section needs the OS collector (hostname, uptime)
+    fn needs_os(self) -> bool {
+        matches!(self, Section::Os | Section::Network | Section::Session)
+    }
+}
+
+/// Fields of other sections that the platform collector fills in, as
+/// `section.key` report paths. Without the platform collector they are left
+/// out of the report rather than shown as empty, since "not collected" is
+/// not "none".
+pub const PLATFORM_FIELDS: [&str; 5] = [
+    "cpu.core_topology",
+    "cpu.gpus",
+    "cpu.hypervisor",
+    "disk.zfs_health",
+    "memory.ram_slots",
+];
+
+impl FromStr for Section {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let name = s.trim().to_ascii_lowercase();
+        Section::ALL
+            .into_iter()
+            .find(|section| section.name() == name)
+            .ok_or_else(|| {
+                AppError::config(format!(
+                    "unknown section `{}` (expected one of: {})",
+                    s,
+                    Section::ALL.map(Section::name).join(", ")
+                ))
+            })
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 /// Collected system information used by TR-300 reports
 #[non_exhaustive]
 #[derive(Debug, Clone)]
@@ -63,6 +181,10 @@ pub struct SystemInfo {
     pub raw_load_15m: Option<f64>,
     pub gpus: Vec<String>,
     pub cpu_core_topology: Option<String>,
+    /// Top-process snapshot: the union of the heaviest processes by CPU and
+    /// by resident memory, ordered by CPU usage. Empty unless requested
+    /// through [`SystemInfo::collect_with_top_processes`].
+    pub top_processes: Vec<process::ProcessInfo>,
 
     // Disk Section
     pub disk_used_bytes: u64,
@@ -90,6 +212,9 @@ pub struct SystemInfo {
     // Session Section
     pub last_login: Option<String>,
     pub last_login_ip: Option<String>,
+    /// Active login sessions from utmp. `None` when no utmp database is
+    /// readable (non-Linux platforms, minimal containers).
+    pub active_sessions: Option<Vec<session::LoginSession>>,
     pub uptime_seconds: u64,
     /// Optional alternate session uptime when a platform can establish one
     /// independently. `None` otherwise — drives the
@@ -100,14 +225,43 @@ pub struct SystemInfo {
     pub locale: Option<String>,
     pub battery: Option<String>,
     pub encryption: Option<String>,
+    /// Whether a restart is pending to finish installing updates. `None`
+    /// where the platform offers no reliable signal.
+    pub pending_reboot: Option<bool>,
     pub desktop_environment: Option<String>,
     pub display_server: Option<String>,
     pub display_resolution: Option<String>,
     pub boot_mode: Option<String>,
+    /// Whether UEFI Secure Boot is enforcing; `None` when unreadable.
+    pub secure_boot: Option<bool>,
+
+    // Services Section
+    /// systemd health summary. `None` on non-systemd hosts and in fast mode.
+    pub services: Option<services::ServicesInfo>,
+    /// Local Docker/Podman inventory. `None` when no runtime socket answers
+    /// and in fast mode.
+    pub containers: Option<containers::ContainerInfo>,
+
+    // Time Section
+    /// Timezone and clock synchronization health. NTP fields are full-mode
+    /// Linux only; `None` when nothing could be determined.
+    pub time: Option<time::TimeInfo>,
 
     /// The collection mode used
     pub mode: CollectMode,
 
+    /// Sections that were collected, in report order. Fields that belong only
+    /// to other sections keep empty defaults and are not rendered.
+    pub sections: Vec<Section>,
+
+    /// Whether the platform collector ran, i.e. whether the
+    /// [`PLATFORM_FIELDS`] hold collected values
+    pub platform_collected: bool,
+
+    /// Collectors that failed. The report still renders; the failed
+    /// collector's own section is left out of [`SystemInfo::sections`].
+    pub collection_errors: Vec<CollectionError>,
+
     /// Whether the current process is running with elevated privileges
     /// (Unix euid == 0 / Windows admin token under UAC). Drives the
     /// elevation-tier footer hint and gates admin-only collectors.
@@ -115,54 +269,145 @@ pub struct SystemInfo {
     pub elevation_unlocks_more: bool,
 }
 
+/// A collector that failed during [`SystemInfo::collect_sections`]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollectionError {
+    /// Collector name (`os`, `cpu`, `memory`, `disk`, `network`, `session`)
+    pub collector: String,
+    /// The collector's error, or a note that its thread panicked
+    pub message: String,
+    /// How long the collector ran before failing
+    pub elapsed: Duration,
+}
+
+/// Builder for a selective [`SystemInfo`] collection:
+/// `SystemInfo::builder().sections([Section::Cpu, Section::Memory]).collect()`.
+///
+/// Defaults to every section, [`CollectMode::Full`], and no process
+/// snapshot. Collectors that no requested section needs are never started.
+#[must_use]
+#[derive(Debug, Clone)]
+pub struct SystemInfoBuilder {
+    mode: CollectMode,
+    sections: Vec<Section>,
+    top_processes: usize,
+}
+
+impl Default for SystemInfoBuilder {
+    fn default() -> Self {
+        Self {
+            mode: CollectMode::Full,
+            sections: Section::ALL.to_vec(),
+            top_processes: 0,
+        }
+    }
+}
+
+impl SystemInfoBuilder {
+    /// Collect only these sections; the rest are left empty or `None`
+    pub fn sections(mut self, sections: impl IntoIterator<Item = Section>) -> Self {
+        self.sections = sections.into_iter().collect();
+        self
+    }
+
+    /// Full or fast collection
+    pub fn mode(mut self, mode: CollectMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Snapshot the `top` heaviest processes (needs [`Section::Cpu`] and
+    /// full mode)
+    pub fn top_processes(mut self, top: usize) -> Self {
+        self.top_processes = top;
+        self
+    }
+
+    /// Run the collection
+    pub fn collect(&self) -> Result<SystemInfo> {
+        SystemInfo::collect_sections(self.mode, self.top_processes, &self.sections)
+    }
+}
+
 impl SystemInfo {
+    /// Start a selective collection; see [`SystemInfoBuilder`].
+    pub fn builder() -> SystemInfoBuilder {
+        SystemInfoBuilder::default()
+    }
+
     /// Collect all system information with the given mode.
     /// Uses `std::thread::scope` to run collectors in parallel —
     /// the 200ms CPU sleep (full mode) overlaps with disk/network/session/platform.
     pub fn collect_with_mode(mode: CollectMode) -> Result<Self> {
-        use crate::error::AppError;
-
-        let (os_info, cpu_info, mem_info, disks, net_info, session_info, platform_info) =
-            std::thread::scope(|s| {
-                let os_h = s.spawn(|| os::collect(mode));
-                let cpu_h = s.spawn(|| cpu::collect(mode));
-                let mem_h = s.spawn(|| memory::collect_with_mode(mode));
-                let disk_h = s.spawn(disk::collect);
-                let net_h = s.spawn(|| network::collect_network_info(mode));
-                let session_h = s.spawn(|| session::collect(mode));
-                let platform_h = s.spawn(|| platform::collect(mode));
-
-                (
-                    os_h.join().unwrap_or_else(|_| {
-                        Err(AppError::system_info("OS collector thread panicked"))
-                    }),
-                    cpu_h.join().unwrap_or_else(|_| {
-                        Err(AppError::system_info("CPU collector thread panicked"))
-                    }),
-                    mem_h.join().unwrap_or_else(|_| {
-                        Err(AppError::system_info("memory collector thread panicked"))
-                    }),
-                    disk_h.join().unwrap_or_else(|_| {
-                        Err(AppError::system_info("disk collector thread panicked"))
-                    }),
-                    net_h.join().unwrap_or_else(|_| {
-                        Err(AppError::system_info("network collector thread panicked"))
-                    }),
-                    session_h.join().unwrap_or_else(|_| {
-                        Err(AppError::system_info("session collector thread panicked"))
-                    }),
-                    platform_h
-                        .join()
-                        .unwrap_or_else(|_| platform::PlatformInfo::default()),
-                )
-            });
-
-        let os_info = os_info?;
-        let cpu_info = cpu_info?;
-        let mem_info = mem_info?;
-        let disks = disks?;
-        let net_info = net_info?;
-        let session_info = session_info?;
+        Self::collect_with_top_processes(mode, 0)
+    }
+
+    /// Collect all system information plus a snapshot of the `top` heaviest
+    /// processes by CPU and by resident memory. The snapshot shares the CPU
+    /// collector's sampling window and is skipped in fast mode.
+    pub fn collect_with_top_processes(mode: CollectMode, top: usize) -> Result<Self> {
+        Self::collect_sections(mode, top, &Section::ALL)
+    }
+
+    /// Collect only `sections`, spawning just the collectors they need.
+    /// Fields that belong only to other sections keep empty defaults, and
+    /// [`SystemInfo::sections`] records what was collected. A failing
+    /// collector is recorded in [`SystemInfo::collection_errors`] rather
+    /// than failing the whole collection.
+    pub fn collect_sections(mode: CollectMode, top: usize, sections: &[Section]) -> Result<Self> {
+        let mut sections = sections.to_vec();
+        sections.sort();
+        sections.dedup();
+        let wants = |section| sections.contains(&section);
+        let needs_os = sections.iter().any(|s| s.needs_os());
+        let needs_platform = sections.iter().any(|s| s.needs_platform());
+
+        let started = Instant::now();
+        let mut errors = Vec::new();
+        let (
+            os_info,
+            cpu_info,
+            mem_info,
+            disks,
+            net_info,
+            session_info,
+            platform_info,
+            services_info,
+            containers_info,
+            time_info,
+        ) = std::thread::scope(|s| {
+            let os_h = needs_os.then(|| s.spawn(|| timed(|| os::collect(mode))));
+            let cpu_h = wants(Section::Cpu)
+                .then(|| s.spawn(|| timed(|| cpu::collect_with_top_processes(mode, top))));
+            let mem_h = wants(Section::Memory)
+                .then(|| s.spawn(|| timed(|| memory::collect_with_mode(mode))));
+            let disk_h = wants(Section::Disk).then(|| s.spawn(|| timed(disk::collect)));
+            let net_h = wants(Section::Network)
+                .then(|| s.spawn(|| timed(|| network::collect_network_info(mode))));
+            let session_h =
+                wants(Section::Session).then(|| s.spawn(|| timed(|| session::collect(mode))));
+            let platform_h = needs_platform.then(|| s.spawn(|| platform::collect(mode)));
+            let services_h = wants(Section::Services).then(|| s.spawn(|| services::collect(mode)));
+            let containers_h =
+                wants(Section::Containers).then(|| s.spawn(|| containers::collect(mode)));
+            let time_h = wants(Section::Time).then(|| s.spawn(|| time::collect(mode)));
+
+            (
+                joined(os_h, Section::Os, started, &mut errors),
+                joined(cpu_h, Section::Cpu, started, &mut errors),
+                joined(mem_h, Section::Memory, started, &mut errors),
+                joined(disk_h, Section::Disk, started, &mut errors),
+                joined(net_h, Section::Network, started, &mut errors),
+                joined(session_h, Section::Session, started, &mut errors),
+                platform_h.map_or_else(platform::PlatformInfo::default, |h| {
+                    h.join().unwrap_or_default()
+                }),
+                services_h.and_then(|h| h.join().unwrap_or_default()),
+                containers_h.and_then(|h| h.join().unwrap_or_default()),
+                time_h.and_then(|h| h.join().unwrap_or_default()),
+            )
+        });
 
         // Select the system/root volume. A machine-wide sum is not an honest
         // fallback because bind mounts, APFS volumes, and Windows fixed drives
@@ -193,12 +438,14 @@ impl SystemInfo {
         // only when they can establish one.
         let hypervisor = platform_info.virtualization;
 
-        let cpu_usage_percent = (mode == CollectMode::Full)
+        let cpu_usage_percent = (mode == CollectMode::Full && wants(Section::Cpu))
             .then_some(cpu_info.usage_percent as f64)
             .filter(|value| value.is_finite());
         let fallback_shell = non_unknown(session_info.shell);
         let fallback_terminal = non_unknown(session_info.terminal);
 
+        retain_collected(&mut sections, &errors);
+
         Ok(Self {
             os_name: os_info.name,
             os_version: os_info.version,
@@ -229,6 +476,7 @@ impl SystemInfo {
             raw_load_15m: cpu_info.raw_load_15m,
             gpus: platform_info.gpus,
             cpu_core_topology: platform_info.cpu_core_topology,
+            top_processes: cpu_info.top_processes,
             disk_used_bytes: disk_used,
             disk_total_bytes: disk_total,
             disk_available_bytes: disk.as_ref().map_or(0, |d| d.available_bytes),
@@ -250,6 +498,7 @@ impl SystemInfo {
             ram_slots: platform_info.ram_slots,
             last_login: session_info.last_login,
             last_login_ip: session_info.last_login_ip,
+            active_sessions: session_info.active_sessions,
             uptime_seconds: os_info.uptime_seconds,
             session_uptime_seconds: os_info.session_uptime_seconds,
             shell: platform_info.shell.or(fallback_shell),
@@ -257,16 +506,29 @@ impl SystemInfo {
             locale: platform_info.locale,
             battery: platform_info.battery,
             encryption: platform_info.encryption,
+            pending_reboot: platform_info.pending_reboot,
             desktop_environment: platform_info.desktop_environment,
             display_server: platform_info.display_server,
             display_resolution: platform_info.display_resolution,
             boot_mode: platform_info.boot_mode,
+            secure_boot: platform_info.secure_boot,
+            services: services_info,
+            containers: containers_info,
+            time: time_info,
             mode,
+            sections,
+            platform_collected: needs_platform,
+            collection_errors: errors,
             is_elevated: crate::is_elevated(),
             elevation_unlocks_more: platform_info.elevation_unlocks_more,
         })
     }
 
+    /// Whether `section` was collected (and so is rendered)
+    pub fn includes(&self, section: Section) -> bool {
+        self.sections.contains(&section)
+    }
+
     /// Collect all system information (full mode, backward compatible)
     pub fn collect() -> Result<Self> {
         Self::collect_with_mode(CollectMode::Full)
@@ -397,6 +659,48 @@ fn is_system_mount(mount: &str, system_root: Option<&str>) -> bool {
     })
 }
 
+/// Drop the sections a failed collector leaves incomplete: its own, and for
+/// the OS collector also Network and Session, whose hostname and uptime it
+/// supplies. The other sections render.
+fn retain_collected(sections: &mut Vec<Section>, errors: &[CollectionError]) {
+    let failed = |name: &str| errors.iter().any(|error| error.collector == name);
+    let os_failed = failed(Section::Os.name());
+    sections.retain(|section| !(failed(section.name()) || os_failed && section.needs_os()));
+}
+
+/// Run a collector and time it.
+fn timed<T>(collect: impl FnOnce() -> Result<T>) -> (Result<T>, Duration) {
+    let started = Instant::now();
+    let result = collect();
+    (result, started.elapsed())
+}
+
+/// Result of a collector thread, or the empty default when the requested
+/// sections did not need that collector or it failed. Failures are recorded
+/// in `errors` under the name of the section the collector owns; a panicked
+/// thread reports the time since `started`.
+fn joined<T: Default>(
+    handle: Option<std::thread::ScopedJoinHandle<'_, (Result<T>, Duration)>>,
+    section: Section,
+    started: Instant,
+    errors: &mut Vec<CollectionError>,
+) -> T {
+    let Some(handle) = handle else {
+        return T::default();
+    };
+    let (message, elapsed) = match handle.join() {
+        Ok((Ok(value), _)) => return value,
+        Ok((Err(err), elapsed)) => (err.to_string(), elapsed),
+        Err(_) => ("collector thread panicked".to_string(), started.elapsed()),
+    };
+    errors.push(CollectionError {
+        collector: section.name().to_string(),
+        message,
+        elapsed,
+    });
+    T::default()
+}
+
 fn non_unknown(value: String) -> Option<String> {
     let value = value.trim();
     (!value.is_empty() && !value.eq_ignore_ascii_case("unknown")).then(|| value.to_string())
@@ -427,6 +731,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn os_failure_drops_the_sections_it_fills_in() {
+        let error = |collector: &str| CollectionError {
+            collector: collector.to_string(),
+            message: "injected".to_string(),
+            elapsed: Duration::from_millis(1),
+        };
+        let mut sections = Section::ALL.to_vec();
+        retain_collected(&mut sections, &[error("os")]);
+        assert!(!sections.contains(&Section::Os));
+        assert!(!sections.contains(&Section::Network));
+        assert!(!sections.contains(&Section::Session));
+        assert!(sections.contains(&Section::Cpu));
+        assert!(sections.contains(&Section::System));
+
+        let mut sections = Section::ALL.to_vec();
+        retain_collected(&mut sections, &[error("disk")]);
+        assert_eq!(sections.len(), Section::ALL.len() - 1);
+        assert!(sections.contains(&Section::Network));
+        assert!(!sections.contains(&Section::Disk));
+    }
+
     #[test]
     fn root_volume_wins_over_larger_data_volume() {
         let disks = vec![disk("/data", 4_000, false), disk("/", 1_000, false)];
@@ -472,4 +798,44 @@ mod tests {
         assert_eq!(format_duration_seconds(59), "59s");
         assert_eq!(format_duration_seconds(60), "1m");
     }
+
+    #[test]
+    fn builder_collects_only_the_requested_sections() {
+        let info = SystemInfo::builder()
+            .sections([Section::Memory])
+            .mode(CollectMode::Fast)
+            .collect()
+            .unwrap();
+        assert_eq!(info.sections, [Section::Memory]);
+        assert!(info.includes(Section::Memory) && !info.includes(Section::Cpu));
+        assert!(info.processor.is_empty() && info.disk_total_bytes == 0);
+        assert!(info.cpu_usage_percent.is_none() && info.time.is_none());
+    }
+
+    #[test]
+    fn failed_collectors_are_recorded_instead_of_propagated() {
+        let started = Instant::now();
+        let mut errors = Vec::new();
+        let (failed, panicked, ok, skipped) = std::thread::scope(|s| {
+            let failed = s.spawn(|| timed(|| Err::<u64, _>(AppError::system_info("no disks"))));
+            let panicked = s.spawn(|| -> (Result<u64>, Duration) { panic!("probe crashed") });
+            let ok = s.spawn(|| timed(|| Ok(7_u64)));
+            (
+                joined(Some(failed), Section::Disk, started, &mut errors),
+                joined(Some(panicked), Section::Network, started, &mut errors),
+                joined(Some(ok), Section::Memory, started, &mut errors),
+                joined::<u64>(None, Section::Cpu, started, &mut errors),
+            )
+        });
+
+        assert_eq!((failed, panicked, ok, skipped), (0, 0, 7, 0));
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].collector, "disk");
+        assert_eq!(
+            errors[0].message,
+            "Failed to retrieve system information: no disks"
+        );
+        assert_eq!(errors[1].collector, "network");
+        assert_eq!(errors[1].message, "collector thread panicked");
+    }
 }
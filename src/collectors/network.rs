@@ -9,7 +9,7 @@ use std::env;
 
 /// Network information for TR-300
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct NetworkInfo {
     /// Machine's primary IP address (None if skipped in fast mode)
     pub machine_ip: Option<String>,
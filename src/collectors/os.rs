@@ -6,7 +6,7 @@ use sysinfo::System;
 
 /// Operating system information
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct OsInfo {
     /// OS name (e.g., "Windows 11", "macOS", "Ubuntu")
     pub name: String,
@@ -1,6 +1,6 @@
 //! Linux-specific information collectors
 
-use super::{CollectMode, PlatformInfo};
+use super::{zfs_rank, CollectMode, PlatformInfo};
 use crate::collectors::command::{run_stdout, run_stdout_no_args, CommandTimeout};
 use std::env;
 use std::fs;
@@ -20,6 +20,7 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
         } else {
             detect_boot_mode()
         },
+        secure_boot: detect_secure_boot(),
         virtualization: detect_virtualization(mode),
         windows_edition: None,
         macos_codename: None,
@@ -49,6 +50,7 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
         } else {
             None
         },
+        pending_reboot: get_pending_reboot(),
         elevation_unlocks_more: hardware_details.elevation_unlocks_more,
     }
 }
@@ -115,6 +117,25 @@ fn detect_boot_mode() -> Option<String> {
     }
 }
 
+/// The `SecureBoot` EFI variable: four attribute bytes, then 1 when Secure
+/// Boot is enforcing. efivarfs files are world-readable. Legacy BIOS boots
+/// cannot have Secure Boot at all.
+fn detect_secure_boot() -> Option<bool> {
+    const VAR: &str = "/sys/firmware/efi/efivars/SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c";
+    if !Path::new("/sys/firmware/efi").exists() {
+        return Some(false);
+    }
+    secure_boot_from_efivar(&fs::read(VAR).ok()?)
+}
+
+fn secure_boot_from_efivar(bytes: &[u8]) -> Option<bool> {
+    match bytes.get(4)? {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
 /// Detect if running in a virtual machine
 fn detect_virtualization(mode: CollectMode) -> Option<String> {
     if let Ok(osrelease) = fs::read_to_string("/proc/sys/kernel/osrelease") {
@@ -675,16 +696,23 @@ where
     worst
 }
 
-fn zfs_rank(state: &str) -> u8 {
-    match state {
-        "ONLINE" => 1,
-        // Unknown/unparsed state ranks just above ONLINE so it surfaces as
-        // "not clearly healthy" without masquerading as a specific DEGRADED.
-        "DEGRADED" => 3,
-        "FAULTED" | "OFFLINE" | "UNAVAIL" | "REMOVED" => 4,
-        // A SUSPENDED pool has stopped servicing I/O — most severe.
-        "SUSPENDED" => 5,
-        _ => 2,
+/// Debian-family systems flag a needed restart (kernel, libc, microcode
+/// upgrades) with `/var/run/reboot-required`. Other distributions have no
+/// equivalent marker, so their answer is unknown rather than "no".
+fn get_pending_reboot() -> Option<bool> {
+    pending_reboot_from_markers(
+        Path::new("/var/run/reboot-required"),
+        Path::new("/etc/debian_version"),
+    )
+}
+
+fn pending_reboot_from_markers(marker: &Path, debian_version: &Path) -> Option<bool> {
+    if marker.exists() {
+        Some(true)
+    } else if debian_version.exists() {
+        Some(false)
+    } else {
+        None
     }
 }
 
@@ -928,6 +956,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn secure_boot_reads_the_efivar_payload_byte() {
+        assert_eq!(secure_boot_from_efivar(&[6, 0, 0, 0, 1]), Some(true));
+        assert_eq!(secure_boot_from_efivar(&[6, 0, 0, 0, 0]), Some(false));
+        assert_eq!(secure_boot_from_efivar(&[6, 0, 0, 0]), None);
+        assert_eq!(secure_boot_from_efivar(&[6, 0, 0, 0, 7]), None);
+    }
+
+    #[test]
+    fn pending_reboot_is_unknown_without_a_debian_marker() {
+        let dir = tempfile::tempdir().unwrap();
+        let marker = dir.path().join("reboot-required");
+        let debian = dir.path().join("debian_version");
+        assert_eq!(pending_reboot_from_markers(&marker, &debian), None);
+        std::fs::write(&debian, "12.7\n").unwrap();
+        assert_eq!(pending_reboot_from_markers(&marker, &debian), Some(false));
+        std::fs::write(&marker, "*** System restart required ***\n").unwrap();
+        assert_eq!(pending_reboot_from_markers(&marker, &debian), Some(true));
+    }
+
     #[test]
     fn lspci_gpu_line_matches_class_not_device_name() {
         assert_eq!(
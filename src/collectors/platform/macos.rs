@@ -32,6 +32,8 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
             ram_slots: None,
             locale: get_locale(), // Fast: reads env var
             encryption: None,
+            pending_reboot: None,
+            secure_boot: None,
             elevation_unlocks_more: false,
         };
     }
@@ -77,6 +79,8 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
         ram_slots: None,
         locale: get_locale(),
         encryption: get_filevault_status(),
+        pending_reboot: None,
+        secure_boot: None,
         elevation_unlocks_more: false,
     }
 }
@@ -30,6 +30,9 @@ pub struct PlatformInfo {
     pub macos_codename: Option<String>,
     /// Boot mode (UEFI/Legacy)
     pub boot_mode: Option<String>,
+    /// Whether UEFI Secure Boot is enforcing. `None` when the firmware state
+    /// is not readable.
+    pub secure_boot: Option<bool>,
     /// Virtualization platform if running in VM
     pub virtualization: Option<String>,
     /// GPU names
@@ -62,11 +65,29 @@ pub struct PlatformInfo {
     /// Only populated when the data is readable in the current security context;
     /// otherwise `None` (unelevated users may see this gap on certain configurations).
     pub encryption: Option<String>,
+    /// Whether the OS is waiting on a restart to finish installing updates.
+    /// `None` when the platform offers no reliable signal.
+    pub pending_reboot: Option<bool>,
     /// Whether a probe specifically failed because the current process lacks
     /// privileges and elevation is expected to unlock useful data.
     pub elevation_unlocks_more: bool,
 }
 
+/// Severity rank of a `zpool` health state; higher is worse. Shared by the
+/// ZFS collector (to pick the worst pool) and `tr300 check`.
+pub(crate) fn zfs_rank(state: &str) -> u8 {
+    match state {
+        "ONLINE" => 1,
+        // Unknown/unparsed state ranks just above ONLINE so it surfaces as
+        // "not clearly healthy" without masquerading as a specific DEGRADED.
+        "DEGRADED" => 3,
+        "FAULTED" | "OFFLINE" | "UNAVAIL" | "REMOVED" => 4,
+        // A SUSPENDED pool has stopped servicing I/O — most severe.
+        "SUSPENDED" => 5,
+        _ => 2,
+    }
+}
+
 /// Collect platform-specific information
 pub fn collect(mode: CollectMode) -> PlatformInfo {
     #[cfg(target_os = "linux")]
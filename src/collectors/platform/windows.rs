@@ -165,6 +165,7 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
             display_server: Some("DWM".to_string()),
             windows_edition: None,
             boot_mode: None,
+            secure_boot: None,
             virtualization: None,
             macos_codename: None,
             gpus: get_gpus_fast(),
@@ -180,6 +181,7 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
             ram_slots: None,
             locale: None,
             encryption: None,
+            pending_reboot: get_pending_reboot(),
             elevation_unlocks_more: false,
         };
     }
@@ -316,6 +318,7 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
         os_build: None,
         windows_edition,
         boot_mode: detect_boot_mode(),
+        secure_boot: get_secure_boot(),
         virtualization,
         desktop_environment: Some("Windows Shell".to_string()),
         display_server: Some("DWM".to_string()),
@@ -334,10 +337,48 @@ pub fn collect(mode: CollectMode) -> PlatformInfo {
         ram_slots,
         locale: get_locale(),
         encryption: get_bitlocker_status(),
+        pending_reboot: get_pending_reboot(),
         elevation_unlocks_more: false,
     }
 }
 
+/// `UEFISecureBootEnabled` is 1 while Secure Boot is enforcing. The key
+/// only exists on UEFI firmware that supports Secure Boot, so its absence
+/// is left unknown rather than read as "off".
+fn get_secure_boot() -> Option<bool> {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    let state = RegKey::predef(HKEY_LOCAL_MACHINE)
+        .open_subkey(r"SYSTEM\CurrentControlSet\Control\SecureBoot\State")
+        .ok()?;
+    let enabled: u32 = state.get_value("UEFISecureBootEnabled").ok()?;
+    Some(enabled == 1)
+}
+
+/// Servicing and Windows Update create these keys while a restart is needed
+/// to finish installing updates, and delete them afterwards. Both are
+/// readable without elevation; any error other than "not found" leaves the
+/// answer unknown.
+fn get_pending_reboot() -> Option<bool> {
+    use winreg::enums::HKEY_LOCAL_MACHINE;
+    use winreg::RegKey;
+
+    const KEYS: [&str; 2] = [
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\Component Based Servicing\RebootPending",
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update\RebootRequired",
+    ];
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    for key in KEYS {
+        match hklm.open_subkey(key) {
+            Ok(_) => return Some(true),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(_) => return None,
+        }
+    }
+    Some(false)
+}
+
 /// Query BitLocker status for the drive named by `SystemDrive` (falling back
 /// to `C:` only when the environment value is unavailable) via the
 /// `root\CIMV2\Security\MicrosoftVolumeEncryption` namespace.
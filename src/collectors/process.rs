@@ -0,0 +1,188 @@
+//! Top-process snapshot collector
+//!
+//! Ranks processes from the sysinfo `System` the CPU collector already
+//! refreshes across its 200ms sampling window, so no second sampling delay
+//! is needed to compute per-process CPU usage.
+
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind, Users};
+
+/// One process in the top-process snapshot
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcessInfo {
+    /// Process identifier
+    pub pid: u32,
+    /// Owning user name, when it can be resolved
+    pub user: Option<String>,
+    /// Process (executable) name. Full command lines are deliberately not
+    /// collected: arguments routinely carry tokens and passwords, and this
+    /// snapshot is meant to be pasted into incident write-ups.
+    pub command: String,
+    /// CPU usage as a percent of one logical CPU over the sampling window,
+    /// matching `top`/`ps` (a busy multi-threaded process can exceed 100%).
+    pub cpu_percent: f64,
+    /// Resident set size in bytes
+    pub rss_bytes: u64,
+    /// 1-based position among the heaviest processes by CPU, if ranked
+    pub cpu_rank: Option<usize>,
+    /// 1-based position among the heaviest processes by RSS, if ranked
+    pub memory_rank: Option<usize>,
+}
+
+/// Refresh kind used for both samples of the CPU sampling window.
+fn refresh_kind() -> ProcessRefreshKind {
+    ProcessRefreshKind::new()
+        .with_cpu()
+        .with_memory()
+        .with_user(UpdateKind::OnlyIfNotSet)
+}
+
+/// Refresh every process in `sys` with the fields the snapshot needs.
+pub(crate) fn refresh(sys: &mut System) {
+    sys.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind());
+}
+
+/// Build the top-`limit` snapshot from an already refreshed `System`.
+///
+/// Returns the union of the `limit` heaviest processes by CPU and the `limit`
+/// heaviest by resident memory, ordered by CPU usage (descending). Use
+/// [`by_cpu`] and [`by_memory`] to recover each ranking.
+pub(crate) fn top_processes(sys: &System, limit: usize) -> Vec<ProcessInfo> {
+    if limit == 0 {
+        return Vec::new();
+    }
+
+    let users = Users::new_with_refreshed_list();
+    let processes = sys
+        .processes()
+        .values()
+        // Linux exposes threads as tasks of their parent; counting them would
+        // list the same RSS several times.
+        .filter(|process| process.thread_kind().is_none())
+        .map(|process| ProcessInfo {
+            pid: process.pid().as_u32(),
+            user: process
+                .user_id()
+                .and_then(|uid| users.get_user_by_id(uid))
+                .map(|user| user.name().to_string()),
+            command: process.name().to_string_lossy().to_string(),
+            cpu_percent: finite_or_zero(process.cpu_usage() as f64),
+            rss_bytes: process.memory(),
+            cpu_rank: None,
+            memory_rank: None,
+        })
+        .collect();
+
+    select_top(processes, limit)
+}
+
+/// Keep the union of the top-`limit` processes by CPU and by RSS, recording
+/// each process's 1-based position in either ranking.
+fn select_top(mut processes: Vec<ProcessInfo>, limit: usize) -> Vec<ProcessInfo> {
+    processes.sort_by(|a, b| {
+        b.rss_bytes
+            .cmp(&a.rss_bytes)
+            .then(b.cpu_percent.total_cmp(&a.cpu_percent))
+            .then(a.pid.cmp(&b.pid))
+    });
+    for (rank, process) in processes.iter_mut().take(limit).enumerate() {
+        process.memory_rank = Some(rank + 1);
+    }
+
+    processes.sort_by(|a, b| {
+        b.cpu_percent
+            .total_cmp(&a.cpu_percent)
+            .then(b.rss_bytes.cmp(&a.rss_bytes))
+            .then(a.pid.cmp(&b.pid))
+    });
+    for (rank, process) in processes.iter_mut().take(limit).enumerate() {
+        process.cpu_rank = Some(rank + 1);
+    }
+
+    processes.retain(|process| process.cpu_rank.is_some() || process.memory_rank.is_some());
+    processes
+}
+
+/// Processes from a snapshot that rank among the heaviest by CPU, in rank order.
+pub fn by_cpu(processes: &[ProcessInfo]) -> Vec<&ProcessInfo> {
+    let mut ranked: Vec<&ProcessInfo> = processes.iter().filter(|p| p.cpu_rank.is_some()).collect();
+    ranked.sort_by_key(|p| p.cpu_rank);
+    ranked
+}
+
+/// Processes from a snapshot that rank among the heaviest by RSS, in rank order.
+pub fn by_memory(processes: &[ProcessInfo]) -> Vec<&ProcessInfo> {
+    let mut ranked: Vec<&ProcessInfo> = processes
+        .iter()
+        .filter(|p| p.memory_rank.is_some())
+        .collect();
+    ranked.sort_by_key(|p| p.memory_rank);
+    ranked
+}
+
+fn finite_or_zero(value: f64) -> f64 {
+    if value.is_finite() {
+        value.max(0.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn process(pid: u32, cpu_percent: f64, rss_bytes: u64) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            user: Some("user".to_string()),
+            command: format!("proc{pid}"),
+            cpu_percent,
+            rss_bytes,
+            cpu_rank: None,
+            memory_rank: None,
+        }
+    }
+
+    fn pids(processes: Vec<&ProcessInfo>) -> Vec<u32> {
+        processes.iter().map(|p| p.pid).collect()
+    }
+
+    #[test]
+    fn snapshot_keeps_the_union_of_both_rankings() {
+        let processes = vec![
+            process(1, 90.0, 10),
+            process(2, 50.0, 20),
+            process(3, 0.5, 9_000),
+            process(4, 0.1, 8_000),
+            process(5, 0.0, 1),
+        ];
+        let selected = select_top(processes, 2);
+        assert_eq!(
+            selected.iter().map(|p| p.pid).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(pids(by_cpu(&selected)), vec![1, 2]);
+        assert_eq!(pids(by_memory(&selected)), vec![3, 4]);
+    }
+
+    #[test]
+    fn process_in_both_rankings_is_listed_once() {
+        let selected = select_top(vec![process(7, 80.0, 9_000), process(8, 1.0, 1)], 1);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].cpu_rank, Some(1));
+        assert_eq!(selected[0].memory_rank, Some(1));
+    }
+
+    #[test]
+    fn zero_limit_selects_nothing() {
+        assert!(select_top(vec![process(1, 1.0, 1)], 0).is_empty());
+    }
+
+    #[test]
+    fn non_finite_cpu_usage_is_reported_as_zero() {
+        assert_eq!(finite_or_zero(f64::NAN), 0.0);
+        assert_eq!(finite_or_zero(-1.0), 0.0);
+        assert_eq!(finite_or_zero(12.5), 12.5);
+    }
+}
@@ -0,0 +1,359 @@
+//! systemd service health collector
+//!
+//! Summarizes failed units, the manager's overall system state, and boot
+//! timing on systemd hosts. Every probe goes through `collectors::command`
+//! with the usual timeouts and degrades to `None` when a tool is missing,
+//! slow, or refuses (for example an unprivileged journal read).
+
+#[cfg(target_os = "linux")]
+use crate::collectors::command::{run_output_with_env, CommandTimeout};
+use crate::collectors::CollectMode;
+
+/// systemd health summary
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ServicesInfo {
+    /// `systemctl is-system-running` state (`running`, `degraded`, ...)
+    pub system_state: Option<String>,
+    /// Names of units currently in the `failed` state
+    pub failed_units: Vec<String>,
+    /// Boot timing as reported by `systemd-analyze time`
+    pub boot_timing: Option<BootTiming>,
+    /// First journal entry of the current boot, as printed by `journalctl`
+    pub last_boot: Option<String>,
+}
+
+/// Boot duration split by stage, in milliseconds. Stages `systemd-analyze`
+/// does not report on this host (firmware/loader on most VMs, initrd on
+/// hosts without one) stay `None`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BootTiming {
+    pub firmware_ms: Option<u64>,
+    pub loader_ms: Option<u64>,
+    pub kernel_ms: Option<u64>,
+    pub initrd_ms: Option<u64>,
+    pub userspace_ms: Option<u64>,
+    pub total_ms: Option<u64>,
+}
+
+/// Collect the systemd health summary.
+///
+/// Returns `None` in fast mode (every field needs a subprocess), on non-Linux
+/// platforms, and on hosts not booted with systemd.
+pub fn collect(mode: CollectMode) -> Option<ServicesInfo> {
+    if mode == CollectMode::Fast {
+        return None;
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        collect_linux()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn collect_linux() -> Option<ServicesInfo> {
+    // sd_booted(3): systemd is PID 1 exactly when this directory exists.
+    if !std::path::Path::new("/run/systemd/system").is_dir() {
+        return None;
+    }
+
+    // `is-system-running` exits non-zero for every state except `running`,
+    // so read stdout regardless of the exit status.
+    let system_state = c_locale_stdout("systemctl", &["is-system-running"], false)
+        .and_then(|stdout| stdout.lines().next().map(str::trim).map(str::to_string))
+        .filter(|state| !state.is_empty());
+    let failed_units = c_locale_stdout(
+        "systemctl",
+        &[
+            "list-units",
+            "--state=failed",
+            "--no-legend",
+            "--plain",
+            "--no-pager",
+        ],
+        true,
+    )
+    .map(|stdout| parse_failed_units(&stdout))
+    .unwrap_or_default();
+    let boot_timing = c_locale_stdout("systemd-analyze", &["time"], true)
+        .and_then(|stdout| parse_analyze_time(&stdout));
+    // `-o json` (systemd v251+) gives stable field names; older releases
+    // ignore it and print the text table, which the parser also accepts.
+    let last_boot = c_locale_stdout(
+        "journalctl",
+        &["--list-boots", "-o", "json", "--no-pager", "--quiet"],
+        true,
+    )
+    .or_else(|| {
+        c_locale_stdout(
+            "journalctl",
+            &["--list-boots", "--no-pager", "--quiet"],
+            true,
+        )
+    })
+    .and_then(|stdout| parse_current_boot_start(&stdout));
+
+    Some(ServicesInfo {
+        system_state,
+        failed_units,
+        boot_timing,
+        last_boot,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn c_locale_stdout(program: &str, args: &[&str], require_success: bool) -> Option<String> {
+    let output = run_output_with_env(program, args, [("LC_ALL", "C")], CommandTimeout::Slow)?;
+    (!require_success || output.status.success())
+        .then(|| String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Parse `systemctl list-units --state=failed --no-legend --plain` output.
+/// Some systemd releases still prefix rows with a `●` marker despite
+/// `--plain`, so skip any leading non-unit token.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_failed_units(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .filter_map(|line| {
+            line.split_whitespace()
+                .find(|token| token.contains('.'))
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// Parse the first line of `systemd-analyze time`, e.g.
+/// `Startup finished in 3.1s (firmware) + 2s (loader) + 1.2s (kernel) +
+/// 10.2s (userspace) = 16.5s`. Returns `None` while boot is still running
+/// (the tool then prints an error instead of the summary).
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_analyze_time(stdout: &str) -> Option<BootTiming> {
+    let line = stdout
+        .lines()
+        .find(|line| line.starts_with("Startup finished in "))?;
+    let body = line.trim_start_matches("Startup finished in ");
+    let (stages, total) = match body.rsplit_once(" = ") {
+        Some((stages, total)) => (stages, Some(total)),
+        None => (body, None),
+    };
+
+    let mut timing = BootTiming {
+        total_ms: total.and_then(parse_systemd_duration_ms),
+        ..BootTiming::default()
+    };
+    for stage in stages.split(" + ") {
+        let Some((duration, label)) = stage.trim().rsplit_once(" (") else {
+            continue;
+        };
+        let duration = parse_systemd_duration_ms(duration);
+        match label.trim_end_matches(')') {
+            "firmware" => timing.firmware_ms = duration,
+            "loader" => timing.loader_ms = duration,
+            "kernel" => timing.kernel_ms = duration,
+            "initrd" => timing.initrd_ms = duration,
+            "userspace" => timing.userspace_ms = duration,
+            _ => {}
+        }
+    }
+    (timing != BootTiming::default()).then_some(timing)
+}
+
+/// Parse a systemd timespan such as `1min 2.345s`, `345ms`, or `1h 2min`.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_systemd_duration_ms(text: &str) -> Option<u64> {
+    let mut total_ms = 0.0;
+    let mut parsed_any = false;
+    for token in text.split_whitespace() {
+        let split = token
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(split);
+        let value: f64 = number.parse().ok()?;
+        let factor = match unit {
+            "us" | "µs" => 0.001,
+            "ms" => 1.0,
+            "s" | "" => 1_000.0,
+            "min" => 60_000.0,
+            "h" => 3_600_000.0,
+            "d" => 86_400_000.0,
+            _ => return None,
+        };
+        total_ms += value * factor;
+        parsed_any = true;
+    }
+    parsed_any.then_some(total_ms.round() as u64)
+}
+
+/// Extract the first-entry timestamp of boot `0` from
+/// `journalctl --list-boots`, either its JSON form or the text table.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_current_boot_start(stdout: &str) -> Option<String> {
+    match serde_json::from_str::<serde_json::Value>(stdout) {
+        Ok(boots) => parse_current_boot_start_json(&boots),
+        Err(_) => parse_current_boot_start_text(stdout),
+    }
+}
+
+/// `-o json` rows carry `first_entry` as microseconds since the epoch;
+/// the index key is `index` or `idx` depending on the systemd release.
+/// Rendered in UTC, in the same layout as the C-locale text table.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_current_boot_start_json(boots: &serde_json::Value) -> Option<String> {
+    let boot = boots.as_array()?.iter().find(|boot| {
+        ["index", "idx"]
+            .iter()
+            .any(|key| boot.get(key).and_then(serde_json::Value::as_i64) == Some(0))
+    })?;
+    let micros = boot.get("first_entry")?.as_i64()?;
+    let first = chrono::DateTime::from_timestamp_micros(micros)?;
+    Some(first.format("%a %Y-%m-%d %H:%M:%S UTC").to_string())
+}
+
+/// Text rows are `IDX BOOT-ID WEEKDAY DATE TIME ZONE ...` in every release;
+/// only the separator before the last-entry column differs (`—`, `-`, or
+/// whitespace), so read the first entry by position and drop any separator
+/// glued to its zone.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_current_boot_start_text(stdout: &str) -> Option<String> {
+    const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+    stdout.lines().find_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [idx, _boot_id, weekday, date, time, zone, ..] = fields.as_slice() else {
+            return None;
+        };
+        let is_date = date.len() == 10 && date.as_bytes()[4] == b'-' && date.as_bytes()[7] == b'-';
+        if *idx != "0" || !WEEKDAYS.contains(weekday) || !is_date {
+            return None;
+        }
+        let zone = zone
+            .rsplit_once(['—', '-'])
+            .filter(|(_, next)| WEEKDAYS.contains(next))
+            .map_or(*zone, |(zone, _)| zone);
+        Some(format!("{} {} {} {}", weekday, date, time, zone))
+    })
+}
+
+/// Format a millisecond duration as seconds with one decimal place.
+pub fn format_boot_ms(ms: u64) -> String {
+    format!("{:.1}s", ms as f64 / 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyze_time_splits_every_reported_stage() {
+        let stdout = "Startup finished in 3.105s (firmware) + 2.001s (loader) + \
+                      1.234s (kernel) + 2.5s (initrd) + 1min 10.2s (userspace) = 1min 19.040s\n\
+                      graphical.target reached after 10.1s in userspace.\n";
+        let timing = parse_analyze_time(stdout).expect("summary should parse");
+        assert_eq!(timing.firmware_ms, Some(3_105));
+        assert_eq!(timing.loader_ms, Some(2_001));
+        assert_eq!(timing.kernel_ms, Some(1_234));
+        assert_eq!(timing.initrd_ms, Some(2_500));
+        assert_eq!(timing.userspace_ms, Some(70_200));
+        assert_eq!(timing.total_ms, Some(79_040));
+    }
+
+    #[test]
+    fn analyze_time_without_firmware_stages_leaves_them_unknown() {
+        let timing =
+            parse_analyze_time("Startup finished in 845ms (kernel) + 4.2s (userspace) = 5.045s")
+                .expect("VM summary should parse");
+        assert_eq!(timing.firmware_ms, None);
+        assert_eq!(timing.loader_ms, None);
+        assert_eq!(timing.kernel_ms, Some(845));
+        assert_eq!(timing.total_ms, Some(5_045));
+    }
+
+    #[test]
+    fn analyze_time_rejects_unfinished_boot() {
+        assert_eq!(
+            parse_analyze_time("Bootup is not yet finished (org.freedesktop.systemd1.Manager.FinishTimestampMonotonic=0)."),
+            None
+        );
+    }
+
+    #[test]
+    fn systemd_durations_cover_all_units() {
+        assert_eq!(parse_systemd_duration_ms("345ms"), Some(345));
+        assert_eq!(parse_systemd_duration_ms("1h 2min"), Some(3_720_000));
+        assert_eq!(parse_systemd_duration_ms("750us"), Some(1));
+        assert_eq!(parse_systemd_duration_ms("soon"), None);
+        assert_eq!(parse_systemd_duration_ms(""), None);
+    }
+
+    #[test]
+    fn failed_units_skip_the_status_marker() {
+        let stdout = "● nginx.service loaded failed failed A high performance web server\n\
+                      backup.timer  loaded failed failed Nightly backup\n";
+        assert_eq!(
+            parse_failed_units(stdout),
+            vec!["nginx.service".to_string(), "backup.timer".to_string()]
+        );
+        assert!(parse_failed_units("").is_empty());
+    }
+
+    #[test]
+    fn list_boots_parser_handles_legacy_and_columnar_layouts() {
+        let legacy = "-1 1f2e3d4c5b6a Fri 2026-10-16 08:00:01 UTC—Sat 2026-10-17 09:00:00 UTC\n\
+                       0 6a5b4c3d2e1f Sat 2026-10-17 09:01:02 UTC—Sun 2026-10-18 10:00:00 UTC\n";
+        assert_eq!(
+            parse_current_boot_start(legacy).as_deref(),
+            Some("Sat 2026-10-17 09:01:02 UTC")
+        );
+
+        let columnar = "IDX BOOT ID                          FIRST ENTRY                 LAST ENTRY\n\
+                          0 6a5b4c3d2e1f4a5b6c7d8e9f0a1b2c3d Sat 2026-10-17 09:01:02 UTC Sun 2026-10-18 10:00:00 UTC\n";
+        assert_eq!(
+            parse_current_boot_start(columnar).as_deref(),
+            Some("Sat 2026-10-17 09:01:02 UTC")
+        );
+        assert_eq!(
+            parse_current_boot_start("No journal files were found.\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn list_boots_parser_handles_ascii_separators_and_offset_zones() {
+        let ascii = "-1 1f2e3d4c5b6a Fri 2026-10-16 08:00:01 UTC-Sat 2026-10-17 09:00:00 UTC\n\
+                      0 6a5b4c3d2e1f Sat 2026-10-17 09:01:02 UTC-Sun 2026-10-18 10:00:00 UTC\n";
+        assert_eq!(
+            parse_current_boot_start(ascii).as_deref(),
+            Some("Sat 2026-10-17 09:01:02 UTC")
+        );
+
+        let offset = " 0 6a5b4c3d2e1f Sat 2026-10-17 09:01:02 -03-Sun 2026-10-18 10:00:00 -03\n";
+        assert_eq!(
+            parse_current_boot_start(offset).as_deref(),
+            Some("Sat 2026-10-17 09:01:02 -03")
+        );
+    }
+
+    #[test]
+    fn list_boots_parser_reads_json_output() {
+        let json = r#"[
+            {"index":-1,"boot_id":"1f2e3d4c5b6a","first_entry":1792137601000000,"last_entry":1792227600000000},
+            {"index":0,"boot_id":"6a5b4c3d2e1f","first_entry":1792227662000000,"last_entry":1792317600000000}
+        ]"#;
+        assert_eq!(
+            parse_current_boot_start(json).as_deref(),
+            Some("Sat 2026-10-17 09:01:02 UTC")
+        );
+        assert_eq!(
+            parse_current_boot_start(r#"[{"idx":0,"first_entry":1792227662000000}]"#).as_deref(),
+            Some("Sat 2026-10-17 09:01:02 UTC")
+        );
+        assert_eq!(parse_current_boot_start("[]"), None);
+    }
+}
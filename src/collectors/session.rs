@@ -8,7 +8,7 @@ use std::env;
 
 /// Session/user information
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct SessionInfo {
     /// Current username
     pub username: String,
@@ -24,6 +24,35 @@ pub struct SessionInfo {
     pub last_login: Option<String>,
     /// Last login IP (if available)
     pub last_login_ip: Option<String>,
+    /// Active login sessions read from utmp. `None` when the platform or
+    /// host keeps no readable utmp database.
+    pub active_sessions: Option<Vec<LoginSession>>,
+}
+
+/// One active login session
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoginSession {
+    /// Login name
+    pub user: String,
+    /// Terminal line (`pts/0`, `tty1`, ...)
+    pub tty: String,
+    /// Remote host or X display recorded at login, if any
+    pub host: Option<String>,
+    /// Login time as Unix seconds
+    pub login_unix_seconds: i64,
+    /// Seconds since the terminal last saw input, when its device is readable
+    pub idle_seconds: Option<u64>,
+}
+
+impl LoginSession {
+    /// Whether the session came in over the network rather than from a local
+    /// console, X display (`:0`), or terminal multiplexer.
+    pub fn is_remote(&self) -> bool {
+        self.host.as_deref().is_some_and(|host| {
+            !host.is_empty() && !host.starts_with(':') && !host.starts_with("tmux(")
+        })
+    }
 }
 
 /// Collect session information
@@ -55,6 +84,7 @@ pub fn collect(mode: CollectMode) -> Result<SessionInfo> {
         terminal,
         last_login,
         last_login_ip,
+        active_sessions: get_active_sessions(),
     })
 }
 
@@ -319,6 +349,88 @@ fn parse_last_record(line: &str) -> Option<(Option<String>, Option<String>)> {
     (!date_fields.is_empty()).then(|| (Some(date_fields.join(" ")), origin))
 }
 
+/// Read active sessions from `/var/run/utmp`.
+#[cfg(target_os = "linux")]
+fn get_active_sessions() -> Option<Vec<LoginSession>> {
+    let bytes = std::fs::read("/var/run/utmp").ok()?;
+    let now = std::time::SystemTime::now();
+    Some(
+        parse_utmp_records(&bytes)
+            .into_iter()
+            .map(|mut session| {
+                session.idle_seconds = tty_idle_seconds(&session.tty, now);
+                session
+            })
+            .collect(),
+    )
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_active_sessions() -> Option<Vec<LoginSession>> {
+    None
+}
+
+/// Idle time is the age of the terminal device's last access, as `w` reports.
+#[cfg(target_os = "linux")]
+fn tty_idle_seconds(tty: &str, now: std::time::SystemTime) -> Option<u64> {
+    if tty.is_empty() || tty.contains("..") {
+        return None;
+    }
+    let accessed = std::fs::metadata(std::path::Path::new("/dev").join(tty))
+        .ok()?
+        .accessed()
+        .ok()?;
+    Some(now.duration_since(accessed).unwrap_or_default().as_secs())
+}
+
+/// glibc `struct utmp` layout on 64-bit Linux (records are 384 bytes; the
+/// `ut_tv` seconds field stays 32-bit for on-disk compatibility).
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+mod utmp_layout {
+    pub const RECORD_SIZE: usize = 384;
+    pub const TYPE: usize = 0;
+    pub const LINE: (usize, usize) = (8, 32);
+    pub const USER: (usize, usize) = (44, 32);
+    pub const HOST: (usize, usize) = (76, 256);
+    pub const TV_SEC: usize = 340;
+    pub const USER_PROCESS: i16 = 7;
+}
+
+/// Decode `USER_PROCESS` records from a utmp/wtmp byte image. Trailing
+/// partial records (a file being appended to) are ignored.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_utmp_records(bytes: &[u8]) -> Vec<LoginSession> {
+    use utmp_layout::*;
+
+    fn c_string(record: &[u8], (offset, len): (usize, usize)) -> String {
+        let field = &record[offset..offset + len];
+        let end = field.iter().position(|b| *b == 0).unwrap_or(len);
+        String::from_utf8_lossy(&field[..end]).trim().to_string()
+    }
+
+    bytes
+        .chunks_exact(RECORD_SIZE)
+        .filter(|record| i16::from_ne_bytes([record[TYPE], record[TYPE + 1]]) == USER_PROCESS)
+        .filter_map(|record| {
+            let user = c_string(record, USER);
+            if user.is_empty() {
+                return None;
+            }
+            let host = c_string(record, HOST);
+            let seconds = i32::from_ne_bytes(record[TV_SEC..TV_SEC + 4].try_into().ok()?);
+            Some(LoginSession {
+                user,
+                tty: c_string(record, LINE),
+                host: (!host.is_empty()).then_some(host),
+                // The on-disk field is a signed 32-bit value that wraps in
+                // 2038; reinterpret it as unsigned to stay correct until 2106.
+                login_unix_seconds: seconds as u32 as i64,
+                idle_seconds: None,
+            })
+        })
+        .collect()
+}
+
 #[cfg(target_os = "windows")]
 fn get_last_login_windows(_username: &str) -> (Option<String>, Option<String>) {
     let when = wts_query_session_logon_time().and_then(filetime_to_local_string);
@@ -497,6 +609,49 @@ mod tests {
         );
     }
 
+    fn utmp_record(kind: i16, user: &str, line: &str, host: &str, seconds: i32) -> Vec<u8> {
+        let mut record = vec![0u8; utmp_layout::RECORD_SIZE];
+        record[0..2].copy_from_slice(&kind.to_ne_bytes());
+        record[8..8 + line.len()].copy_from_slice(line.as_bytes());
+        record[44..44 + user.len()].copy_from_slice(user.as_bytes());
+        record[76..76 + host.len()].copy_from_slice(host.as_bytes());
+        record[340..344].copy_from_slice(&seconds.to_ne_bytes());
+        record
+    }
+
+    #[test]
+    fn utmp_parser_keeps_only_user_processes() {
+        let mut bytes = utmp_record(2, "reboot", "~", "6.8.0", 1_700_000_000);
+        bytes.extend(utmp_record(
+            7,
+            "alice",
+            "pts/0",
+            "192.0.2.44",
+            1_700_000_100,
+        ));
+        bytes.extend(utmp_record(7, "bob", "tty1", "", 1_700_000_200));
+        bytes.extend(utmp_record(8, "", "pts/1", "", 1_700_000_300));
+        // A half-written trailing record must not be decoded.
+        bytes.extend_from_slice(&[7, 0, 0]);
+
+        let sessions = parse_utmp_records(&bytes);
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].user, "alice");
+        assert_eq!(sessions[0].tty, "pts/0");
+        assert_eq!(sessions[0].host.as_deref(), Some("192.0.2.44"));
+        assert_eq!(sessions[0].login_unix_seconds, 1_700_000_100);
+        assert!(sessions[0].is_remote());
+        assert_eq!(sessions[1].host, None);
+        assert!(!sessions[1].is_remote());
+    }
+
+    #[test]
+    fn local_display_and_multiplexer_hosts_are_not_remote() {
+        let mut bytes = utmp_record(7, "alice", "pts/2", ":0", 1);
+        bytes.extend(utmp_record(7, "alice", "pts/3", "tmux(4242).%0", 1));
+        assert!(parse_utmp_records(&bytes).iter().all(|s| !s.is_remote()));
+    }
+
     #[test]
     fn last_parser_does_not_turn_weekday_into_an_origin() {
         assert_eq!(
@@ -0,0 +1,329 @@
+//! Clock and time synchronization collector
+//!
+//! Reports the configured timezone plus, on Linux in full mode, NTP sync
+//! state from chrony or systemd-timesyncd, the current clock offset, and
+//! whether the hardware clock is kept in local time. Clock skew is a common
+//! root cause of TLS and Kerberos failures, so the offset is reported as
+//! measured rather than rounded away.
+
+#[cfg(target_os = "linux")]
+use crate::collectors::command::{run_stdout_c_locale, CommandTimeout};
+use crate::collectors::CollectMode;
+
+/// Clock health summary
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeInfo {
+    /// IANA timezone name (`Europe/Berlin`), or the raw `TZ` value
+    pub timezone: Option<String>,
+    /// Whether the system clock is synchronized to a time source
+    pub ntp_synchronized: Option<bool>,
+    /// Daemon that disciplines the clock (`chronyd`, `systemd-timesyncd`)
+    pub sync_service: Option<String>,
+    /// Time source the daemon is currently following
+    pub ntp_source: Option<String>,
+    /// Estimated offset of the system clock from the source in milliseconds;
+    /// positive when the local clock is ahead
+    pub offset_ms: Option<f64>,
+    /// Whether the RTC is kept in local time rather than UTC
+    pub rtc_in_local_time: Option<bool>,
+}
+
+/// Collect clock health.
+///
+/// The timezone is read in every mode (it needs no subprocess); NTP state and
+/// RTC mode are full-mode Linux probes. Returns `None` when nothing is known.
+pub fn collect(mode: CollectMode) -> Option<TimeInfo> {
+    let info = TimeInfo {
+        timezone: detect_timezone(),
+        ..TimeInfo::default()
+    };
+
+    #[cfg(target_os = "linux")]
+    let info = {
+        let mut info = info;
+        if mode == CollectMode::Full {
+            collect_sync_linux(&mut info);
+        }
+        info
+    };
+    #[cfg(not(target_os = "linux"))]
+    let _ = mode;
+
+    (info != TimeInfo::default()).then_some(info)
+}
+
+/// `TZ` wins when set (it overrides the system zone for this process);
+/// otherwise resolve the `/etc/localtime` symlink, then Debian's
+/// `/etc/timezone`.
+fn detect_timezone() -> Option<String> {
+    if let Some(tz) = std::env::var("TZ")
+        .ok()
+        .map(|tz| tz.trim_start_matches(':').to_string())
+        .filter(|tz| !tz.is_empty())
+    {
+        return Some(tz);
+    }
+
+    #[cfg(unix)]
+    {
+        if let Some(zone) = std::fs::read_link("/etc/localtime")
+            .ok()
+            .and_then(|target| zone_from_localtime_target(&target.to_string_lossy()))
+        {
+            return Some(zone);
+        }
+        if let Ok(contents) = std::fs::read_to_string("/etc/timezone") {
+            let zone = contents.trim();
+            if !zone.is_empty() {
+                return Some(zone.to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/// Strip everything up to `zoneinfo/` from a localtime symlink target
+/// (`/usr/share/zoneinfo/Europe/Berlin`, macOS `/var/db/timezone/zoneinfo/...`).
+#[cfg_attr(not(any(unix, test)), allow(dead_code))]
+fn zone_from_localtime_target(target: &str) -> Option<String> {
+    let (_, zone) = target.rsplit_once("zoneinfo/")?;
+    let zone = zone
+        .trim_start_matches("posix/")
+        .trim_start_matches("right/");
+    (!zone.is_empty()).then(|| zone.to_string())
+}
+
+#[cfg(target_os = "linux")]
+fn collect_sync_linux(info: &mut TimeInfo) {
+    // chronyc answers only when chronyd is running, so success identifies
+    // the active daemon as well as its state.
+    if let Some(tracking) =
+        run_stdout_c_locale("chronyc", ["-n", "tracking"], CommandTimeout::Normal)
+            .and_then(|stdout| parse_chrony_tracking(&stdout))
+    {
+        info.sync_service = Some("chronyd".to_string());
+        info.ntp_synchronized = Some(tracking.synchronized);
+        info.ntp_source = tracking.source;
+        info.offset_ms = tracking.offset_ms;
+    }
+
+    if let Some(show) = run_stdout_c_locale("timedatectl", ["show"], CommandTimeout::Normal) {
+        let value = |key: &str| key_value(&show, key);
+        if info.ntp_synchronized.is_none() {
+            info.ntp_synchronized = value("NTPSynchronized").map(|v| v == "yes");
+        }
+        info.rtc_in_local_time = value("LocalRTC").map(|v| v == "yes");
+        if info.timezone.is_none() {
+            info.timezone = value("Timezone").filter(|tz| !tz.is_empty());
+        }
+    }
+
+    if info.sync_service.is_none() {
+        if let Some(status) = run_stdout_c_locale(
+            "timedatectl",
+            ["timesync-status", "--no-pager"],
+            CommandTimeout::Normal,
+        ) {
+            let (source, offset_ms) = parse_timesync_status(&status);
+            if source.is_some() || offset_ms.is_some() {
+                info.sync_service = Some("systemd-timesyncd".to_string());
+                info.ntp_source = source;
+                info.offset_ms = offset_ms;
+            }
+        }
+    }
+
+    // hwclock records the RTC mode on the third line of /etc/adjtime; this
+    // covers hosts without timedatectl.
+    if info.rtc_in_local_time.is_none() {
+        if let Ok(adjtime) = std::fs::read_to_string("/etc/adjtime") {
+            info.rtc_in_local_time = adjtime.lines().nth(2).map(|line| line.trim() == "LOCAL");
+        }
+    }
+}
+
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+#[derive(Debug, PartialEq)]
+struct ChronyTracking {
+    synchronized: bool,
+    source: Option<String>,
+    offset_ms: Option<f64>,
+}
+
+/// Parse `chronyc -n tracking`. `System time` reads
+/// `0.000012345 seconds fast of NTP time` (local clock ahead) or `... slow`.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_chrony_tracking(stdout: &str) -> Option<ChronyTracking> {
+    let field = |name: &str| {
+        stdout.lines().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == name).then(|| value.trim().to_string())
+        })
+    };
+
+    let leap = field("Leap status")?;
+    let source = field("Reference ID").and_then(|reference| {
+        let (_, rest) = reference.split_once('(')?;
+        let name = rest.trim_end_matches(')').trim();
+        (!name.is_empty()).then(|| name.to_string())
+    });
+    let offset_ms = field("System time").and_then(|system| {
+        let mut tokens = system.split_whitespace();
+        let seconds: f64 = tokens.next()?.parse().ok()?;
+        let direction = tokens.nth(1)?;
+        let sign = match direction {
+            "fast" => 1.0,
+            "slow" => -1.0,
+            _ => return None,
+        };
+        Some(sign * seconds * 1_000.0)
+    });
+
+    Some(ChronyTracking {
+        synchronized: leap != "Not synchronised",
+        source,
+        offset_ms,
+    })
+}
+
+/// Parse `timedatectl timesync-status`, e.g. `Server: 185.125.190.56
+/// (ntp.ubuntu.com)` and `Offset: -345us`.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_timesync_status(stdout: &str) -> (Option<String>, Option<f64>) {
+    let mut source = None;
+    let mut offset_ms = None;
+    for line in stdout.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        match key.trim() {
+            "Server" => {
+                let value = value.trim();
+                let name = match value.split_once('(') {
+                    Some((_, name)) => name.trim_end_matches(')').trim(),
+                    None => value,
+                };
+                source = (!name.is_empty()).then(|| name.to_string());
+            }
+            "Offset" => offset_ms = parse_signed_timespan_ms(value.trim()),
+            _ => {}
+        }
+    }
+    (source, offset_ms)
+}
+
+/// Parse a signed systemd timespan such as `+1.234ms`, `-345us`, or `-1.5s`.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_signed_timespan_ms(text: &str) -> Option<f64> {
+    let (sign, magnitude) = match text.strip_prefix('-') {
+        Some(rest) => (-1.0, rest),
+        None => (1.0, text.trim_start_matches('+')),
+    };
+    let split = magnitude
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(magnitude.len());
+    let (number, unit) = magnitude.split_at(split);
+    let value: f64 = number.parse().ok()?;
+    let factor = match unit {
+        "ns" => 0.000_001,
+        "us" | "µs" => 0.001,
+        "ms" => 1.0,
+        "s" => 1_000.0,
+        "min" => 60_000.0,
+        _ => return None,
+    };
+    Some(sign * value * factor)
+}
+
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn key_value(stdout: &str, key: &str) -> Option<String> {
+    stdout.lines().find_map(|line| {
+        let (k, v) = line.split_once('=')?;
+        (k == key).then(|| v.trim().to_string())
+    })
+}
+
+/// Format a clock offset with an explicit sign, switching to seconds once
+/// the skew is large enough to matter for TLS and Kerberos.
+pub fn format_offset_ms(offset_ms: f64) -> String {
+    if offset_ms.abs() >= 1_000.0 {
+        format!("{:+.2} s", offset_ms / 1_000.0)
+    } else {
+        format!("{:+.3} ms", offset_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn localtime_symlink_targets_resolve_to_zone_names() {
+        assert_eq!(
+            zone_from_localtime_target("/usr/share/zoneinfo/Europe/Berlin").as_deref(),
+            Some("Europe/Berlin")
+        );
+        assert_eq!(
+            zone_from_localtime_target("../usr/share/zoneinfo/posix/America/New_York").as_deref(),
+            Some("America/New_York")
+        );
+        assert_eq!(
+            zone_from_localtime_target("/var/db/timezone/zoneinfo/Asia/Tokyo").as_deref(),
+            Some("Asia/Tokyo")
+        );
+        assert_eq!(zone_from_localtime_target("/etc/localtime.bak"), None);
+    }
+
+    #[test]
+    fn chrony_tracking_reports_source_state_and_signed_offset() {
+        let stdout = "Reference ID    : C0A80001 (192.168.0.1)\n\
+                      Stratum         : 3\n\
+                      System time     : 0.000123000 seconds slow of NTP time\n\
+                      Last offset     : +0.000011 seconds\n\
+                      Leap status     : Normal\n";
+        let tracking = parse_chrony_tracking(stdout).expect("tracking should parse");
+        assert!(tracking.synchronized);
+        assert_eq!(tracking.source.as_deref(), Some("192.168.0.1"));
+        let offset = tracking.offset_ms.expect("offset");
+        assert!((offset + 0.123).abs() < 1e-9);
+
+        let unsynced = "Reference ID    : 00000000 ()\n\
+                        System time     : 2.500000000 seconds fast of NTP time\n\
+                        Leap status     : Not synchronised\n";
+        let tracking = parse_chrony_tracking(unsynced).expect("tracking should parse");
+        assert!(!tracking.synchronized);
+        assert_eq!(tracking.source, None);
+        assert_eq!(tracking.offset_ms, Some(2_500.0));
+
+        assert_eq!(parse_chrony_tracking("506 Cannot talk to daemon\n"), None);
+    }
+
+    #[test]
+    fn timesync_status_reports_server_and_offset() {
+        let stdout = "       Server: 185.125.190.56 (ntp.ubuntu.com)\n\
+                      Poll interval: 34min 8s (min: 32s; max 34min 8s)\n\
+                             Leap: normal\n\
+                           Offset: -345us\n";
+        let (source, offset) = parse_timesync_status(stdout);
+        assert_eq!(source.as_deref(), Some("ntp.ubuntu.com"));
+        assert!((offset.expect("offset") + 0.345).abs() < 1e-9);
+    }
+
+    #[test]
+    fn signed_timespans_and_offset_formatting() {
+        assert_eq!(parse_signed_timespan_ms("+1.5ms"), Some(1.5));
+        assert_eq!(parse_signed_timespan_ms("-2s"), Some(-2_000.0));
+        assert_eq!(parse_signed_timespan_ms("soon"), None);
+        assert_eq!(format_offset_ms(-0.345), "-0.345 ms");
+        assert_eq!(format_offset_ms(2_500.0), "+2.50 s");
+    }
+
+    #[test]
+    fn timedatectl_show_keys_are_exact() {
+        let show = "Timezone=Europe/Berlin\nLocalRTC=yes\nNTPSynchronized=no\n";
+        assert_eq!(key_value(show, "LocalRTC").as_deref(), Some("yes"));
+        assert_eq!(key_value(show, "NTP"), None);
+    }
+}
@@ -3,8 +3,11 @@
 //! Handles configuration constants and runtime settings for the fixed-width
 //! terminal report.
 
+use crate::collectors::Section;
 use crate::error::{AppError, Result};
+use std::fmt;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 /// Default title for the report header
 pub const DEFAULT_TITLE: &str = "QUBETX DEVELOPER TOOLS";
@@ -77,19 +80,30 @@ pub struct Config {
     pub title: Option<String>,
     /// Custom subtitle (overrides DEFAULT_SUBTITLE)
     pub subtitle: Option<String>,
-    /// Whether to show network information
-    pub show_network: bool,
-    /// Whether to show disk information
-    pub show_disks: bool,
+    /// Sections to collect and show (default: all). Renderers also hide a
+    /// collected section that is not listed here.
+    pub sections: Vec<Section>,
     /// Output width (0 = auto-detect)
     pub width: usize,
     /// Whether to use compact mode
     pub compact: bool,
-    /// Output format: "table" (default) or "json"
+    /// Output format: "table" (default), "json", "html", "env", "csv", or "yaml"
     pub format: OutputFormat,
     /// Suppress the elevation-tier footer hint shown to non-elevated users
     /// on platforms where running with sudo / Administrator unlocks more data
     pub no_elevation_hint: bool,
+    /// Number of heaviest processes to list by CPU and by memory (0 = off)
+    pub top_processes: usize,
+    /// When and how the installed shell hook runs the report
+    pub autorun: AutorunPolicy,
+    /// Where `tr300 update` looks up releases and downloads assets
+    pub release_source: ReleaseSource,
+    /// Show a one-line notice under the table when a newer release is known,
+    /// re-checking in the background at most once per this many hours
+    /// (`None` = off, the default)
+    pub update_notice: Option<u32>,
+    /// Warning/critical limits used by `tr300 check`
+    pub thresholds: Thresholds,
 }
 
 /// Output format options
@@ -98,6 +112,58 @@ pub struct Config {
 pub enum OutputFormat {
     Table,
     Json,
+    /// Self-contained HTML page (inline CSS, print-friendly)
+    Html,
+    /// Shell-quoted `TR300_*=value` lines
+    Env,
+    /// Header row plus one value row
+    Csv,
+    Yaml,
+}
+
+impl FromStr for OutputFormat {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "html" => Ok(Self::Html),
+            "env" => Ok(Self::Env),
+            "csv" => Ok(Self::Csv),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            _ => Err(AppError::config(format!(
+                "unknown output format `{}` (expected table, json, html, env, csv, or yaml)",
+                s
+            ))),
+        }
+    }
+}
+
+/// File format written by the save flag (`--save`)
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SaveFormat {
+    /// `.md` tables (the default)
+    #[default]
+    Markdown,
+    /// `.html` page, the same as `--format html`
+    Html,
+}
+
+impl FromStr for SaveFormat {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "html" => Ok(Self::Html),
+            _ => Err(AppError::config(format!(
+                "unknown save format `{}` (expected markdown or html)",
+                s
+            ))),
+        }
+    }
 }
 
 impl Default for Config {
@@ -107,12 +173,16 @@ impl Default for Config {
             use_colors: true,
             title: None,
             subtitle: None,
-            show_network: true,
-            show_disks: true,
+            sections: Section::ALL.to_vec(),
             width: 0,
             compact: false,
             format: OutputFormat::Table,
             no_elevation_hint: false,
+            top_processes: 0,
+            autorun: AutorunPolicy::default(),
+            release_source: ReleaseSource::default(),
+            update_notice: None,
+            thresholds: Thresholds::default(),
         }
     }
 }
@@ -217,15 +287,21 @@ impl Config {
         self
     }
 
+    /// Collect and show only these sections
+    pub fn with_sections(mut self, sections: impl IntoIterator<Item = Section>) -> Self {
+        self.sections = sections.into_iter().collect();
+        self
+    }
+
     /// Disable network display
     pub fn without_network(mut self) -> Self {
-        self.show_network = false;
+        self.sections.retain(|section| *section != Section::Network);
         self
     }
 
     /// Disable disk display
     pub fn without_disks(mut self) -> Self {
-        self.show_disks = false;
+        self.sections.retain(|section| *section != Section::Disk);
         self
     }
 
@@ -235,11 +311,515 @@ impl Config {
         self
     }
 
+    /// Set the output format
+    pub fn with_format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
     /// Suppress the elevation-tier footer hint
     pub fn with_no_elevation_hint(mut self, no_hint: bool) -> Self {
         self.no_elevation_hint = no_hint;
         self
     }
+
+    /// Set how many top processes to list by CPU and by memory (0 = off)
+    pub fn with_top_processes(mut self, top: usize) -> Self {
+        self.top_processes = top;
+        self
+    }
+
+    /// Set the auto-run policy written by `tr300 install`
+    pub fn with_autorun(mut self, autorun: AutorunPolicy) -> Self {
+        self.autorun = autorun;
+        self
+    }
+
+    /// Set the release API and asset host used by `tr300 update`
+    pub fn with_release_source(mut self, source: ReleaseSource) -> Self {
+        self.release_source = source;
+        self
+    }
+
+    /// Enable the passive update notice, checking at most every `hours` hours
+    pub fn with_update_notice(mut self, hours: Option<u32>) -> Self {
+        self.update_notice = hours;
+        self
+    }
+
+    /// Set the warning/critical limits used by `tr300 check`
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+}
+
+/// Flags the installed shell hook passes to `tr300` by default
+pub const DEFAULT_AUTORUN_ARGS: &[&str] = &["--fast"];
+
+/// When the installed shell hook runs the report
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutorunTrigger {
+    /// Every new interactive shell
+    #[default]
+    Always,
+    /// Only shells started by an SSH login (`SSH_CONNECTION` set)
+    Ssh,
+    /// Only login shells
+    Login,
+    /// At most once per this many hours, tracked by a stamp file in the
+    /// user cache directory
+    EveryHours(u32),
+}
+
+impl FromStr for AutorunTrigger {
+    type Err = AppError;
+
+    /// Parse `always`, `ssh`, `login`, or an interval such as `6h`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "always" => Ok(Self::Always),
+            "ssh" => Ok(Self::Ssh),
+            "login" => Ok(Self::Login),
+            other => parse_hours(other).map(Self::EveryHours).ok_or_else(|| {
+                AppError::config(format!(
+                    "unknown auto-run trigger `{}` (expected always, ssh, login, or an interval like 6h)",
+                    s
+                ))
+            }),
+        }
+    }
+}
+
+/// Parse a whole-hour interval such as `6h` (case-insensitive, at least 1h).
+pub fn parse_interval_hours(s: &str) -> Result<u32> {
+    parse_hours(&s.trim().to_ascii_lowercase()).ok_or_else(|| {
+        AppError::config(format!(
+            "invalid interval `{}` (expected whole hours like 24h)",
+            s
+        ))
+    })
+}
+
+fn parse_hours(s: &str) -> Option<u32> {
+    s.strip_suffix('h')
+        .and_then(|hours| hours.parse::<u32>().ok())
+        .filter(|hours| *hours > 0)
+}
+
+impl fmt::Display for AutorunTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Always => f.write_str("always"),
+            Self::Ssh => f.write_str("ssh"),
+            Self::Login => f.write_str("login"),
+            Self::EveryHours(hours) => write!(f, "{}h", hours),
+        }
+    }
+}
+
+/// Auto-run behaviour baked into the shell hook at install time
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AutorunPolicy {
+    /// When the hook fires
+    pub trigger: AutorunTrigger,
+    /// Flags passed to `tr300` when it does
+    pub args: Vec<String>,
+}
+
+impl Default for AutorunPolicy {
+    fn default() -> Self {
+        Self {
+            trigger: AutorunTrigger::Always,
+            args: DEFAULT_AUTORUN_ARGS.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+}
+
+impl AutorunPolicy {
+    /// Set when the hook fires
+    pub fn with_trigger(mut self, trigger: AutorunTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// Set the flags the hook passes to `tr300`, e.g. `"--fast --no-color"`.
+    ///
+    /// The flags run in every interactive shell, so only the report-only
+    /// [`AUTORUN_FLAGS`] and [`AUTORUN_VALUE_FLAGS`] are accepted, with
+    /// values built from `[A-Za-z0-9_.,:-]` — no actions such as
+    /// `--uninstall`, and no quoting, expansion, or command separators.
+    pub fn with_args(mut self, args: &str) -> Result<Self> {
+        let tokens: Vec<String> = args.split_whitespace().map(str::to_string).collect();
+        if let Some(bad) = tokens.iter().find(|token| !is_safe_autorun_arg(token)) {
+            return Err(AppError::config(format!(
+                "auto-run flag `{}` is not allowed: use report options such as {}, or {}=VALUE",
+                bad,
+                AUTORUN_FLAGS.join(", "),
+                AUTORUN_VALUE_FLAGS.join("=VALUE, ")
+            )));
+        }
+        self.args = tokens;
+        Ok(self)
+    }
+}
+
+/// Report-only switches the auto-run hook may pass to `tr300`
+pub const AUTORUN_FLAGS: [&str; 4] = ["--fast", "--no-color", "--ascii", "--no-elevation-hint"];
+
+/// Report-only options the auto-run hook may pass as `--flag=VALUE`
+pub const AUTORUN_VALUE_FLAGS: [&str; 5] = [
+    "--title",
+    "--fields",
+    "--top",
+    "--format",
+    "--update-notice",
+];
+
+fn is_safe_autorun_arg(token: &str) -> bool {
+    if AUTORUN_FLAGS.contains(&token) {
+        return true;
+    }
+    token.split_once('=').is_some_and(|(flag, value)| {
+        AUTORUN_VALUE_FLAGS.contains(&flag)
+            && !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ',' | ':'))
+    })
+}
+
+/// GitHub releases API for TR-300 (`/latest` and `/tags/v<version>` live below it)
+pub const DEFAULT_RELEASES_API_URL: &str =
+    "https://api.github.com/repos/QubeTX/qube-machine-report/releases";
+
+/// GitHub download base for TR-300 release assets (`<base>/v<version>/<asset>`)
+pub const DEFAULT_ASSET_BASE_URL: &str =
+    "https://github.com/QubeTX/qube-machine-report/releases/download";
+
+/// Which releases `tr300 update` follows
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReleaseChannel {
+    /// Published releases only (`<api_url>/latest`)
+    #[default]
+    Stable,
+    /// The newest release including prereleases (`-rc.N`); a stable release
+    /// is only taken when it is newer than every prerelease
+    Beta,
+}
+
+impl FromStr for ReleaseChannel {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "stable" => Ok(Self::Stable),
+            "beta" => Ok(Self::Beta),
+            _ => Err(AppError::config(format!(
+                "unknown release channel `{}` (expected stable or beta)",
+                s
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for ReleaseChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stable => f.write_str("stable"),
+            Self::Beta => f.write_str("beta"),
+        }
+    }
+}
+
+/// Release host used by `tr300 update`: GitHub by default, or an internal
+/// mirror that serves the same layout (API objects with `tag_name`, and each
+/// asset next to its `.sha256` and `.minisig` sidecars).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseSource {
+    /// Releases API base; `<api_url>/latest` and `<api_url>/tags/v<version>`
+    pub api_url: String,
+    /// Asset base; `<asset_base_url>/v<version>/<asset>`
+    pub asset_base_url: String,
+    /// Minisign public key trusted in addition to the key embedded at build
+    /// time, e.g. for a mirror that re-signs assets
+    pub trusted_key: Option<String>,
+    /// Stable releases only, or prereleases too
+    pub channel: ReleaseChannel,
+}
+
+impl Default for ReleaseSource {
+    fn default() -> Self {
+        Self {
+            api_url: DEFAULT_RELEASES_API_URL.to_string(),
+            asset_base_url: DEFAULT_ASSET_BASE_URL.to_string(),
+            trusted_key: None,
+            channel: ReleaseChannel::default(),
+        }
+    }
+}
+
+impl ReleaseSource {
+    /// Set the releases API base URL
+    pub fn with_api_url(mut self, url: &str) -> Result<Self> {
+        self.api_url = validate_release_url(url)?;
+        Ok(self)
+    }
+
+    /// Set the asset download base URL
+    pub fn with_asset_base_url(mut self, url: &str) -> Result<Self> {
+        self.asset_base_url = validate_release_url(url)?;
+        Ok(self)
+    }
+
+    /// Trust an extra minisign public key (the `RWQ...` line or the whole
+    /// `minisign.pub` file) for installer signatures
+    pub fn with_trusted_key(mut self, key: &str) -> Result<Self> {
+        let key = crate::signature::PublicKey::parse(key)
+            .map_err(|e| AppError::config(format!("trusted key: {}", e)))?;
+        self.trusted_key = Some(key.encoded());
+        Ok(self)
+    }
+
+    /// Follow the stable or the beta release channel
+    pub fn with_channel(mut self, channel: ReleaseChannel) -> Self {
+        self.channel = channel;
+        self
+    }
+
+    /// Whether this is the public GitHub release host with no extra trusted
+    /// key (the channel is not part of the host)
+    pub fn is_default(&self) -> bool {
+        let default = Self::default();
+        self.api_url == default.api_url
+            && self.asset_base_url == default.asset_base_url
+            && self.trusted_key == default.trusted_key
+    }
+
+    /// Whether API requests go to api.github.com, which is the only host
+    /// that receives a `GITHUB_TOKEN`/`GH_TOKEN` bearer token
+    pub fn is_github_api(&self) -> bool {
+        self.api_url.starts_with("https://api.github.com/")
+    }
+
+    /// API list of releases, newest first, prereleases included
+    pub fn releases_url(&self) -> String {
+        format!("{}?per_page=100", self.api_url)
+    }
+
+    /// API object for the latest release
+    pub fn latest_url(&self) -> String {
+        format!("{}/latest", self.api_url)
+    }
+
+    /// API object for the release tagged `v<version>`
+    pub fn tag_url(&self, version: &str) -> String {
+        format!("{}/tags/v{}", self.api_url, version)
+    }
+
+    /// Download URL for `asset`, pinned to the immutable `v<version>` tag
+    pub fn asset_url(&self, version: &str, asset: &str) -> String {
+        format!("{}/v{}/{}", self.asset_base_url, version, asset)
+    }
+}
+
+/// Release URLs must be `https://`, or `http://` to a loopback host (a local
+/// test stand-in). Asset URLs are interpolated into the installer-script
+/// command lines, so only a conservative URL character set is accepted.
+fn validate_release_url(url: &str) -> Result<String> {
+    let url = url.trim().trim_end_matches('/');
+    let rest = if let Some(rest) = url.strip_prefix("https://") {
+        rest
+    } else if let Some(rest) = url.strip_prefix("http://") {
+        let authority = rest.split('/').next().unwrap_or("");
+        let authority = authority
+            .rsplit_once('@')
+            .map_or(authority, |(_, host)| host);
+        let host = if authority.starts_with('[') {
+            authority.split_inclusive(']').next().unwrap_or("")
+        } else {
+            authority.split(':').next().unwrap_or("")
+        };
+        let loopback = host == "localhost"
+            || host == "[::1]"
+            || (host.starts_with("127.") && host.chars().all(|c| c.is_ascii_digit() || c == '.'));
+        if !loopback {
+            return Err(AppError::config(format!(
+                "release URL `{}` must use https:// (http:// is only allowed for localhost)",
+                url
+            )));
+        }
+        rest
+    } else {
+        return Err(AppError::config(format!(
+            "release URL `{}` must start with https://",
+            url
+        )));
+    };
+    let safe = rest.chars().all(|c| {
+        c.is_ascii_alphanumeric()
+            || matches!(
+                c,
+                '-' | '.' | '_' | '~' | '/' | ':' | '%' | '+' | '@' | '[' | ']'
+            )
+    });
+    if rest.is_empty() || !safe {
+        return Err(AppError::config(format!(
+            "release URL `{}` contains characters that are not allowed",
+            url
+        )));
+    }
+    Ok(url.to_string())
+}
+
+/// How far a metric is past its limits
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ThresholdLevel {
+    Ok,
+    Warning,
+    Critical,
+}
+
+/// Warning and critical limits for one metric. As in Nagios ranges, a value
+/// exactly on a limit is still within it.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    pub warn: f64,
+    pub crit: f64,
+    /// Lower values are the bad direction (battery health) instead of
+    /// higher ones (disk use)
+    pub lower_is_worse: bool,
+}
+
+impl Threshold {
+    /// Limits for a metric where higher is worse; `warn <= crit`
+    pub fn new(warn: f64, crit: f64) -> Self {
+        Self {
+            warn,
+            crit,
+            lower_is_worse: false,
+        }
+    }
+
+    /// Limits for a metric where lower is worse; `warn >= crit`
+    pub fn floor(warn: f64, crit: f64) -> Self {
+        Self {
+            warn,
+            crit,
+            lower_is_worse: true,
+        }
+    }
+
+    /// Classify `value` against these limits
+    pub fn level(&self, value: f64) -> ThresholdLevel {
+        let past = |limit: f64| {
+            if self.lower_is_worse {
+                value < limit
+            } else {
+                value > limit
+            }
+        };
+        if past(self.crit) {
+            ThresholdLevel::Critical
+        } else if past(self.warn) {
+            ThresholdLevel::Warning
+        } else {
+            ThresholdLevel::Ok
+        }
+    }
+}
+
+/// Metric names accepted in a threshold spec, in report order
+pub const THRESHOLD_METRICS: &[&str] = &["disk", "memory", "swap", "load", "battery"];
+
+/// Warning/critical limits for the metrics `tr300 check` evaluates
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// Root disk use, percent
+    pub disk: Threshold,
+    /// Memory use, percent
+    pub memory: Threshold,
+    /// Swap use, percent (skipped on hosts without swap)
+    pub swap: Threshold,
+    /// 5-minute load as a percent of logical CPU capacity
+    pub load: Threshold,
+    /// Battery health (full-charge vs design capacity), percent; lower is worse
+    pub battery: Threshold,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Self {
+            disk: Threshold::new(80.0, 90.0),
+            memory: Threshold::new(85.0, 95.0),
+            swap: Threshold::new(50.0, 80.0),
+            load: Threshold::new(100.0, 200.0),
+            battery: Threshold::floor(80.0, 60.0),
+        }
+    }
+}
+
+impl Thresholds {
+    /// Override limits from a spec such as `disk=85:95,load=150:300`.
+    /// Metrics not named keep their current limits.
+    pub fn with_spec(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let invalid = || {
+                AppError::config(format!(
+                    "invalid threshold `{}` (expected METRIC=WARN:CRIT, e.g. disk=80:90)",
+                    entry
+                ))
+            };
+            let (metric, limits) = entry.split_once('=').ok_or_else(invalid)?;
+            let (warn, crit) = limits.split_once(':').ok_or_else(invalid)?;
+            let parse = |v: &str| {
+                v.trim()
+                    .trim_end_matches('%')
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|v| v.is_finite() && *v >= 0.0)
+            };
+            let (Some(warn), Some(crit)) = (parse(warn), parse(crit)) else {
+                return Err(invalid());
+            };
+            let slot = match metric.trim().to_ascii_lowercase().as_str() {
+                "disk" => &mut self.disk,
+                "memory" | "mem" => &mut self.memory,
+                "swap" => &mut self.swap,
+                "load" => &mut self.load,
+                "battery" => &mut self.battery,
+                other => {
+                    return Err(AppError::config(format!(
+                        "unknown threshold metric `{}` (expected one of {})",
+                        other,
+                        THRESHOLD_METRICS.join(", ")
+                    )))
+                }
+            };
+            let ordered = if slot.lower_is_worse {
+                warn >= crit
+            } else {
+                warn <= crit
+            };
+            if !ordered {
+                return Err(AppError::config(format!(
+                    "threshold `{}` is out of order: the warning limit must come {} the critical one",
+                    entry,
+                    if slot.lower_is_worse { "above" } else { "below" }
+                )));
+            }
+            slot.warn = warn;
+            slot.crit = crit;
+        }
+        Ok(self)
+    }
 }
 
 /// Box-drawing character set
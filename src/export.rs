@@ -0,0 +1,362 @@
+//! Flat and YAML renderings of the JSON report
+//!
+//! `--format env`, `--format csv`, and `--format yaml` are derived from
+//! [`report::json_value`](crate::report::json_value), so every format carries
+//! the same fields under the same names. The flat forms join the path to each
+//! value (`cpu.cores`, `TR300_CPU_CORES`) and keep one column per field on
+//! every machine, so CSV rows from a fleet line up.
+
+use serde_json::Value;
+
+/// Per-item lists that have no single-row form; they stay JSON/YAML-only.
+const LIST_SECTIONS: &[&str] = &["errors", "processes", "sessions"];
+
+/// Sections that are `null` when their collector found nothing, with the
+/// keys they have when present (sorted, as serde_json orders them). A null
+/// section still gets its columns so the flat layout does not depend on the
+/// machine.
+const NULLABLE_SECTIONS: &[(&str, &[&str])] = &[
+    (
+        "containers",
+        &[
+            "image_bytes",
+            "paused",
+            "running",
+            "runtime",
+            "socket",
+            "stopped",
+            "version",
+        ],
+    ),
+    (
+        "services",
+        &["boot_timing", "failed_units", "last_boot", "system_state"],
+    ),
+    (
+        "services.boot_timing",
+        &[
+            "firmware_ms",
+            "initrd_ms",
+            "kernel_ms",
+            "loader_ms",
+            "total_ms",
+            "userspace_ms",
+        ],
+    ),
+    (
+        "time",
+        &[
+            "ntp_source",
+            "ntp_synchronized",
+            "offset_ms",
+            "rtc_in_local_time",
+            "sync_service",
+            "timezone",
+        ],
+    ),
+];
+
+/// Flatten a report into `(dotted.path, text)` pairs in a stable order.
+///
+/// Nulls become empty text and lists of plain values are joined with `, `.
+pub fn flatten(report: &Value) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    flatten_into("", report, &mut fields);
+    fields
+}
+
+fn flatten_into(path: &str, value: &Value, fields: &mut Vec<(String, String)>) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let child = child(key);
+                if !LIST_SECTIONS.contains(&child.as_str()) {
+                    flatten_into(&child, value, fields);
+                }
+            }
+        }
+        Value::Null => match NULLABLE_SECTIONS
+            .iter()
+            .find(|(section, _)| *section == path)
+        {
+            Some((_, keys)) => {
+                for key in *keys {
+                    flatten_into(&child(key), &Value::Null, fields);
+                }
+            }
+            None => fields.push((path.to_string(), String::new())),
+        },
+        Value::Array(items) => fields.push((
+            path.to_string(),
+            items.iter().map(scalar_text).collect::<Vec<_>>().join(", "),
+        )),
+        scalar => fields.push((path.to_string(), scalar_text(scalar))),
+    }
+}
+
+fn scalar_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Look up one dotted path (`memory.total_bytes`, `network.dns_servers.0`)
+/// and return it as plain text for `--get`: strings unquoted, null as an
+/// empty string, and objects or lists as compact JSON. `None` when the
+/// report has no such field.
+pub fn get(report: &Value, path: &str) -> Option<String> {
+    let pointer: String = path
+        .split('.')
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect();
+    report.pointer(&pointer).map(|value| match value {
+        Value::Object(_) | Value::Array(_) => value.to_string(),
+        scalar => scalar_text(scalar),
+    })
+}
+
+/// `KEY=value` lines for `source <(tr300 --format env)`: keys are the
+/// upper-cased path with a `TR300_` prefix, and values are single-quoted
+/// whenever the shell could otherwise split or expand them.
+pub fn env(report: &Value) -> String {
+    flatten(report)
+        .into_iter()
+        .map(|(path, value)| {
+            format!(
+                "TR300_{}={}\n",
+                path.replace('.', "_").to_ascii_uppercase(),
+                shell_quote(&value)
+            )
+        })
+        .collect()
+}
+
+fn shell_quote(value: &str) -> String {
+    let safe = value
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "._-+/:@%,".contains(c));
+    if safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// One header row and one value row. Columns are the same on every machine,
+/// so `tail -n 1` output from a fleet can be appended to one sheet.
+pub fn csv(report: &Value) -> String {
+    let (header, row): (Vec<String>, Vec<String>) = flatten(report)
+        .into_iter()
+        .map(|(path, value)| (csv_field(&path), csv_field(&value)))
+        .unzip();
+    format!("{}\n{}\n", header.join(","), row.join(","))
+}
+
+/// RFC 4180 quoting: wrap in quotes when the field holds a separator, quote,
+/// line break, or edge whitespace, doubling embedded quotes.
+fn csv_field(value: &str) -> String {
+    let needs_quotes =
+        value.contains([',', '"', '\n', '\r']) || value.starts_with(' ') || value.ends_with(' ');
+    if needs_quotes {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Block-style YAML of the whole report, including the per-item lists.
+/// Strings are written double-quoted with JSON escapes, which YAML reads
+/// back unchanged, so values like `no`, `1.10`, or `null` keep their type.
+pub fn yaml(report: &Value) -> String {
+    let mut out = String::new();
+    write_yaml(report, 0, &mut out);
+    out
+}
+
+fn write_yaml(value: &Value, indent: usize, out: &mut String) {
+    let pad = " ".repeat(indent);
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    Value::Object(m) if !m.is_empty() => {
+                        out.push_str(&format!("{}{}:\n", pad, key));
+                        write_yaml(value, indent + 2, out);
+                    }
+                    Value::Array(a) if !a.is_empty() => {
+                        out.push_str(&format!("{}{}:\n", pad, key));
+                        write_yaml(value, indent + 2, out);
+                    }
+                    scalar => out.push_str(&format!("{}{}: {}\n", pad, key, yaml_scalar(scalar))),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                match item {
+                    Value::Object(m) if !m.is_empty() => write_yaml_item(item, indent, out),
+                    Value::Array(a) if !a.is_empty() => write_yaml_item(item, indent, out),
+                    scalar => out.push_str(&format!("{}- {}\n", pad, yaml_scalar(scalar))),
+                }
+            }
+        }
+        scalar => out.push_str(&format!("{}{}\n", pad, yaml_scalar(scalar))),
+    }
+}
+
+/// A nested collection as a sequence item: rendered one level deeper, with
+/// its first line's indent replaced by the `- ` marker.
+fn write_yaml_item(item: &Value, indent: usize, out: &mut String) {
+    let mut block = String::new();
+    write_yaml(item, indent + 2, &mut block);
+    out.push_str(&" ".repeat(indent));
+    out.push_str("- ");
+    out.push_str(&block[indent + 2..]);
+}
+
+fn yaml_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collectors::containers::ContainerInfo;
+    use crate::collectors::services::{BootTiming, ServicesInfo};
+    use crate::collectors::time::TimeInfo;
+    use crate::report::{json_value, tests::fixture_info};
+
+    fn value<'a>(fields: &'a [(String, String)], path: &str) -> &'a str {
+        &fields
+            .iter()
+            .find(|(p, _)| p == path)
+            .unwrap_or_else(|| panic!("no field {path}"))
+            .1
+    }
+
+    #[test]
+    fn columns_do_not_depend_on_optional_sections() {
+        let bare = fixture_info();
+        let mut full = fixture_info();
+        full.services = Some(ServicesInfo {
+            system_state: Some("running".to_string()),
+            boot_timing: Some(BootTiming::default()),
+            ..ServicesInfo::default()
+        });
+        full.time = Some(TimeInfo::default());
+        full.containers = Some(ContainerInfo {
+            runtime: "Docker".to_string(),
+            version: None,
+            socket: "/var/run/docker.sock".to_string(),
+            running: 1,
+            paused: 0,
+            stopped: 0,
+            image_bytes: None,
+        });
+
+        let paths = |info| {
+            flatten(&json_value(&info))
+                .into_iter()
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>()
+        };
+        let full_paths = paths(full);
+        assert_eq!(paths(bare), full_paths);
+        assert!(full_paths.contains(&"services.boot_timing.total_ms".to_string()));
+        assert!(!full_paths.iter().any(|p| p.starts_with("processes")));
+    }
+
+    #[test]
+    fn flattens_scalars_lists_and_nulls() {
+        let mut info = fixture_info();
+        info.dns_servers = vec!["1.1.1.1".to_string(), "9.9.9.9".to_string()];
+        info.services = None;
+        let fields = flatten(&json_value(&info));
+
+        assert_eq!(value(&fields, "schema_version"), "1");
+        assert_eq!(value(&fields, "cpu.cores"), info.cores.to_string());
+        assert_eq!(value(&fields, "network.dns_servers"), "1.1.1.1, 9.9.9.9");
+        assert_eq!(value(&fields, "services.system_state"), "");
+    }
+
+    #[test]
+    fn get_prints_one_value_by_path() {
+        let report = serde_json::json!({
+            "memory": {"total_bytes": 17179869184_u64},
+            "network": {"hostname": "lab-01", "dns_servers": ["1.1.1.1"], "machine_ip": null},
+        });
+        assert_eq!(
+            get(&report, "memory.total_bytes").as_deref(),
+            Some("17179869184")
+        );
+        assert_eq!(get(&report, "network.hostname").as_deref(), Some("lab-01"));
+        assert_eq!(
+            get(&report, "network.dns_servers.0").as_deref(),
+            Some("1.1.1.1")
+        );
+        assert_eq!(
+            get(&report, "network.dns_servers").as_deref(),
+            Some("[\"1.1.1.1\"]")
+        );
+        assert_eq!(get(&report, "network.machine_ip").as_deref(), Some(""));
+        assert_eq!(get(&report, "memory.free_bytes"), None);
+    }
+
+    #[test]
+    fn env_lines_are_shell_safe() {
+        let mut info = fixture_info();
+        info.hostname = "it's $(here)".to_string();
+        let env = env(&json_value(&info));
+
+        assert!(env.contains(&format!("TR300_CPU_CORES={}\n", info.cores)));
+        assert!(env.contains("TR300_NETWORK_HOSTNAME='it'\\''s $(here)'\n"));
+        assert!(env.contains("TR300_SERVICES_SYSTEM_STATE=\n"));
+        assert!(env
+            .lines()
+            .all(|line| line.starts_with("TR300_") && line.contains('=')));
+    }
+
+    #[test]
+    fn csv_has_one_quoted_value_row_under_the_header() {
+        let mut info = fixture_info();
+        info.processor = "Chip, \"Pro\"".to_string();
+        let csv = csv(&json_value(&info));
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("collection_mode,containers.image_bytes,"));
+        assert!(lines[1].contains(",\"Chip, \"\"Pro\"\"\","));
+        assert_eq!(csv_field(" padded"), "\" padded\"");
+    }
+
+    #[test]
+    fn yaml_nests_maps_and_lists_with_quoted_strings() {
+        let report = serde_json::json!({
+            "cpu": {"cores": 8, "gpus": ["A", "B"], "hypervisor": null},
+            "processes": [{"pid": 1, "command": "init"}],
+            "sessions": [],
+            "os": {"version": "1.10", "name": "no"},
+        });
+        assert_eq!(
+            yaml(&report),
+            "cpu:\n  cores: 8\n  gpus:\n    - \"A\"\n    - \"B\"\n  hypervisor: null\n\
+             os:\n  name: \"no\"\n  version: \"1.10\"\n\
+             processes:\n  - command: \"init\"\n    pid: 1\n\
+             sessions: []\n"
+        );
+    }
+}
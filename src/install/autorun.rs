@@ -0,0 +1,164 @@
+//! Auto-run policy support shared by the shell hooks and the binary
+//!
+//! The trigger conditions a shell can check cheaply (SSH login, login shell)
+//! are rendered into each hook dialect at install time. The once-per-N-hours
+//! trigger is enforced by the binary itself via `--autorun-interval`, so every
+//! dialect shares one stamp-file implementation instead of four shell ones.
+
+use crate::config::{AutorunPolicy, AutorunTrigger};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Hidden flag the hook passes for `AutorunTrigger::EveryHours`.
+pub const INTERVAL_FLAG: &str = "--autorun-interval";
+
+/// The `tr300 …` command line the hook runs for `policy`.
+pub(crate) fn command_line(policy: &AutorunPolicy) -> String {
+    let mut command = vec!["tr300".to_string()];
+    command.extend(policy.args.iter().cloned());
+    if let AutorunTrigger::EveryHours(hours) = policy.trigger {
+        command.push(INTERVAL_FLAG.to_string());
+        command.push(hours.to_string());
+    }
+    command.join(" ")
+}
+
+/// Stamp file recording the last interval-gated auto-run.
+pub fn stamp_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("tr300").join("autorun.stamp"))
+}
+
+/// Decide whether an interval-gated auto-run is due, claiming the slot by
+/// touching the stamp when it is. Fails open: if the cache directory cannot
+/// be used the report runs, since skipping silently forever would be worse.
+pub fn claim_interval_slot(hours: u32) -> bool {
+    match stamp_path() {
+        Some(stamp) => claim_interval_slot_at(&stamp, hours),
+        None => true,
+    }
+}
+
+/// `claim_interval_slot` for any stamp file; also gates the background
+/// release check behind `--update-notice`.
+pub(crate) fn claim_interval_slot_at(stamp: &Path, hours: u32) -> bool {
+    let last_run = fs::metadata(stamp).and_then(|meta| meta.modified()).ok();
+    if !interval_elapsed(last_run, SystemTime::now(), hours) {
+        return false;
+    }
+    if let Some(parent) = stamp.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let _ = fs::write(stamp, b"");
+    true
+}
+
+fn interval_elapsed(last_run: Option<SystemTime>, now: SystemTime, hours: u32) -> bool {
+    let Some(last_run) = last_run else {
+        return true;
+    };
+    match now.duration_since(last_run) {
+        Ok(elapsed) => elapsed >= Duration::from_secs(u64::from(hours) * 3_600),
+        // A stamp in the future means the clock moved backwards; run rather
+        // than stay silent until the clock catches up.
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triggers_parse_and_round_trip() {
+        for text in ["always", "ssh", "login", "6h"] {
+            let trigger: AutorunTrigger = text.parse().unwrap();
+            assert_eq!(trigger.to_string(), text);
+        }
+        assert_eq!(
+            "12H".parse::<AutorunTrigger>().unwrap(),
+            AutorunTrigger::EveryHours(12)
+        );
+        for bad in ["0h", "hourly", "6", ""] {
+            assert!(bad.parse::<AutorunTrigger>().is_err(), "{bad} should fail");
+        }
+    }
+
+    #[test]
+    fn command_line_appends_the_interval_gate() {
+        let policy = AutorunPolicy::default();
+        assert_eq!(command_line(&policy), "tr300 --fast");
+
+        let policy = AutorunPolicy::default()
+            .with_trigger(AutorunTrigger::EveryHours(6))
+            .with_args("--fast --no-color")
+            .unwrap();
+        assert_eq!(
+            command_line(&policy),
+            "tr300 --fast --no-color --autorun-interval 6"
+        );
+    }
+
+    #[test]
+    fn autorun_args_reject_shell_syntax() {
+        for bad in [
+            "--fast; rm -rf ~",
+            "$(id)",
+            "--title=`x`",
+            "report",
+            "--a|b",
+        ] {
+            assert!(
+                AutorunPolicy::default().with_args(bad).is_err(),
+                "{bad} should be rejected"
+            );
+        }
+        assert!(AutorunPolicy::default()
+            .with_args("--ascii --title=LAB-01")
+            .is_ok());
+    }
+
+    #[test]
+    fn autorun_args_accept_only_report_flags() {
+        for bad in [
+            "--uninstall --complete --yes",
+            "--fast --update",
+            "--install",
+            "--save=markdown",
+            "--save",
+            "--title",
+            "--title=",
+            "--fast=1",
+            "-V",
+            "update",
+        ] {
+            assert!(
+                AutorunPolicy::default().with_args(bad).is_err(),
+                "{bad} should be rejected"
+            );
+        }
+        let policy = AutorunPolicy::default()
+            .with_args("--fast --no-color --top=5 --fields=cpu,memory --update-notice=24h")
+            .unwrap();
+        assert_eq!(policy.args.len(), 5);
+    }
+
+    #[test]
+    fn interval_is_measured_from_the_stamp() {
+        let now = SystemTime::now();
+        let hour = Duration::from_secs(3_600);
+        assert!(interval_elapsed(None, now, 6));
+        assert!(!interval_elapsed(Some(now - 5 * hour), now, 6));
+        assert!(interval_elapsed(Some(now - 6 * hour), now, 6));
+        assert!(interval_elapsed(Some(now + hour), now, 6));
+    }
+
+    #[test]
+    fn a_claimed_slot_stays_taken_until_the_interval_passes() {
+        let dir = tempfile::tempdir().unwrap();
+        let stamp = dir.path().join("nested").join("check.stamp");
+        assert!(claim_interval_slot_at(&stamp, 24));
+        assert!(stamp.exists());
+        assert!(!claim_interval_slot_at(&stamp, 24));
+    }
+}
@@ -8,15 +8,21 @@ pub mod unix;
 #[cfg(windows)]
 pub mod windows;
 
+pub mod autorun;
+pub mod plan;
 pub mod prompt;
 mod shared;
+pub mod status;
 
+use crate::config::AutorunPolicy;
 use crate::error::Result;
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
+pub use plan::{ChangeKind, PlannedChange};
 pub use prompt::{confirm_complete_uninstall, prompt_uninstall_option, UninstallOption};
+pub use status::InstallStatus;
 
 // ── Shared file-write safety primitives (v3.15.2+) ──────────────────
 //
@@ -146,20 +152,44 @@ pub(crate) fn check_marker_balance(
     ))
 }
 
+/// Options for `tr300 install`
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct InstallOptions {
+    /// When and how the shell hook runs the report
+    pub autorun: AutorunPolicy,
+    /// Install the hook for every user (`/etc/profile.d`) instead of the
+    /// current user's profiles. Unix only; requires root.
+    pub system: bool,
+}
+
 /// Install tr300 to the system
 pub fn install() -> Result<()> {
+    install_with_options(&InstallOptions::default())
+}
+
+/// Install tr300 to the system with explicit options
+pub fn install_with_options(options: &InstallOptions) -> Result<()> {
     #[cfg(unix)]
     {
-        unix::install()
+        if options.system {
+            unix::install_system(&options.autorun)
+        } else {
+            unix::install(&options.autorun)
+        }
     }
 
     #[cfg(windows)]
     {
-        windows::install()
+        if options.system {
+            return Err(system_install_unsupported());
+        }
+        windows::install(&options.autorun)
     }
 
     #[cfg(not(any(unix, windows)))]
     {
+        let _ = options;
         Err(crate::error::AppError::platform(
             "Self-installation not supported on this platform",
         ))
@@ -186,6 +216,152 @@ pub fn uninstall() -> Result<()> {
     }
 }
 
+/// Every file change `install_with_options` would make, without making it
+pub fn plan_install(options: &InstallOptions) -> Result<Vec<PlannedChange>> {
+    #[cfg(unix)]
+    {
+        if options.system {
+            unix::plan_install_system(&options.autorun)
+        } else {
+            unix::plan_install(&options.autorun)
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        if options.system {
+            return Err(system_install_unsupported());
+        }
+        windows::plan_install(&options.autorun)
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = options;
+        Err(crate::error::AppError::platform(
+            "Self-installation not supported on this platform",
+        ))
+    }
+}
+
+/// Every change an uninstall would make: profile edits for
+/// `ProfileOnly`, plus the binary for `Complete`. `system` plans the
+/// removal of the `install --system` drop-ins instead.
+pub fn plan_uninstall(option: UninstallOption, system: bool) -> Result<Vec<PlannedChange>> {
+    if option == UninstallOption::Cancel {
+        return Ok(Vec::new());
+    }
+
+    #[cfg(unix)]
+    let mut changes = unix::plan_uninstall(system)?;
+
+    #[cfg(windows)]
+    let mut changes = if system {
+        return Err(system_install_unsupported());
+    } else {
+        windows::plan_uninstall()?
+    };
+
+    #[cfg(not(any(unix, windows)))]
+    let mut changes: Vec<PlannedChange> = {
+        let _ = system;
+        return Err(crate::error::AppError::platform(
+            "Self-uninstallation not supported on this platform",
+        ));
+    };
+
+    if option == UninstallOption::Complete && !system {
+        if let Some(binary) = find_binary_location() {
+            changes.extend(rollback_copy(&binary).map(PlannedChange::binary_removed));
+            changes.push(PlannedChange::binary_removed(binary));
+        }
+    }
+    Ok(changes)
+}
+
+/// The copy kept beside `binary` for `tr300 update --rollback`, if any
+pub(crate) fn rollback_copy(binary: &Path) -> Option<PathBuf> {
+    crate::update::previous_binary_path(binary).filter(|path| path.is_file())
+}
+
+/// Remove the system-wide hook installed by `install --system`
+pub fn uninstall_system() -> Result<()> {
+    #[cfg(unix)]
+    {
+        unix::uninstall_system()
+    }
+
+    #[cfg(not(unix))]
+    {
+        Err(system_install_unsupported())
+    }
+}
+
+#[cfg(not(unix))]
+fn system_install_unsupported() -> crate::error::AppError {
+    crate::error::AppError::platform(
+        "`--system` installs use /etc/profile.d and are Unix-only. On Windows, deploy the Global MSI for machine-wide installs.",
+    )
+}
+
+/// Inspect the shell integration for `install --status`
+pub fn check_status() -> InstallStatus {
+    #[cfg(unix)]
+    let (candidates, definitions, defines_report) = (
+        unix::status_profile_candidates(),
+        dirs::home_dir()
+            .map(|home| unix::report_definitions(&home))
+            .unwrap_or_default(),
+        unix::defines_report as fn(&str) -> bool,
+    );
+
+    #[cfg(windows)]
+    let (candidates, definitions, defines_report) = {
+        let profiles = windows::status_profile_candidates();
+        let definitions = windows::report_definitions(&profiles);
+        (
+            profiles,
+            definitions,
+            windows::defines_report as fn(&str) -> bool,
+        )
+    };
+
+    #[cfg(not(any(unix, windows)))]
+    let (candidates, definitions, defines_report): (
+        Vec<PathBuf>,
+        Vec<String>,
+        fn(&str) -> bool,
+    ) = (Vec::new(), Vec::new(), |_| false);
+
+    let mut profiles: Vec<status::ProfileStatus> = Vec::new();
+    for path in candidates {
+        if profiles.iter().any(|p| p.path == path) {
+            continue;
+        }
+        profiles.extend(status::inspect_profile(&path, defines_report));
+    }
+    // Only a definition after the hook shadows the alias; the alias
+    // shadows everything else, including executables on PATH.
+    let report_conflicts: Vec<String> = profiles
+        .iter()
+        .flat_map(|profile| profile.shadowing.iter().cloned())
+        .collect();
+    let report_shadowed = definitions
+        .into_iter()
+        .filter(|definition| !report_conflicts.contains(definition))
+        .collect();
+
+    InstallStatus {
+        profiles,
+        report_conflicts,
+        report_shadowed,
+        binaries: std::env::var_os("PATH")
+            .map(|path| status::binaries_on_path(&path))
+            .unwrap_or_default(),
+        running: std::env::current_exe().ok(),
+    }
+}
+
 /// Get the installation path
 pub fn install_path() -> Option<PathBuf> {
     #[cfg(unix)]
@@ -0,0 +1,385 @@
+//! Change plans for `install` / `uninstall --dry-run`
+//!
+//! The platform modules compute every file change up front as a
+//! [`PlannedChange`] and only then apply it, so a dry run shows exactly what
+//! the real run would write. The JSON shape mirrors `migrate-cleanup --json`
+//! (`action` / `dry_run` / `requested` / `targets` / `success`) so the same
+//! config-management glue can read both.
+
+use std::path::PathBuf;
+
+/// What a planned change does to its file
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// The file does not exist yet and would be created
+    Create,
+    /// The file would be rewritten
+    Modify,
+    /// The file would be deleted
+    Delete,
+    /// The file would be rewritten with identical content
+    Unchanged,
+}
+
+impl ChangeKind {
+    /// Stable `status` value for the JSON plan
+    pub fn json_status(self) -> &'static str {
+        match self {
+            ChangeKind::Create => "would_create",
+            ChangeKind::Modify => "would_modify",
+            ChangeKind::Delete => "would_remove",
+            ChangeKind::Unchanged => "unchanged",
+        }
+    }
+
+    fn word(self) -> &'static str {
+        match self {
+            ChangeKind::Create => "create",
+            ChangeKind::Modify => "modify",
+            ChangeKind::Delete => "delete",
+            ChangeKind::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// One file an install or uninstall would touch
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedChange {
+    /// Target kind: `shell_profile` or `binary`
+    pub id: &'static str,
+    /// File the change applies to
+    pub path: PathBuf,
+    /// What happens to the file
+    pub kind: ChangeKind,
+    /// Current text content (`None` when absent or not a text file)
+    pub before: Option<String>,
+    /// Content after the change (`None` when deleted or not a text file)
+    pub after: Option<String>,
+}
+
+impl PlannedChange {
+    /// A shell profile rewritten from `before` to `after` (or created when
+    /// `before` is `None`).
+    pub(crate) fn profile(path: PathBuf, before: Option<String>, after: String) -> Self {
+        let kind = match &before {
+            None => ChangeKind::Create,
+            Some(before) if *before == after => ChangeKind::Unchanged,
+            Some(_) => ChangeKind::Modify,
+        };
+        Self {
+            id: "shell_profile",
+            path,
+            kind,
+            before,
+            after: Some(after),
+        }
+    }
+
+    /// A TR-300-owned shell drop-in that would be deleted outright.
+    #[cfg_attr(not(any(unix, test)), allow(dead_code))]
+    pub(crate) fn profile_removed(path: PathBuf, before: String) -> Self {
+        Self {
+            id: "shell_profile",
+            path,
+            kind: ChangeKind::Delete,
+            before: Some(before),
+            after: None,
+        }
+    }
+
+    /// The tr300 binary removed by a complete uninstall.
+    pub(crate) fn binary_removed(path: PathBuf) -> Self {
+        Self {
+            id: "binary",
+            path,
+            kind: ChangeKind::Delete,
+            before: None,
+            after: None,
+        }
+    }
+
+    /// Unified diff of the text change; empty for binaries and no-ops.
+    pub fn diff(&self) -> String {
+        if self.before.is_none() && self.after.is_none() {
+            return String::new();
+        }
+        let label = self.path.display().to_string();
+        let old_label = if self.before.is_some() {
+            label.as_str()
+        } else {
+            "/dev/null"
+        };
+        let new_label = if self.after.is_some() {
+            label.as_str()
+        } else {
+            "/dev/null"
+        };
+        unified_diff(
+            old_label,
+            new_label,
+            self.before.as_deref().unwrap_or_default(),
+            self.after.as_deref().unwrap_or_default(),
+        )
+    }
+}
+
+/// Human-readable plan: one line per file followed by its diff.
+pub fn render_plan(action: &str, changes: &[PlannedChange]) -> String {
+    let mut out = format!(
+        "Planned changes for `tr300 {}` (dry-run — nothing will be written):\n",
+        action
+    );
+    if changes.is_empty() {
+        out.push_str("  · nothing to do\n");
+        return out;
+    }
+    for change in changes {
+        out.push_str(&format!(
+            "  · {} {}\n",
+            change.kind.word(),
+            change.path.display()
+        ));
+    }
+    for change in changes {
+        let diff = change.diff();
+        if !diff.is_empty() {
+            out.push('\n');
+            out.push_str(&diff);
+        }
+    }
+    out
+}
+
+/// JSON plan in the `migrate-cleanup --json` shape.
+pub fn plan_json(
+    action: &str,
+    requested: serde_json::Value,
+    changes: &[PlannedChange],
+) -> serde_json::Value {
+    let targets: Vec<serde_json::Value> = changes
+        .iter()
+        .map(|change| {
+            let diff = change.diff();
+            serde_json::json!({
+                "id": change.id,
+                "label": change.path.file_name().map(|n| n.to_string_lossy().to_string()),
+                "status": change.kind.json_status(),
+                "detail": serde_json::Value::Null,
+                "path": change.path.display().to_string(),
+                "diff": if diff.is_empty() { None } else { Some(diff) },
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "action": action,
+        "dry_run": true,
+        "requested": requested,
+        "targets": targets,
+        "success": true,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Lines of context around each hunk, as in `diff -u`.
+const CONTEXT: usize = 3;
+
+/// Minimal `diff -u` over lines. Shell profiles are small and TR-300 only
+/// touches one block, so an LCS table over the differing middle (after
+/// trimming the common prefix and suffix) is plenty.
+pub(crate) fn unified_diff(old_label: &str, new_label: &str, before: &str, after: &str) -> String {
+    let a: Vec<&str> = before.lines().collect();
+    let b: Vec<&str> = after.lines().collect();
+    let ops = edit_script(&a, &b);
+    if ops.iter().all(|(op, _)| *op == Op::Equal) {
+        return String::new();
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", old_label, new_label);
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != Op::Equal)
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut group_start = 0;
+    while group_start < changed.len() {
+        let mut group_end = group_start;
+        while group_end + 1 < changed.len()
+            && changed[group_end + 1] - changed[group_end] <= 2 * CONTEXT + 1
+        {
+            group_end += 1;
+        }
+        let from = changed[group_start].saturating_sub(CONTEXT);
+        let to = (changed[group_end] + CONTEXT + 1).min(ops.len());
+
+        let old_before = ops[..from]
+            .iter()
+            .filter(|(op, _)| *op != Op::Insert)
+            .count();
+        let new_before = ops[..from]
+            .iter()
+            .filter(|(op, _)| *op != Op::Delete)
+            .count();
+        let hunk = &ops[from..to];
+        let old_len = hunk.iter().filter(|(op, _)| *op != Op::Insert).count();
+        let new_len = hunk.iter().filter(|(op, _)| *op != Op::Delete).count();
+        // `diff -u` numbers an empty range by the line before it.
+        let old_start = if old_len == 0 {
+            old_before
+        } else {
+            old_before + 1
+        };
+        let new_start = if new_len == 0 {
+            new_before
+        } else {
+            new_before + 1
+        };
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_start, old_len, new_start, new_len
+        ));
+        for (op, line) in hunk {
+            let sign = match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            };
+            out.push(sign);
+            out.push_str(line);
+            out.push('\n');
+        }
+        group_start = group_end + 1;
+    }
+    out
+}
+
+fn edit_script<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(Op, &'a str)> {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let suffix = a[prefix..]
+        .iter()
+        .rev()
+        .zip(b[prefix..].iter().rev())
+        .take_while(|(x, y)| x == y)
+        .count();
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    // lcs[i][j] = LCS length of a_mid[i..] and b_mid[j..]
+    let mut lcs = vec![vec![0u32; b_mid.len() + 1]; a_mid.len() + 1];
+    for i in (0..a_mid.len()).rev() {
+        for j in (0..b_mid.len()).rev() {
+            lcs[i][j] = if a_mid[i] == b_mid[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops: Vec<(Op, &str)> = a[..prefix].iter().map(|l| (Op::Equal, *l)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a_mid.len() || j < b_mid.len() {
+        if i < a_mid.len() && j < b_mid.len() && a_mid[i] == b_mid[j] {
+            ops.push((Op::Equal, a_mid[i]));
+            i += 1;
+            j += 1;
+        } else if i < a_mid.len() && (j == b_mid.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push((Op::Delete, a_mid[i]));
+            i += 1;
+        } else {
+            ops.push((Op::Insert, b_mid[j]));
+            j += 1;
+        }
+    }
+    ops.extend(a[a.len() - suffix..].iter().map(|l| (Op::Equal, *l)));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unified_diff_shows_an_appended_block_with_context() {
+        let before = "one\ntwo\nthree\nfour\nfive\n";
+        let after = "one\ntwo\nthree\nfour\nfive\n\n# TR-300 Machine Report\n# End TR-300\n";
+        assert_eq!(
+            unified_diff("a", "a", before, after),
+            "--- a\n+++ a\n@@ -3,3 +3,6 @@\n three\n four\n five\n+\n+# TR-300 Machine Report\n+# End TR-300\n"
+        );
+        assert_eq!(unified_diff("a", "a", before, before), "");
+    }
+
+    #[test]
+    fn unified_diff_splits_distant_changes_into_hunks() {
+        let before: String = (1..=20).map(|n| format!("{n}\n")).collect();
+        let after: String = (1..=20)
+            .map(|n| match n {
+                2 => "two\n".to_string(),
+                19 => "nineteen\n".to_string(),
+                _ => format!("{n}\n"),
+            })
+            .collect();
+        let diff = unified_diff("f", "f", &before, &after);
+        assert_eq!(diff.matches("@@ -").count(), 2, "{diff}");
+        assert!(diff.contains("@@ -1,5 +1,5 @@\n 1\n-2\n+two\n"), "{diff}");
+        assert!(diff.contains("-19\n+nineteen\n 20\n"), "{diff}");
+    }
+
+    #[test]
+    fn created_and_deleted_files_diff_against_dev_null() {
+        let created = PlannedChange::profile(PathBuf::from("tr300.fish"), None, "x\n".into());
+        assert_eq!(created.kind, ChangeKind::Create);
+        assert_eq!(
+            created.diff(),
+            "--- /dev/null\n+++ tr300.fish\n@@ -0,0 +1,1 @@\n+x\n"
+        );
+
+        let deleted = PlannedChange::profile_removed(PathBuf::from("tr300.fish"), "x\n".into());
+        assert_eq!(
+            deleted.diff(),
+            "--- tr300.fish\n+++ /dev/null\n@@ -1,1 +0,0 @@\n-x\n"
+        );
+
+        let binary = PlannedChange::binary_removed(PathBuf::from("/usr/local/bin/tr300"));
+        assert_eq!(binary.diff(), "");
+    }
+
+    #[test]
+    fn json_plan_mirrors_the_migrate_cleanup_contract() {
+        let changes = vec![
+            PlannedChange::profile(
+                PathBuf::from("/home/u/.bashrc"),
+                Some("a\n".into()),
+                "a\nb\n".into(),
+            ),
+            PlannedChange::binary_removed(PathBuf::from("/home/u/.local/bin/tr300")),
+        ];
+        let json = plan_json(
+            "uninstall",
+            serde_json::json!({"mode": "complete"}),
+            &changes,
+        );
+        assert_eq!(json["action"], "uninstall");
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["targets"][0]["status"], "would_modify");
+        assert_eq!(json["targets"][0]["label"], ".bashrc");
+        assert!(json["targets"][0]["diff"]
+            .as_str()
+            .unwrap()
+            .contains("+b\n"));
+        assert_eq!(json["targets"][1]["id"], "binary");
+        assert_eq!(json["targets"][1]["status"], "would_remove");
+        assert!(json["targets"][1]["diff"].is_null());
+    }
+}
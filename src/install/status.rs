@@ -0,0 +1,425 @@
+//! `tr300 install --status`: health check for the shell integration
+//!
+//! Read-only. Reports which profiles carry the TR-300 marker block (and
+//! whether each block is balanced), whether the `report` alias is
+//! redefined after it, and every `tr300` binary on `PATH` in resolution
+//! order.
+
+use super::check_marker_balance;
+use super::shared::{MARKER_END, MARKER_START};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// One profile that contains TR-300 marker lines
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileStatus {
+    /// Profile path
+    pub path: PathBuf,
+    /// TR-300 blocks in the profile (lines containing the opening marker)
+    pub blocks: usize,
+    /// Why the install would refuse to touch this profile, as reported by
+    /// `check_marker_balance`
+    pub marker_error: Option<String>,
+    /// Definitions of `report` after the last TR-300 block, which shadow
+    /// its alias; one `path:line  text` entry each
+    pub shadowing: Vec<String>,
+}
+
+impl ProfileStatus {
+    /// Start and end markers pair up (see `check_marker_balance`)
+    pub fn balanced(&self) -> bool {
+        self.marker_error.is_none()
+    }
+}
+
+/// Snapshot of the shell integration
+#[non_exhaustive]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstallStatus {
+    /// Profiles that contain TR-300 marker lines
+    pub profiles: Vec<ProfileStatus>,
+    /// Definitions of `report` after a TR-300 block, which shadow the alias
+    pub report_conflicts: Vec<String>,
+    /// Other definitions of `report`, such as executables on `PATH`, which
+    /// the alias shadows; listed for information only
+    pub report_shadowed: Vec<String>,
+    /// Distinct `tr300` binaries on `PATH`; the first one wins
+    pub binaries: Vec<PathBuf>,
+    /// The binary answering this command
+    pub running: Option<PathBuf>,
+}
+
+impl InstallStatus {
+    /// Whether any profile carries the hook
+    pub fn installed(&self) -> bool {
+        !self.profiles.is_empty()
+    }
+
+    /// The `tr300` a new shell would run
+    pub fn active_binary(&self) -> Option<&Path> {
+        self.binaries.first().map(PathBuf::as_path)
+    }
+
+    /// Inconsistencies worth a non-zero exit; empty when healthy
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        for profile in &self.profiles {
+            if let Some(error) = &profile.marker_error {
+                problems.push(format!("{}: {}", profile.path.display(), error));
+            } else if profile.blocks > 1 {
+                problems.push(format!(
+                    "{}: contains {} TR-300 blocks; re-run `tr300 install` to collapse them",
+                    profile.path.display(),
+                    profile.blocks
+                ));
+            }
+        }
+        if self.installed() {
+            if self.binaries.is_empty() {
+                problems.push(
+                    "the shell hook is installed but no tr300 is on PATH, so it never runs"
+                        .to_string(),
+                );
+            }
+            for conflict in &self.report_conflicts {
+                problems.push(format!(
+                    "`report` is redefined after the TR-300 block: {}",
+                    conflict
+                ));
+            }
+        }
+        if let Some(active) = self.active_binary().filter(|_| self.binaries.len() > 1) {
+            problems.push(format!(
+                "{} tr300 binaries on PATH; {} wins and the others are shadowed",
+                self.binaries.len(),
+                active.display()
+            ));
+        }
+        problems
+    }
+}
+
+/// The TR-300 block state of `path`, with every line after the last block
+/// that `defines_report` matches; `None` when unreadable or free of
+/// markers.
+pub(crate) fn inspect_profile(
+    path: &Path,
+    defines_report: fn(&str) -> bool,
+) -> Option<ProfileStatus> {
+    let content = fs::read_to_string(path).ok()?;
+    let last_end = content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.contains(MARKER_END))
+        .map(|(idx, _)| idx)
+        .last();
+    let blocks = content.lines().filter(|l| l.contains(MARKER_START)).count();
+    if blocks == 0 && last_end.is_none() {
+        return None;
+    }
+    let marker_error = check_marker_balance(&content, MARKER_START, MARKER_END).err();
+    let shadowing = match last_end.filter(|_| marker_error.is_none()) {
+        Some(last_end) => content
+            .lines()
+            .enumerate()
+            .skip(last_end + 1)
+            .filter(|(_, line)| defines_report(line))
+            .map(|(idx, line)| format!("{}:{}  {}", path.display(), idx + 1, line.trim()))
+            .collect(),
+        None => Vec::new(),
+    };
+    Some(ProfileStatus {
+        path: path.to_path_buf(),
+        blocks,
+        marker_error,
+        shadowing,
+    })
+}
+
+/// Distinct `tr300` executables in `PATH` order. Entries resolving to the
+/// same file (merged `/bin` and `/usr/bin`, symlinked shims) count once.
+pub(crate) fn binaries_on_path(path_var: &OsStr) -> Vec<PathBuf> {
+    let name = if cfg!(windows) { "tr300.exe" } else { "tr300" };
+    let mut seen: Vec<PathBuf> = Vec::new();
+    let mut binaries = Vec::new();
+    for dir in std::env::split_paths(path_var) {
+        let candidate = dir.join(name);
+        if !is_executable(&candidate) {
+            continue;
+        }
+        let real = fs::canonicalize(&candidate).unwrap_or_else(|_| candidate.clone());
+        if seen.contains(&real) {
+            continue;
+        }
+        seen.push(real);
+        binaries.push(candidate);
+    }
+    binaries
+}
+
+fn is_executable(path: &Path) -> bool {
+    let Ok(meta) = fs::metadata(path) else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        meta.is_file() && meta.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        meta.is_file()
+    }
+}
+
+/// Human-readable status report.
+pub fn render_status(status: &InstallStatus) -> String {
+    let mut out = String::from("TR-300 shell integration:\n");
+
+    out.push_str("  Profiles:\n");
+    if status.profiles.is_empty() {
+        out.push_str("    · not installed in any shell profile\n");
+    }
+    for profile in &status.profiles {
+        let state = if !profile.balanced() {
+            "UNBALANCED marker block"
+        } else if profile.blocks > 1 {
+            "installed (duplicate blocks)"
+        } else {
+            "installed"
+        };
+        out.push_str(&format!("    · {} — {}\n", profile.path.display(), state));
+    }
+
+    let report = if !status.installed() {
+        "not installed".to_string()
+    } else if status.report_conflicts.is_empty() {
+        "resolves to tr300".to_string()
+    } else {
+        format!(
+            "shadowed ({} later definition{})",
+            status.report_conflicts.len(),
+            if status.report_conflicts.len() == 1 {
+                ""
+            } else {
+                "s"
+            }
+        )
+    };
+    out.push_str(&format!("  `report` alias: {}\n", report));
+    for conflict in &status.report_conflicts {
+        out.push_str(&format!("    · {}\n", conflict));
+    }
+    if status.installed() && !status.report_shadowed.is_empty() {
+        out.push_str("  Also named `report` (the alias takes precedence):\n");
+        for other in &status.report_shadowed {
+            out.push_str(&format!("    · {}\n", other));
+        }
+    }
+
+    out.push_str("  tr300 on PATH:\n");
+    if status.binaries.is_empty() {
+        out.push_str("    · none\n");
+    }
+    for (idx, binary) in status.binaries.iter().enumerate() {
+        let role = if idx == 0 { "active" } else { "shadowed" };
+        out.push_str(&format!("    · {} ({})\n", binary.display(), role));
+    }
+    if let Some(running) = &status.running {
+        out.push_str(&format!("  Running binary: {}\n", running.display()));
+    }
+
+    let problems = status.problems();
+    if problems.is_empty() {
+        out.push_str("  Status: OK\n");
+    } else {
+        out.push_str("  Problems:\n");
+        for problem in &problems {
+            out.push_str(&format!("    · {}\n", problem.replace('\n', "\n      ")));
+        }
+    }
+    out
+}
+
+/// JSON status report; `success` is false when `problems` is non-empty.
+pub fn status_json(status: &InstallStatus) -> serde_json::Value {
+    let problems = status.problems();
+    let profiles: Vec<serde_json::Value> = status
+        .profiles
+        .iter()
+        .map(|profile| {
+            serde_json::json!({
+                "path": profile.path.display().to_string(),
+                "blocks": profile.blocks,
+                "balanced": profile.balanced(),
+                "marker_error": profile.marker_error,
+            })
+        })
+        .collect();
+    let binaries: Vec<serde_json::Value> = status
+        .binaries
+        .iter()
+        .enumerate()
+        .map(|(idx, path)| {
+            serde_json::json!({
+                "path": path.display().to_string(),
+                "active": idx == 0,
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "action": "install-status",
+        "installed": status.installed(),
+        "profiles": profiles,
+        "report": {
+            "resolves_to_tr300": status.installed() && status.report_conflicts.is_empty(),
+            "conflicts": status.report_conflicts,
+            "shadowed_by_alias": status.report_shadowed,
+        },
+        "binaries": binaries,
+        "running": status.running.as_ref().map(|p| p.display().to_string()),
+        "problems": problems,
+        "success": problems.is_empty(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile(path: &str, blocks: usize, balanced: bool) -> ProfileStatus {
+        ProfileStatus {
+            path: PathBuf::from(path),
+            blocks,
+            marker_error: (!balanced).then(|| "marker block looks mutilated".to_string()),
+            shadowing: Vec::new(),
+        }
+    }
+
+    fn defines_report(line: &str) -> bool {
+        line.trim().starts_with("alias report=") && !line.contains("tr300")
+    }
+
+    #[test]
+    fn profiles_are_inspected_for_marker_balance() {
+        let dir = tempfile::tempdir().unwrap();
+        let clean = dir.path().join("clean");
+        let good = dir.path().join("good");
+        let broken = dir.path().join("broken");
+        fs::write(&clean, "alias report=make\n").unwrap();
+        fs::write(
+            &good,
+            format!(
+                "alias report=make\n{MARKER_START}\nalias report='tr300'\n{MARKER_END}\n  alias report=less\n"
+            ),
+        )
+        .unwrap();
+        fs::write(
+            &broken,
+            format!("{MARKER_START}\nalias report='tr300'\nalias report=less\n"),
+        )
+        .unwrap();
+
+        assert_eq!(inspect_profile(&clean, defines_report), None);
+        assert_eq!(
+            inspect_profile(&dir.path().join("missing"), defines_report),
+            None
+        );
+        let good_status = inspect_profile(&good, defines_report).unwrap();
+        assert!(good_status.balanced());
+        assert_eq!(good_status.blocks, 1);
+        assert_eq!(
+            good_status.shadowing,
+            vec![format!("{}:5  alias report=less", good.display())]
+        );
+
+        let broken = inspect_profile(&broken, defines_report).unwrap();
+        assert!(!broken.balanced());
+        let expected = check_marker_balance(
+            &fs::read_to_string(&broken.path).unwrap(),
+            MARKER_START,
+            MARKER_END,
+        )
+        .unwrap_err();
+        assert_eq!(broken.marker_error, Some(expected));
+        assert!(broken.shadowing.is_empty());
+    }
+
+    #[test]
+    fn problems_flag_inconsistent_installs_only() {
+        let healthy = InstallStatus {
+            profiles: vec![profile("/home/u/.bashrc", 1, true)],
+            report_shadowed: vec![
+                "/usr/bin/report  (executable on PATH)".to_string(),
+                "/home/u/.bash_aliases:3  alias report=make".to_string(),
+            ],
+            binaries: vec![PathBuf::from("/home/u/.cargo/bin/tr300")],
+            ..InstallStatus::default()
+        };
+        assert!(healthy.problems().is_empty());
+        assert!(render_status(&healthy).contains("/usr/bin/report  (executable on PATH)"));
+        assert_eq!(status_json(&healthy)["success"], true);
+        assert!(InstallStatus::default().problems().is_empty());
+
+        let broken = InstallStatus {
+            profiles: vec![
+                profile("/home/u/.bashrc", 1, false),
+                profile("/home/u/.zshrc", 2, true),
+            ],
+            report_conflicts: vec!["/home/u/.zshrc:40  alias report=make".to_string()],
+            ..InstallStatus::default()
+        };
+        let problems = broken.problems();
+        assert_eq!(problems.len(), 4, "{problems:?}");
+        assert!(problems[0].contains("looks mutilated"));
+        assert!(problems[1].contains("2 TR-300 blocks"));
+        assert!(problems[2].contains("no tr300 is on PATH"));
+        assert!(problems[3].contains("redefined after the TR-300 block"));
+
+        let shadowed = InstallStatus {
+            binaries: vec![
+                PathBuf::from("/home/u/.cargo/bin/tr300"),
+                PathBuf::from("/usr/local/bin/tr300"),
+            ],
+            ..healthy
+        };
+        assert_eq!(shadowed.problems().len(), 1);
+        let json = status_json(&shadowed);
+        assert_eq!(json["success"], false);
+        assert_eq!(json["binaries"][0]["active"], true);
+        assert_eq!(json["binaries"][1]["active"], false);
+        assert_eq!(json["report"]["resolves_to_tr300"], true);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_scan_keeps_order_and_skips_duplicates_and_non_executables() {
+        use std::os::unix::fs::{symlink, PermissionsExt};
+
+        let dir = tempfile::tempdir().unwrap();
+        let (first, second, alias, plain) = (
+            dir.path().join("first"),
+            dir.path().join("second"),
+            dir.path().join("alias"),
+            dir.path().join("plain"),
+        );
+        for d in [&first, &second, &alias, &plain] {
+            fs::create_dir(d).unwrap();
+        }
+        for d in [&first, &second, &plain] {
+            fs::write(d.join("tr300"), "").unwrap();
+        }
+        for d in [&first, &second] {
+            fs::set_permissions(d.join("tr300"), fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        symlink(second.join("tr300"), alias.join("tr300")).unwrap();
+
+        let path_var = std::env::join_paths([&plain, &second, &alias, &first]).unwrap();
+        assert_eq!(
+            binaries_on_path(&path_var),
+            vec![second.join("tr300"), first.join("tr300")]
+        );
+    }
+}
@@ -1,13 +1,19 @@
 //! Unix/macOS installation utilities
 //!
-//! Adds TR-300 alias and auto-run to shell profiles.
+//! Adds TR-300 alias and auto-run to shell profiles: `~/.bashrc`,
+//! `~/.zshrc`, POSIX `~/.profile`, fish's `conf.d`, and nushell's
+//! `config.nu`, each with its own dialect of the same marker block.
+//! `install --system` writes the same block to `/etc/profile.d` and fish's
+//! vendor `conf.d` for every user on the machine.
 
 use crate::error::{AppError, Result};
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::plan::{ChangeKind, PlannedChange};
 use super::shared::{MARKER_END, MARKER_START};
+use crate::config::{AutorunPolicy, AutorunTrigger};
 
 /// Shell profile content to add.
 ///
@@ -30,20 +36,331 @@ use super::shared::{MARKER_END, MARKER_START};
 /// `super::shared::remove_delimited_block` and the
 /// `super::check_marker_balance` pre-write sanity check. The test
 /// `shell_additions_contains_shared_markers` below pins this contract.
+///
+/// Each dialect's snippet is a template: `{TRIGGER}` receives the extra
+/// auto-run condition for the configured `AutorunTrigger` (empty for
+/// `always`) and `{COMMAND}` the `tr300 …` command line.
 const SHELL_ADDITIONS: &str = r#"# TR-300 Machine Report
 alias report='tr300'
 
 # Auto-run on interactive shell; guards prevent spam-on-every-prompt
 # when the binary is missing, and recursion in nested shells.
 case "$-" in *i*)
-    if command -v tr300 >/dev/null 2>&1 && [ -z "${TR300_AUTORUN_RAN-}" ]; then
+    if command -v tr300 >/dev/null 2>&1 && [ -z "${TR300_AUTORUN_RAN-}" ]{TRIGGER}; then
         export TR300_AUTORUN_RAN=1
-        tr300 --fast
+        {COMMAND}
     fi
     ;;
 esac
 # End TR-300"#;
 
+/// fish snippet, written to its own `conf.d/tr300.fish` drop-in (fish
+/// sources every file there). Same markers and guards as
+/// `SHELL_ADDITIONS`: `status is-interactive`, `type -q tr300`, and the
+/// exported `TR300_AUTORUN_RAN` sentinel.
+const FISH_ADDITIONS: &str = r#"# TR-300 Machine Report
+alias report tr300
+
+# Auto-run on interactive shell; guards prevent spam-on-every-prompt
+# when the binary is missing, and recursion in nested shells.
+if status is-interactive; and type -q tr300; and not set -q TR300_AUTORUN_RAN{TRIGGER}
+    set -gx TR300_AUTORUN_RAN 1
+    {COMMAND}
+end
+# End TR-300"#;
+
+/// nushell snippet, appended to `config.nu`. Environment changes inside a
+/// nushell block are scoped to that block, so the sentinel is exported at
+/// top level after the guarded call rather than inside it.
+const NUSHELL_ADDITIONS: &str = r#"# TR-300 Machine Report
+alias report = ^tr300
+
+# Auto-run on interactive shell; guards prevent spam-on-every-prompt
+# when the binary is missing, and recursion in nested shells.
+if $nu.is-interactive and ((which tr300 | length) > 0) and ($env.TR300_AUTORUN_RAN? == null){TRIGGER} {
+    ^{COMMAND}
+}
+$env.TR300_AUTORUN_RAN = "1"
+# End TR-300"#;
+
+/// Shell dialect of a profile TR-300 manages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ShellKind {
+    /// bash, zsh, and POSIX `sh`-family shells
+    Posix,
+    Fish,
+    Nushell,
+}
+
+/// Per-user opt-out from the system-wide hook: set this variable, or create
+/// [`OPT_OUT_FILE`] under the home directory.
+pub const OPT_OUT_VAR: &str = "TR300_NO_AUTORUN";
+
+/// Per-user opt-out marker file, relative to the home directory.
+pub const OPT_OUT_FILE: &str = ".config/tr300/no-autorun";
+
+impl ShellKind {
+    /// Render this dialect's snippet for `policy`. System-wide snippets
+    /// (`opt_out`) additionally honor the per-user opt-out.
+    fn additions(self, policy: &AutorunPolicy, opt_out: bool) -> String {
+        let (template, trigger) = match self {
+            ShellKind::Posix => (
+                SHELL_ADDITIONS,
+                match policy.trigger {
+                    AutorunTrigger::Ssh => r#" && [ -n "${SSH_CONNECTION-}" ]"#,
+                    // `-bash`/`-sh` argv0 marks a login shell everywhere;
+                    // `bash --login` and `zsh -l` need their own checks.
+                    AutorunTrigger::Login => {
+                        r#" && { case "$0" in -*) true ;; *) shopt -q login_shell 2>/dev/null || { [ -n "${ZSH_VERSION-}" ] && [ -o login ]; } ;; esac; }"#
+                    }
+                    _ => "",
+                },
+            ),
+            ShellKind::Fish => (
+                FISH_ADDITIONS,
+                match policy.trigger {
+                    AutorunTrigger::Ssh => "; and set -q SSH_CONNECTION",
+                    AutorunTrigger::Login => "; and status is-login",
+                    _ => "",
+                },
+            ),
+            ShellKind::Nushell => (
+                NUSHELL_ADDITIONS,
+                match policy.trigger {
+                    AutorunTrigger::Ssh => " and ($env.SSH_CONNECTION? != null)",
+                    AutorunTrigger::Login => " and $nu.is-login",
+                    _ => "",
+                },
+            ),
+        };
+        let opt_out_check = match self {
+            _ if !opt_out => "",
+            ShellKind::Posix => {
+                r#" && [ -z "${TR300_NO_AUTORUN-}" ] && [ ! -e "$HOME/.config/tr300/no-autorun" ]"#
+            }
+            ShellKind::Fish => {
+                "; and not set -q TR300_NO_AUTORUN; and not test -e ~/.config/tr300/no-autorun"
+            }
+            ShellKind::Nushell => {
+                r#" and ($env.TR300_NO_AUTORUN? == null) and not ("~/.config/tr300/no-autorun" | path expand | path exists)"#
+            }
+        };
+        template
+            .replace("{TRIGGER}", &format!("{}{}", trigger, opt_out_check))
+            .replace("{COMMAND}", &super::autorun::command_line(policy))
+    }
+}
+
+/// One profile file TR-300 writes its block into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProfileTarget {
+    path: PathBuf,
+    kind: ShellKind,
+    /// The file belongs to TR-300 alone (fish `conf.d` drop-in): create it
+    /// on install and delete it once the block is removed.
+    owned: bool,
+    /// System-wide drop-in read by every user's shell
+    system: bool,
+}
+
+impl ProfileTarget {
+    fn new(path: PathBuf, kind: ShellKind) -> Self {
+        Self {
+            path,
+            kind,
+            owned: false,
+            system: false,
+        }
+    }
+
+    fn system(path: PathBuf, kind: ShellKind) -> Self {
+        Self {
+            path,
+            kind,
+            owned: true,
+            system: true,
+        }
+    }
+}
+
+/// Home and config directories the profile search runs against. Split out
+/// from the environment so target selection is testable.
+#[derive(Debug, Clone)]
+struct ProfileDirs {
+    home: PathBuf,
+    /// `$XDG_CONFIG_HOME`, else `~/.config` (fish always uses this)
+    xdg_config: PathBuf,
+    /// Platform config dir (`~/Library/Application Support` on macOS),
+    /// which nushell also searches
+    platform_config: Option<PathBuf>,
+    /// Basename of `$SHELL` (`bash`, `fish`, `nu`, ...)
+    login_shell: Option<String>,
+}
+
+impl ProfileDirs {
+    fn from_env(home: PathBuf) -> Self {
+        let xdg_config = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+            .unwrap_or_else(|| home.join(".config"));
+        let login_shell = env::var("SHELL").ok().and_then(|shell| {
+            Path::new(&shell)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        });
+        Self {
+            home,
+            xdg_config,
+            platform_config: dirs::config_dir(),
+            login_shell,
+        }
+    }
+
+    fn fish_conf_d(&self) -> PathBuf {
+        self.xdg_config
+            .join("fish")
+            .join("conf.d")
+            .join("tr300.fish")
+    }
+
+    fn nushell_configs(&self) -> Vec<PathBuf> {
+        let mut configs = vec![self.xdg_config.join("nushell").join("config.nu")];
+        if let Some(ref dir) = self.platform_config {
+            let platform = dir.join("nushell").join("config.nu");
+            if !configs.contains(&platform) {
+                configs.push(platform);
+            }
+        }
+        configs
+    }
+
+    /// Whether the login shell is a POSIX `sh`-family shell that reads only
+    /// `~/.profile` (dash, ksh, ash, ...), as opposed to bash/zsh.
+    fn login_shell_reads_only_profile(&self) -> bool {
+        matches!(
+            self.login_shell.as_deref(),
+            Some("sh" | "dash" | "ash" | "ksh" | "mksh" | "oksh" | "yash" | "posh")
+        )
+    }
+}
+
+/// Profiles to write on install.
+///
+/// Existing `~/.bashrc` / `~/.zshrc` / nushell `config.nu` files are
+/// updated; fish gets its drop-in when a fish config dir exists or fish is
+/// the login shell; `~/.profile` is used when the login shell reads nothing
+/// else. When none apply, one default profile is created for the login
+/// shell (zsh on macOS, bash elsewhere, when `$SHELL` is unknown).
+fn install_targets(dirs: &ProfileDirs) -> Vec<ProfileTarget> {
+    let mut targets = Vec::new();
+    for name in [".bashrc", ".zshrc"] {
+        let path = dirs.home.join(name);
+        if path.exists() {
+            targets.push(ProfileTarget::new(path, ShellKind::Posix));
+        }
+    }
+    let profile = dirs.home.join(".profile");
+    if dirs.login_shell_reads_only_profile() && profile.exists() {
+        targets.push(ProfileTarget::new(profile.clone(), ShellKind::Posix));
+    }
+    if dirs.xdg_config.join("fish").is_dir() || dirs.login_shell.as_deref() == Some("fish") {
+        targets.push(ProfileTarget {
+            path: dirs.fish_conf_d(),
+            kind: ShellKind::Fish,
+            owned: true,
+            system: false,
+        });
+    }
+    if let Some(config) = dirs.nushell_configs().into_iter().find(|p| p.exists()) {
+        targets.push(ProfileTarget::new(config, ShellKind::Nushell));
+    }
+
+    if targets.is_empty() {
+        let default = match dirs.login_shell.as_deref() {
+            Some("zsh") => dirs.home.join(".zshrc"),
+            Some("bash") => dirs.home.join(".bashrc"),
+            _ if dirs.login_shell_reads_only_profile() => profile,
+            // macOS has defaulted to zsh since 10.15 (Catalina, 2019) —
+            // creating `.bashrc` there would silently never fire.
+            _ if cfg!(target_os = "macos") => dirs.home.join(".zshrc"),
+            _ => dirs.home.join(".bashrc"),
+        };
+        targets.push(ProfileTarget::new(default, ShellKind::Posix));
+    }
+    targets
+}
+
+/// Every profile an install could have written, whatever the current shell.
+fn uninstall_targets(dirs: &ProfileDirs) -> Vec<ProfileTarget> {
+    let mut targets: Vec<ProfileTarget> = [".bashrc", ".zshrc", ".profile"]
+        .into_iter()
+        .map(|name| ProfileTarget::new(dirs.home.join(name), ShellKind::Posix))
+        .collect();
+    targets.push(ProfileTarget {
+        path: dirs.fish_conf_d(),
+        kind: ShellKind::Fish,
+        owned: true,
+        system: false,
+    });
+    targets.extend(
+        dirs.nushell_configs()
+            .into_iter()
+            .map(|path| ProfileTarget::new(path, ShellKind::Nushell)),
+    );
+    targets
+}
+
+/// System-wide hook locations.
+#[derive(Debug, Clone)]
+struct SystemDirs {
+    /// `/etc/profile.d`, sourced by `/etc/profile` for login shells
+    profile_d: PathBuf,
+    /// fish vendor `conf.d` directories, most preferred first
+    fish_vendor_conf_d: Vec<PathBuf>,
+}
+
+impl SystemDirs {
+    fn standard() -> Self {
+        Self {
+            profile_d: PathBuf::from("/etc/profile.d"),
+            fish_vendor_conf_d: vec![
+                PathBuf::from("/usr/share/fish/vendor_conf.d"),
+                PathBuf::from("/usr/local/share/fish/vendor_conf.d"),
+            ],
+        }
+    }
+}
+
+/// System-wide drop-ins to write: `/etc/profile.d/tr300.sh`, plus a fish
+/// vendor drop-in when fish is installed (its vendor directory exists).
+fn system_install_targets(dirs: &SystemDirs) -> Vec<ProfileTarget> {
+    let mut targets = vec![ProfileTarget::system(
+        dirs.profile_d.join("tr300.sh"),
+        ShellKind::Posix,
+    )];
+    if let Some(dir) = dirs.fish_vendor_conf_d.iter().find(|dir| dir.is_dir()) {
+        targets.push(ProfileTarget::system(
+            dir.join("tr300.fish"),
+            ShellKind::Fish,
+        ));
+    }
+    targets
+}
+
+/// Every system-wide drop-in an install could have written.
+fn system_uninstall_targets(dirs: &SystemDirs) -> Vec<ProfileTarget> {
+    let mut targets = vec![ProfileTarget::system(
+        dirs.profile_d.join("tr300.sh"),
+        ShellKind::Posix,
+    )];
+    targets.extend(
+        dirs.fish_vendor_conf_d
+            .iter()
+            .map(|dir| ProfileTarget::system(dir.join("tr300.fish"), ShellKind::Fish)),
+    );
+    targets
+}
+
 /// Get the installation path for Unix systems
 pub fn install_path() -> PathBuf {
     // Prefer ~/.local/bin if it exists
@@ -58,7 +375,7 @@ pub fn install_path() -> PathBuf {
 }
 
 /// Install tr300 to shell profiles
-pub fn install() -> Result<()> {
+pub fn install(policy: &AutorunPolicy) -> Result<()> {
     refuse_root_install()?;
 
     let home =
@@ -72,34 +389,10 @@ pub fn install() -> Result<()> {
     warn_if_report_already_defined(&home);
 
     let mut modified_files = Vec::new();
-
-    // Try to update .bashrc
-    let bashrc = home.join(".bashrc");
-    if bashrc.exists() && update_shell_profile(&bashrc)? {
-        modified_files.push(bashrc.display().to_string());
-    }
-
-    // Try to update .zshrc
-    let zshrc = home.join(".zshrc");
-    if zshrc.exists() && update_shell_profile(&zshrc)? {
-        modified_files.push(zshrc.display().to_string());
-    }
-
-    // If neither rc file exists, create the default for this platform.
-    // macOS has defaulted to zsh since 10.15 (Catalina, 2019) — creating
-    // `.bashrc` there would silently never fire because the user's
-    // actual zsh shell wouldn't source it. Linux defaults remain
-    // `.bashrc`.
-    if modified_files.is_empty() && !bashrc.exists() && !zshrc.exists() {
-        let default_rc = if cfg!(target_os = "macos") {
-            &zshrc
-        } else {
-            &bashrc
-        };
-        super::atomic_write(default_rc, SHELL_ADDITIONS).map_err(|e| {
-            AppError::platform(format!("Failed to create {}: {}", default_rc.display(), e))
-        })?;
-        modified_files.push(default_rc.display().to_string());
+    for target in install_targets(&ProfileDirs::from_env(home)) {
+        if update_shell_profile(&target, policy)? {
+            modified_files.push(target.path.display().to_string());
+        }
     }
 
     if modified_files.is_empty() {
@@ -116,14 +409,35 @@ pub fn install() -> Result<()> {
 
 /// Warn (to stderr) when `report` is already defined in the user's shell
 /// environment so the install doesn't silently shadow it.
+fn warn_if_report_already_defined(home: &Path) {
+    let hits = report_definitions(home);
+    if hits.is_empty() {
+        return;
+    }
+
+    eprintln!();
+    eprintln!("Note: `report` is already defined in your environment:");
+    for h in &hits {
+        eprintln!("    {}", h);
+    }
+    eprintln!("TR-300 is about to add `alias report='tr300'` to your shell profile,");
+    eprintln!("which will shadow the existing definition for new interactive shells.");
+    eprintln!("If you want to keep your existing `report`, edit the TR-300 block out");
+    eprintln!("of your shell profile after install (search for `# TR-300 Machine Report`).");
+    eprintln!();
+}
+
+/// Definitions of `report` other than TR-300's own alias, one
+/// `path:line  text` (or `path  (executable on PATH)`) entry each.
 ///
 /// Read-only heuristic: scans `~/.bashrc`, `~/.bash_profile`, `~/.zshrc`,
-/// `~/.profile`, and `~/.bash_aliases` for `alias report=` /
-/// `report ()` / `function report` declarations, plus probes
-/// `~/.local/bin/report`, `~/bin/report`, `/usr/local/bin/report`, and
-/// `/usr/bin/report` for an existing executable. No subprocess — so an
-/// rc file's side effects (fastfetch, tmux auto-attach, MOTD echoes,
-/// network probes) can't fire during `tr300 install`.
+/// `~/.profile`, `~/.bash_aliases`, and fish's `config.fish` for
+/// `alias report=` / `report ()` / `function report` declarations, plus
+/// every `$PATH` directory and the well-known `~/.local/bin`, `~/bin`,
+/// `/usr/local/bin`, and `/usr/bin` for an existing `report` executable.
+/// No subprocess — so an rc file's side effects (fastfetch, tmux
+/// auto-attach, MOTD echoes, network probes) can't fire during
+/// `tr300 install`.
 ///
 /// Best-effort by design: misses aliases defined in shell-specific
 /// fragment files, sourced configs, or pre-built shell environment
@@ -131,7 +445,7 @@ pub fn install() -> Result<()> {
 /// not a contract. False positives are also acceptable — worst case the
 /// user sees a one-time install-time message about a `report` they were
 /// fine shadowing.
-fn warn_if_report_already_defined(home: &Path) {
+pub(crate) fn report_definitions(home: &Path) -> Vec<String> {
     let mut hits: Vec<String> = Vec::new();
 
     // rc-file scan. Match definitions of an alias, function, or variable
@@ -145,6 +459,7 @@ fn warn_if_report_already_defined(home: &Path) {
         ".zshrc",
         ".zprofile",
         ".profile",
+        ".config/fish/config.fish",
     ];
     for name in &rc_candidates {
         let path = home.join(name);
@@ -152,54 +467,76 @@ fn warn_if_report_already_defined(home: &Path) {
             continue;
         };
         for (idx, raw) in content.lines().enumerate() {
-            let line = raw.trim();
-            // Skip TR-300's own block so re-running install doesn't warn
-            // about itself.
-            if line.contains(super::shared::MARKER_START)
-                || line.contains(super::shared::MARKER_END)
-                || (line.contains("alias report=") && line.contains("tr300"))
-            {
-                continue;
-            }
-            let matches_alias =
-                line.starts_with("alias report=") || line.starts_with("alias report =");
-            let matches_fn = line.starts_with("function report")
-                || line.starts_with("report()")
-                || line.starts_with("report ()");
-            if matches_alias || matches_fn {
-                hits.push(format!("{}:{}  {}", path.display(), idx + 1, line));
+            if defines_report(raw) {
+                hits.push(format!("{}:{}  {}", path.display(), idx + 1, raw.trim()));
             }
         }
     }
 
-    // Filesystem scan. A file at one of these well-known paths that's
-    // executable would also be shadowed by our alias.
-    let bin_candidates = [
+    // Filesystem scan. A file at one of these well-known paths or in any
+    // `$PATH` directory would also be shadowed by our alias.
+    let mut bin_candidates = vec![
         home.join(".local").join("bin").join("report"),
         home.join("bin").join("report"),
         PathBuf::from("/usr/local/bin/report"),
         PathBuf::from("/usr/bin/report"),
     ];
+    if let Some(path_var) = env::var_os("PATH") {
+        bin_candidates.extend(env::split_paths(&path_var).map(|dir| dir.join("report")));
+    }
+    let mut seen: Vec<PathBuf> = Vec::new();
     for path in &bin_candidates {
-        if path.exists() && !is_our_install(path) {
-            hits.push(format!("{}  (executable on PATH)", path.display()));
+        if !path.exists() || is_our_install(path) {
+            continue;
+        }
+        let real = fs::canonicalize(path).unwrap_or_else(|_| path.clone());
+        if seen.contains(&real) {
+            continue;
         }
+        seen.push(real);
+        hits.push(format!("{}  (executable on PATH)", path.display()));
     }
 
-    if hits.is_empty() {
-        return;
+    hits
+}
+
+/// Whether an rc-file line defines `report` as an alias or function.
+pub(crate) fn defines_report(raw: &str) -> bool {
+    let line = raw.trim();
+    // Skip TR-300's own block so re-running install doesn't warn
+    // about itself.
+    if line.contains(super::shared::MARKER_START)
+        || line.contains(super::shared::MARKER_END)
+        || (line.starts_with("alias report") && line.contains("tr300"))
+    {
+        return false;
     }
+    let matches_alias = line.starts_with("alias report=")
+        || line.starts_with("alias report =")
+        || line.starts_with("alias report ");
+    let matches_fn = line.starts_with("function report")
+        || line.starts_with("report()")
+        || line.starts_with("report ()");
+    matches_alias || matches_fn
+}
 
-    eprintln!();
-    eprintln!("Note: `report` is already defined in your environment:");
-    for h in &hits {
-        eprintln!("    {}", h);
+/// Every profile `install --status` inspects: each per-user candidate
+/// plus the system-wide drop-ins.
+pub(crate) fn status_profile_candidates() -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = Vec::new();
+    if let Some(home) = dirs::home_dir() {
+        paths.extend(
+            uninstall_targets(&ProfileDirs::from_env(home))
+                .into_iter()
+                .map(|target| target.path),
+        );
     }
-    eprintln!("TR-300 is about to add `alias report='tr300'` to your shell profile,");
-    eprintln!("which will shadow the existing definition for new interactive shells.");
-    eprintln!("If you want to keep your existing `report`, edit the TR-300 block out");
-    eprintln!("of your shell profile after install (search for `# TR-300 Machine Report`).");
-    eprintln!();
+    paths.extend(
+        system_uninstall_targets(&SystemDirs::standard())
+            .into_iter()
+            .map(|target| target.path),
+    );
+    paths
 }
 
 /// Treat our own installed `report` executable (when the user has previously
@@ -224,37 +561,95 @@ fn is_our_install(_path: &Path) -> bool {
 ///   causing `EACCES` the next time the user (non-root) tries to
 ///   re-run `tr300 install` for an upgrade.
 ///
-/// Refusing up-front with an actionable message avoids both. Users who
-/// want to install TR-300 system-wide should use the MSI/EXE installer
-/// (on Windows) or `cargo install tr300` (cross-platform) — the
-/// shell-profile flow is by design per-user.
+/// Refusing up-front with an actionable message avoids both. Admins who
+/// want the hook for every user on the machine use the explicit
+/// `tr300 install --system` flow, which writes only to system locations.
 fn refuse_root_install() -> Result<()> {
     let euid = unsafe { libc::geteuid() };
     if euid == 0 {
         return Err(AppError::platform(
-            "Don't run `tr300 install` with sudo / as root — TR-300 modifies your personal shell profile (~/.bashrc / ~/.zshrc). Running as root would either write the auto-run into root's profile (no benefit to your shell) or leave root-owned files in your home directory (the next non-sudo `tr300 install` would fail with permission denied). Re-run as your normal user without sudo.",
+            "Don't run `tr300 install` with sudo / as root — TR-300 modifies your personal shell profile (~/.bashrc / ~/.zshrc). Running as root would either write the auto-run into root's profile (no benefit to your shell) or leave root-owned files in your home directory (the next non-sudo `tr300 install` would fail with permission denied). Re-run as your normal user without sudo, or use `sudo tr300 install --system` to install the hook for every user.",
         ));
     }
     Ok(())
 }
 
+/// `--system` is the mirror image of `refuse_root_install`: it writes only
+/// to root-owned system directories, so it needs root.
+fn require_root_for_system(action: &str) -> Result<()> {
+    let euid = unsafe { libc::geteuid() };
+    if euid != 0 {
+        return Err(AppError::platform(format!(
+            "`tr300 {} --system` changes files in /etc for every user on this machine. Re-run it with sudo.",
+            action
+        )));
+    }
+    Ok(())
+}
+
+/// Install the hook system-wide for every user
+pub fn install_system(policy: &AutorunPolicy) -> Result<()> {
+    require_root_for_system("install")?;
+    let dirs = SystemDirs::standard();
+    // macOS and some minimal distros have no profile.d convention; a file
+    // there would never be sourced.
+    if !dirs.profile_d.is_dir() {
+        return Err(AppError::platform(format!(
+            "{} does not exist, so this system's /etc/profile does not load drop-ins from it. Use the per-user `tr300 install` instead.",
+            dirs.profile_d.display()
+        )));
+    }
+
+    let mut modified_files = Vec::new();
+    for target in system_install_targets(&dirs) {
+        if update_shell_profile(&target, policy)? {
+            modified_files.push(target.path.display().to_string());
+        }
+    }
+
+    println!("Installed system-wide shell hooks:");
+    for file in &modified_files {
+        println!("  - {}", file);
+    }
+    println!(
+        "Users can opt out with `export {}=1` or by creating ~/{}.",
+        OPT_OUT_VAR, OPT_OUT_FILE
+    );
+    Ok(())
+}
+
+/// Remove the system-wide hook
+pub fn uninstall_system() -> Result<()> {
+    require_root_for_system("uninstall")?;
+
+    let mut removed = Vec::new();
+    for target in system_uninstall_targets(&SystemDirs::standard()) {
+        if remove_from_profile(&target)? {
+            removed.push(target.path.display().to_string());
+        }
+    }
+
+    if removed.is_empty() {
+        println!("No system-wide TR-300 hook found.");
+    } else {
+        println!("Removed system-wide shell hooks:");
+        for file in &removed {
+            println!("  - {}", file);
+        }
+    }
+    Ok(())
+}
+
 /// Uninstall tr300 from shell profiles
 pub fn uninstall() -> Result<()> {
     let home =
         dirs::home_dir().ok_or_else(|| AppError::platform("Could not determine home directory"))?;
 
     let mut modified_files = Vec::new();
-
-    // Try to clean .bashrc
-    let bashrc = home.join(".bashrc");
-    if bashrc.exists() && remove_from_profile(&bashrc)? {
-        modified_files.push(bashrc.display().to_string());
-    }
-
-    // Try to clean .zshrc
-    let zshrc = home.join(".zshrc");
-    if zshrc.exists() && remove_from_profile(&zshrc)? {
-        modified_files.push(zshrc.display().to_string());
+    for target in uninstall_targets(&ProfileDirs::from_env(home)) {
+        if remove_from_profile(&target)? {
+            modified_files.push(target.path.display().to_string());
+        }
     }
 
     if modified_files.is_empty() {
@@ -269,8 +664,19 @@ pub fn uninstall() -> Result<()> {
     Ok(())
 }
 
-/// Update a shell profile with TR-300 additions
-fn update_shell_profile(path: &PathBuf) -> Result<bool> {
+/// Work out what `update_shell_profile` would write, without writing.
+fn plan_update(target: &ProfileTarget, policy: &AutorunPolicy) -> Result<PlannedChange> {
+    let path = &target.path;
+    let additions = target.kind.additions(policy, target.system);
+
+    if !path.exists() {
+        return Ok(PlannedChange::profile(
+            path.clone(),
+            None,
+            format!("{}\n", additions),
+        ));
+    }
+
     let content = fs::read_to_string(path)
         .map_err(|e| AppError::platform(format!("Failed to read {}: {}", path.display(), e)))?;
 
@@ -279,18 +685,46 @@ fn update_shell_profile(path: &PathBuf) -> Result<bool> {
     // `MARKER_START` to EOF.
     super::check_marker_balance(&content, MARKER_START, MARKER_END).map_err(AppError::platform)?;
 
-    // One-time backup of the original rc file before any modification.
-    let _ = super::backup_once(path);
-
     let cleaned_content = remove_tr300_block(&content);
 
     // Append TR-300 config to cleaned content
     let new_content = if cleaned_content.trim().is_empty() {
-        format!("{}\n", SHELL_ADDITIONS)
+        format!("{}\n", additions)
     } else {
-        format!("{}\n\n{}\n", cleaned_content.trim_end(), SHELL_ADDITIONS)
+        format!("{}\n\n{}\n", cleaned_content.trim_end(), additions)
     };
 
+    Ok(PlannedChange::profile(
+        path.clone(),
+        Some(content),
+        new_content,
+    ))
+}
+
+/// Update a shell profile with TR-300 additions, creating it (and, for
+/// TR-300-owned drop-ins, its parent directories) when missing.
+fn update_shell_profile(target: &ProfileTarget, policy: &AutorunPolicy) -> Result<bool> {
+    let change = plan_update(target, policy)?;
+    let path = &target.path;
+    let new_content = change.after.unwrap_or_default();
+
+    if change.kind == ChangeKind::Create {
+        if let Some(parent) = path.parent().filter(|_| target.owned) {
+            fs::create_dir_all(parent).map_err(|e| {
+                AppError::platform(format!("Failed to create {}: {}", parent.display(), e))
+            })?;
+        }
+        super::atomic_write(path, &new_content).map_err(|e| {
+            AppError::platform(format!("Failed to create {}: {}", path.display(), e))
+        })?;
+        return Ok(true);
+    }
+
+    // One-time backup of the original rc file before any modification.
+    if !target.owned {
+        let _ = super::backup_once(path);
+    }
+
     super::atomic_write(path, &new_content)
         .map_err(|e| AppError::platform(format!("Failed to write {}: {}", path.display(), e)))?;
 
@@ -326,14 +760,19 @@ fn remove_tr300_block(content: &str) -> String {
     }
 }
 
-/// Remove TR-300 additions from a shell profile
-fn remove_from_profile(path: &PathBuf) -> Result<bool> {
+/// Work out what `remove_from_profile` would do; `None` when the file
+/// is missing or carries no TR-300 block.
+fn plan_removal(target: &ProfileTarget) -> Result<Option<PlannedChange>> {
+    let path = &target.path;
+    if !path.exists() {
+        return Ok(None);
+    }
     let content = fs::read_to_string(path)
         .map_err(|e| AppError::platform(format!("Failed to read {}: {}", path.display(), e)))?;
 
     // Check if TR-300 is configured
     if !content.contains(MARKER_START) {
-        return Ok(false);
+        return Ok(None);
     }
 
     // Refuse to mutate a mutilated marker block — same hazard as on
@@ -349,14 +788,75 @@ fn remove_from_profile(path: &PathBuf) -> Result<bool> {
         new_lines.pop();
     }
 
+    if target.owned && new_lines.iter().all(|line| line.trim().is_empty()) {
+        return Ok(Some(PlannedChange::profile_removed(path.clone(), content)));
+    }
+
     let new_content = new_lines.join("\n") + "\n";
+    Ok(Some(PlannedChange::profile(
+        path.clone(),
+        Some(content),
+        new_content,
+    )))
+}
 
-    super::atomic_write(path, &new_content)
+/// Remove TR-300 additions from a shell profile. A TR-300-owned drop-in
+/// left with nothing but blank lines is deleted outright.
+fn remove_from_profile(target: &ProfileTarget) -> Result<bool> {
+    let Some(change) = plan_removal(target)? else {
+        return Ok(false);
+    };
+    let path = &target.path;
+
+    if change.kind == ChangeKind::Delete {
+        fs::remove_file(path).map_err(|e| {
+            AppError::platform(format!("Failed to remove {}: {}", path.display(), e))
+        })?;
+        return Ok(true);
+    }
+
+    super::atomic_write(path, &change.after.unwrap_or_default())
         .map_err(|e| AppError::platform(format!("Failed to write {}: {}", path.display(), e)))?;
 
     Ok(true)
 }
 
+/// Every change `install` would make, without making it
+pub fn plan_install(policy: &AutorunPolicy) -> Result<Vec<PlannedChange>> {
+    refuse_root_install()?;
+    let home =
+        dirs::home_dir().ok_or_else(|| AppError::platform("Could not determine home directory"))?;
+    install_targets(&ProfileDirs::from_env(home))
+        .iter()
+        .map(|target| plan_update(target, policy))
+        .collect()
+}
+
+/// Every change `install_system` would make. Reading `/etc` needs no
+/// root, so a dry run does not demand sudo.
+pub fn plan_install_system(policy: &AutorunPolicy) -> Result<Vec<PlannedChange>> {
+    system_install_targets(&SystemDirs::standard())
+        .iter()
+        .map(|target| plan_update(target, policy))
+        .collect()
+}
+
+/// Every change `uninstall` (or `uninstall_system`) would make
+pub fn plan_uninstall(system: bool) -> Result<Vec<PlannedChange>> {
+    let targets = if system {
+        system_uninstall_targets(&SystemDirs::standard())
+    } else {
+        let home = dirs::home_dir()
+            .ok_or_else(|| AppError::platform("Could not determine home directory"))?;
+        uninstall_targets(&ProfileDirs::from_env(home))
+    };
+    let mut changes = Vec::new();
+    for target in &targets {
+        changes.extend(plan_removal(target)?);
+    }
+    Ok(changes)
+}
+
 /// Find the location of the currently running binary
 pub fn find_binary_location() -> Option<PathBuf> {
     // First try to get the current executable path
@@ -398,8 +898,11 @@ pub fn uninstall_complete() -> Result<()> {
     // First, uninstall from shell profiles
     uninstall()?;
 
-    // Then remove the binary
+    // Then remove the binary and its rollback copy
     if let Some(binary_path) = find_binary_location() {
+        if let Some(copy) = super::rollback_copy(&binary_path) {
+            remove_binary(&copy)?;
+        }
         remove_binary(&binary_path)?;
     }
 
@@ -409,8 +912,12 @@ pub fn uninstall_complete() -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::{
-        remove_from_profile, update_shell_profile, MARKER_END, MARKER_START, SHELL_ADDITIONS,
+        install_targets, plan_removal, plan_update, remove_from_profile, system_install_targets,
+        system_uninstall_targets, uninstall_targets, update_shell_profile, ChangeKind, ProfileDirs,
+        ProfileTarget, ShellKind, SystemDirs, FISH_ADDITIONS, MARKER_END, MARKER_START,
+        NUSHELL_ADDITIONS, SHELL_ADDITIONS,
     };
+    use crate::config::{AutorunPolicy, AutorunTrigger};
     use crate::install::shared::{ALIAS_NAME, AUTORUN_SENTINEL_VAR, BINARY_NAME};
 
     #[test]
@@ -456,9 +963,11 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let profile = dir.path().join(".zshrc");
         std::fs::write(&profile, "export KEEP_ME=yes\n").unwrap();
+        let target = ProfileTarget::new(profile.clone(), ShellKind::Posix);
 
-        assert!(update_shell_profile(&profile).unwrap());
-        assert!(update_shell_profile(&profile).unwrap());
+        let policy = AutorunPolicy::default();
+        assert!(update_shell_profile(&target, &policy).unwrap());
+        assert!(update_shell_profile(&target, &policy).unwrap());
         let installed = std::fs::read_to_string(&profile).unwrap();
         assert_eq!(installed.matches(MARKER_START).count(), 1);
         assert_eq!(installed.matches(MARKER_END).count(), 1);
@@ -468,10 +977,256 @@ mod tests {
             "export KEEP_ME=yes\n"
         );
 
-        assert!(remove_from_profile(&profile).unwrap());
+        assert!(remove_from_profile(&target).unwrap());
         assert_eq!(
             std::fs::read_to_string(&profile).unwrap(),
             "export KEEP_ME=yes\n"
         );
     }
+
+    #[test]
+    fn every_dialect_carries_markers_and_guards() {
+        for additions in [SHELL_ADDITIONS, FISH_ADDITIONS, NUSHELL_ADDITIONS] {
+            assert!(additions.starts_with(MARKER_START));
+            assert!(additions.ends_with(MARKER_END));
+            assert!(additions.contains(ALIAS_NAME));
+            assert!(additions.contains(AUTORUN_SENTINEL_VAR));
+        }
+        assert!(FISH_ADDITIONS.contains("status is-interactive"));
+        assert!(FISH_ADDITIONS.contains("type -q tr300"));
+        assert!(NUSHELL_ADDITIONS.contains("$nu.is-interactive"));
+        assert!(NUSHELL_ADDITIONS.contains("which tr300"));
+    }
+
+    fn profile_dirs(home: &std::path::Path, login_shell: &str) -> ProfileDirs {
+        ProfileDirs {
+            home: home.to_path_buf(),
+            xdg_config: home.join(".config"),
+            platform_config: None,
+            login_shell: Some(login_shell.to_string()),
+        }
+    }
+
+    #[test]
+    fn fish_login_shell_gets_a_conf_d_drop_in_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let targets = install_targets(&profile_dirs(dir.path(), "fish"));
+        assert_eq!(targets.len(), 1);
+        assert_eq!(targets[0].kind, ShellKind::Fish);
+        assert!(targets[0].path.ends_with(".config/fish/conf.d/tr300.fish"));
+        assert!(targets[0].owned);
+    }
+
+    #[test]
+    fn dash_login_shell_uses_profile_and_existing_nushell_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let nu_dir = dir.path().join(".config").join("nushell");
+        std::fs::create_dir_all(&nu_dir).unwrap();
+        std::fs::write(
+            nu_dir.join("config.nu"),
+            "$env.config.show_banner = false\n",
+        )
+        .unwrap();
+
+        let targets = install_targets(&profile_dirs(dir.path(), "dash"));
+        assert_eq!(targets.len(), 1, "no .profile yet: only nushell");
+        assert_eq!(targets[0].kind, ShellKind::Nushell);
+
+        std::fs::write(dir.path().join(".profile"), "PATH=$HOME/bin:$PATH\n").unwrap();
+        let targets = install_targets(&profile_dirs(dir.path(), "dash"));
+        assert!(targets
+            .iter()
+            .any(|t| t.kind == ShellKind::Posix && t.path.ends_with(".profile")));
+    }
+
+    #[test]
+    fn unknown_shell_without_profiles_creates_one_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let targets = install_targets(&profile_dirs(dir.path(), "bash"));
+        assert_eq!(
+            targets,
+            vec![ProfileTarget::new(
+                dir.path().join(".bashrc"),
+                ShellKind::Posix
+            )]
+        );
+    }
+
+    #[test]
+    fn fish_drop_in_is_created_and_removed_whole() {
+        let dir = tempfile::tempdir().unwrap();
+        let dirs = profile_dirs(dir.path(), "fish");
+        let target = install_targets(&dirs).remove(0);
+
+        let policy = AutorunPolicy::default();
+        assert!(update_shell_profile(&target, &policy).unwrap());
+        let installed = std::fs::read_to_string(&target.path).unwrap();
+        assert_eq!(
+            installed,
+            format!("{}\n", ShellKind::Fish.additions(&policy, false))
+        );
+        assert!(
+            !target
+                .path
+                .with_file_name("tr300.fish.tr300-backup")
+                .exists(),
+            "TR-300's own drop-in needs no backup"
+        );
+
+        let fish = uninstall_targets(&dirs)
+            .into_iter()
+            .find(|t| t.kind == ShellKind::Fish)
+            .unwrap();
+        assert!(remove_from_profile(&fish).unwrap());
+        assert!(!target.path.exists());
+    }
+
+    #[test]
+    fn default_policy_renders_the_classic_hook() {
+        let policy = AutorunPolicy::default();
+        for kind in [ShellKind::Posix, ShellKind::Fish, ShellKind::Nushell] {
+            let rendered = kind.additions(&policy, false);
+            assert!(!rendered.contains("{TRIGGER}") && !rendered.contains("{COMMAND}"));
+            assert!(rendered.contains("tr300 --fast\n"), "{kind:?}: {rendered}");
+        }
+        assert!(ShellKind::Posix
+            .additions(&policy, false)
+            .contains(r#"[ -z "${TR300_AUTORUN_RAN-}" ]; then"#));
+    }
+
+    #[test]
+    fn triggers_add_a_dialect_specific_condition() {
+        let ssh = AutorunPolicy::default().with_trigger(AutorunTrigger::Ssh);
+        assert!(ShellKind::Posix
+            .additions(&ssh, false)
+            .contains(r#"&& [ -n "${SSH_CONNECTION-}" ]; then"#));
+        assert!(ShellKind::Fish
+            .additions(&ssh, false)
+            .contains("and set -q SSH_CONNECTION"));
+        assert!(ShellKind::Nushell
+            .additions(&ssh, false)
+            .contains("($env.SSH_CONNECTION? != null) {"));
+
+        let login = AutorunPolicy::default().with_trigger(AutorunTrigger::Login);
+        assert!(ShellKind::Posix
+            .additions(&login, false)
+            .contains("shopt -q login_shell"));
+        assert!(ShellKind::Fish
+            .additions(&login, false)
+            .contains("status is-login"));
+        assert!(ShellKind::Nushell
+            .additions(&login, false)
+            .contains("$nu.is-login"));
+
+        let hourly = AutorunPolicy::default()
+            .with_trigger(AutorunTrigger::EveryHours(4))
+            .with_args("--ascii")
+            .unwrap();
+        let rendered = ShellKind::Posix.additions(&hourly, false);
+        assert!(rendered.contains("tr300 --ascii --autorun-interval 4\n"));
+        assert!(rendered.contains(r#"[ -z "${TR300_AUTORUN_RAN-}" ]; then"#));
+    }
+
+    #[test]
+    fn posix_login_hook_is_valid_sh() {
+        let policy = AutorunPolicy::default().with_trigger(AutorunTrigger::Login);
+        let status = std::process::Command::new("sh")
+            .arg("-n")
+            .arg("-c")
+            .arg(ShellKind::Posix.additions(&policy, false))
+            .status();
+        if let Ok(status) = status {
+            assert!(status.success(), "sh -n rejected the rendered hook");
+        }
+    }
+
+    #[test]
+    fn system_hook_honors_the_per_user_opt_out() {
+        let policy = AutorunPolicy::default();
+        let user = ShellKind::Posix.additions(&policy, false);
+        let system = ShellKind::Posix.additions(&policy, true);
+        assert!(!user.contains("TR300_NO_AUTORUN"));
+        assert!(system.contains(r#"[ -z "${TR300_NO_AUTORUN-}" ]"#));
+        assert!(system.contains(".config/tr300/no-autorun"));
+        assert!(ShellKind::Fish
+            .additions(&policy, true)
+            .contains("not set -q TR300_NO_AUTORUN"));
+
+        let mut hook = std::process::Command::new("sh");
+        hook.arg("-n").arg("-c").arg(&system);
+        if let Ok(status) = hook.status() {
+            assert!(status.success(), "sh -n rejected the system hook");
+        }
+    }
+
+    #[test]
+    fn system_install_writes_owned_drop_ins_and_uninstall_deletes_them() {
+        let dir = tempfile::tempdir().unwrap();
+        let dirs = SystemDirs {
+            profile_d: dir.path().join("profile.d"),
+            fish_vendor_conf_d: vec![
+                dir.path().join("share/fish/vendor_conf.d"),
+                dir.path().join("local/share/fish/vendor_conf.d"),
+            ],
+        };
+        std::fs::create_dir_all(&dirs.profile_d).unwrap();
+        assert_eq!(system_install_targets(&dirs).len(), 1, "no fish installed");
+
+        std::fs::create_dir_all(&dirs.fish_vendor_conf_d[1]).unwrap();
+        let targets = system_install_targets(&dirs);
+        assert_eq!(targets.len(), 2);
+        let policy = AutorunPolicy::default();
+        for target in &targets {
+            assert!(update_shell_profile(target, &policy).unwrap());
+            let content = std::fs::read_to_string(&target.path).unwrap();
+            assert!(content.starts_with(MARKER_START));
+            assert!(content.contains("TR300_NO_AUTORUN"));
+        }
+        assert!(!dirs.profile_d.join("tr300.sh.tr300-backup").exists());
+
+        for target in system_uninstall_targets(&dirs) {
+            if target.path.exists() {
+                assert!(remove_from_profile(&target).unwrap());
+            }
+        }
+        assert!(!dirs.profile_d.join("tr300.sh").exists());
+        assert!(!dirs.fish_vendor_conf_d[1].join("tr300.fish").exists());
+    }
+
+    #[test]
+    fn plans_leave_files_untouched_and_match_the_applied_change() {
+        let dir = tempfile::tempdir().unwrap();
+        let profile = dir.path().join(".bashrc");
+        std::fs::write(&profile, "export A=1\n").unwrap();
+        let target = ProfileTarget::new(profile.clone(), ShellKind::Posix);
+        let policy = AutorunPolicy::default();
+
+        let plan = plan_update(&target, &policy).unwrap();
+        assert_eq!(plan.kind, ChangeKind::Modify);
+        assert_eq!(std::fs::read_to_string(&profile).unwrap(), "export A=1\n");
+        assert!(plan.diff().contains("+# TR-300 Machine Report\n"));
+
+        update_shell_profile(&target, &policy).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&profile).unwrap(),
+            plan.after.unwrap()
+        );
+        assert_eq!(
+            plan_update(&target, &policy).unwrap().kind,
+            ChangeKind::Unchanged
+        );
+
+        let removal = plan_removal(&target).unwrap().expect("block is present");
+        assert_eq!(removal.after.as_deref(), Some("export A=1\n"));
+        assert!(std::fs::read_to_string(&profile)
+            .unwrap()
+            .contains(MARKER_START));
+
+        let owned = ProfileTarget::system(dir.path().join("tr300.sh"), ShellKind::Posix);
+        assert_eq!(
+            plan_update(&owned, &policy).unwrap().kind,
+            ChangeKind::Create
+        );
+        assert!(plan_removal(&owned).unwrap().is_none());
+    }
 }
@@ -9,7 +9,9 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use super::plan::PlannedChange;
 use super::shared::{MARKER_END, MARKER_START};
+use crate::config::{AutorunPolicy, AutorunTrigger};
 
 /// PowerShell profile content to add.
 ///
@@ -34,6 +36,9 @@ use super::shared::{MARKER_END, MARKER_START};
 /// The literal `# TR-300 Machine Report` / `# End TR-300` markers
 /// must appear at the boundaries — pinned by
 /// `shell_additions_contains_shared_markers` below.
+///
+/// `{TRIGGER}` and `{COMMAND}` are filled from the `AutorunPolicy` by
+/// `powershell_additions`.
 const POWERSHELL_ADDITIONS: &str = "# TR-300 Machine Report\r\n\
     Set-Alias -Name report -Value tr300\r\n\
     \r\n\
@@ -43,10 +48,10 @@ const POWERSHELL_ADDITIONS: &str = "# TR-300 Machine Report\r\n\
     if (\r\n\
     \x20   (Get-Command tr300 -ErrorAction SilentlyContinue) -and\r\n\
     \x20   -not $env:TR300_AUTORUN_RAN -and\r\n\
-    \x20   [Environment]::UserInteractive\r\n\
+    \x20   [Environment]::UserInteractive{TRIGGER}\r\n\
     ) {\r\n\
     \x20   $env:TR300_AUTORUN_RAN = '1'\r\n\
-    \x20   tr300 --fast\r\n\
+    \x20   {COMMAND}\r\n\
     }\r\n\
     # End TR-300";
 
@@ -129,8 +134,28 @@ fn get_powershell_profiles() -> Vec<PathBuf> {
     paths
 }
 
+/// Render `POWERSHELL_ADDITIONS` for `policy`. PowerShell has no notion of
+/// a login shell, so the `login` trigger is refused rather than silently
+/// treated as `always`.
+fn powershell_additions(policy: &AutorunPolicy) -> Result<String> {
+    let trigger = match policy.trigger {
+        AutorunTrigger::Ssh => " -and\r\n    $env:SSH_CONNECTION",
+        AutorunTrigger::Login => {
+            return Err(AppError::config(
+                "the `login` auto-run trigger is not available for PowerShell; use always, ssh, or an interval like 6h",
+            ))
+        }
+        _ => "",
+    };
+    Ok(POWERSHELL_ADDITIONS
+        .replace("{TRIGGER}", trigger)
+        .replace("{COMMAND}", &super::autorun::command_line(policy)))
+}
+
 /// Install tr300 to PowerShell profile
-pub fn install() -> Result<()> {
+pub fn install(policy: &AutorunPolicy) -> Result<()> {
+    let additions = powershell_additions(policy)?;
+
     // Preflight: ensure the user's execution policy allows the profile to load.
     // A fresh Windows install defaults to `Restricted`, which blocks every
     // `.ps1` file — including `$PROFILE` itself — so the auto-run we're about
@@ -156,7 +181,7 @@ pub fn install() -> Result<()> {
 
     let mut modified = Vec::with_capacity(profile_paths.len());
     for profile_path in &profile_paths {
-        install_into_profile(profile_path)?;
+        install_into_profile(profile_path, &additions)?;
         modified.push(profile_path.display().to_string());
     }
 
@@ -172,13 +197,48 @@ pub fn install() -> Result<()> {
     Ok(())
 }
 
+/// Work out what `install_into_profile` would write, without writing.
+fn plan_profile_install(profile_path: &Path, additions: &str) -> Result<PlannedChange> {
+    // Read existing profile or create empty
+    let existing_content = if profile_path.exists() {
+        Some(
+            fs::read_to_string(profile_path)
+                .map_err(|e| fail_install(InstallStep::ReadProfile, profile_path, e))?,
+        )
+    } else {
+        None
+    };
+    let content = existing_content.as_deref().unwrap_or_default();
+
+    // Refuse to mutate a mutilated marker block; otherwise the block
+    // parser would silently drop every line from `MARKER_START` to EOF.
+    super::check_marker_balance(content, MARKER_START, MARKER_END).map_err(AppError::platform)?;
+
+    let cleaned_content = remove_tr300_block(content);
+
+    // Append TR-300 config to cleaned content
+    let new_content = if cleaned_content.trim().is_empty() {
+        additions.to_string()
+    } else {
+        format!("{}\r\n\r\n{}", cleaned_content.trim_end(), additions)
+    };
+
+    Ok(PlannedChange::profile(
+        profile_path.to_path_buf(),
+        existing_content,
+        new_content,
+    ))
+}
+
 /// Write the TR-300 block into one PowerShell profile.
 ///
 /// Extracted from `install()` so the v3.15.3+ multi-profile path
 /// (Windows PowerShell 5.1 AND PowerShell 7 when both are present)
 /// can iterate without duplicating the read / sanity-check / write
 /// pipeline.
-fn install_into_profile(profile_path: &Path) -> Result<()> {
+fn install_into_profile(profile_path: &Path, additions: &str) -> Result<()> {
+    let change = plan_profile_install(profile_path, additions)?;
+
     // Create profile directory if needed
     if let Some(parent) = profile_path.parent() {
         if !parent.exists() {
@@ -187,39 +247,52 @@ fn install_into_profile(profile_path: &Path) -> Result<()> {
         }
     }
 
-    // Read existing profile or create empty
-    let existing_content = if profile_path.exists() {
-        fs::read_to_string(profile_path)
-            .map_err(|e| fail_install(InstallStep::ReadProfile, profile_path, e))?
-    } else {
-        String::new()
-    };
-
-    // Refuse to mutate a mutilated marker block; otherwise the block
-    // parser would silently drop every line from `MARKER_START` to EOF.
-    super::check_marker_balance(&existing_content, MARKER_START, MARKER_END)
-        .map_err(AppError::platform)?;
-
     // One-time backup of the original profile before any modification.
     let _ = super::backup_once(profile_path);
 
-    let cleaned_content = remove_tr300_block(&existing_content);
+    super::atomic_write(profile_path, &change.after.unwrap_or_default())
+        .map_err(|e| fail_install(InstallStep::WriteProfile, profile_path, e))?;
 
-    // Append TR-300 config to cleaned content
-    let new_content = if cleaned_content.trim().is_empty() {
-        POWERSHELL_ADDITIONS.to_string()
+    Ok(())
+}
+
+/// Work out what uninstall would do to one profile; `None` when the file
+/// is missing or carries no TR-300 block.
+fn plan_profile_removal(profile_path: &Path) -> Result<Option<PlannedChange>> {
+    if !profile_path.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(profile_path)
+        .map_err(|e| fail_install(InstallStep::ReadProfile, profile_path, e))?;
+
+    if !content.contains(MARKER_START) {
+        return Ok(None);
+    }
+
+    // Refuse to mutate a mutilated marker block — same hazard as
+    // on install. Without this, an uninstall on a hand-edited
+    // profile would drop everything from `MARKER_START` to EOF.
+    super::check_marker_balance(&content, MARKER_START, MARKER_END).map_err(AppError::platform)?;
+
+    let lines: Vec<&str> = content.lines().collect();
+    let mut new_lines = super::shared::remove_delimited_block(&lines, MARKER_START, MARKER_END);
+
+    // Clean up extra blank lines at the end
+    while new_lines.last().map(|s| s.is_empty()).unwrap_or(false) {
+        new_lines.pop();
+    }
+
+    let new_content = if new_lines.is_empty() {
+        String::new()
     } else {
-        format!(
-            "{}\r\n\r\n{}",
-            cleaned_content.trim_end(),
-            POWERSHELL_ADDITIONS
-        )
+        new_lines.join("\r\n") + "\r\n"
     };
 
-    super::atomic_write(profile_path, &new_content)
-        .map_err(|e| fail_install(InstallStep::WriteProfile, profile_path, e))?;
-
-    Ok(())
+    Ok(Some(PlannedChange::profile(
+        profile_path.to_path_buf(),
+        Some(content),
+        new_content,
+    )))
 }
 
 /// Uninstall tr300 from PowerShell profile(s).
@@ -236,37 +309,11 @@ pub fn uninstall() -> Result<()> {
 
     let mut cleaned = Vec::new();
     for profile_path in &profile_paths {
-        if !profile_path.exists() {
-            continue;
-        }
-        let content = fs::read_to_string(profile_path)
-            .map_err(|e| fail_install(InstallStep::ReadProfile, profile_path, e))?;
-
-        if !content.contains(MARKER_START) {
+        let Some(change) = plan_profile_removal(profile_path)? else {
             continue;
-        }
-
-        // Refuse to mutate a mutilated marker block — same hazard as
-        // on install. Without this, an uninstall on a hand-edited
-        // profile would drop everything from `MARKER_START` to EOF.
-        super::check_marker_balance(&content, MARKER_START, MARKER_END)
-            .map_err(AppError::platform)?;
-
-        let lines: Vec<&str> = content.lines().collect();
-        let mut new_lines = super::shared::remove_delimited_block(&lines, MARKER_START, MARKER_END);
-
-        // Clean up extra blank lines at the end
-        while new_lines.last().map(|s| s.is_empty()).unwrap_or(false) {
-            new_lines.pop();
-        }
-
-        let new_content = if new_lines.is_empty() {
-            String::new()
-        } else {
-            new_lines.join("\r\n") + "\r\n"
         };
 
-        super::atomic_write(profile_path, &new_content)
+        super::atomic_write(profile_path, &change.after.unwrap_or_default())
             .map_err(|e| fail_install(InstallStep::WriteProfile, profile_path, e))?;
 
         cleaned.push(profile_path.display().to_string());
@@ -289,6 +336,30 @@ pub fn uninstall() -> Result<()> {
     Ok(())
 }
 
+/// Every profile change `install` would make, without making it. The
+/// execution-policy preflight is not part of the plan.
+pub fn plan_install(policy: &AutorunPolicy) -> Result<Vec<PlannedChange>> {
+    let additions = powershell_additions(policy)?;
+    get_powershell_profiles()
+        .iter()
+        .map(|path| plan_profile_install(path, &additions))
+        .collect()
+}
+
+/// Every profile `install --status` inspects
+pub(crate) fn status_profile_candidates() -> Vec<PathBuf> {
+    get_powershell_profiles()
+}
+
+/// Every profile change `uninstall` would make
+pub fn plan_uninstall() -> Result<Vec<PlannedChange>> {
+    let mut changes = Vec::new();
+    for path in get_powershell_profiles() {
+        changes.extend(plan_profile_removal(&path)?);
+    }
+    Ok(changes)
+}
+
 /// Remove existing TR-300 blocks from content
 fn remove_tr300_block(content: &str) -> String {
     let lines: Vec<&str> = content.lines().collect();
@@ -382,6 +453,27 @@ pub fn remove_empty_parent_dir(dir: &PathBuf) -> Result<()> {
 
 /// Warn (to stderr) when `report` is already defined in the user's
 /// PowerShell environment so the install doesn't silently shadow it.
+fn warn_if_report_already_defined(profile_paths: &[PathBuf]) {
+    let hits = report_definitions(profile_paths);
+    if hits.is_empty() {
+        return;
+    }
+
+    eprintln!();
+    eprintln!("Note: `report` is already defined in your PowerShell environment:");
+    for h in &hits {
+        eprintln!("    {}", h);
+    }
+    eprintln!("TR-300 is about to add `Set-Alias -Name report -Value tr300` to your");
+    eprintln!("PowerShell profile, which will shadow the existing definition for");
+    eprintln!("new sessions. If you want to keep your existing `report`, edit the");
+    eprintln!("TR-300 block out of $PROFILE after install (search for");
+    eprintln!("`# TR-300 Machine Report`).");
+    eprintln!();
+}
+
+/// Definitions of `report` other than TR-300's own alias, one
+/// `path:line  text` (or `path  (executable on PATH)`) entry each.
 ///
 /// Read-only heuristic: scans each `$PROFILE` we'd be writing to for
 /// `Set-Alias`, `function`, or `New-Alias` declarations of `report`,
@@ -393,7 +485,7 @@ pub fn remove_empty_parent_dir(dir: &PathBuf) -> Result<()> {
 /// Best-effort by design: misses aliases defined in PowerShell modules
 /// that are auto-loaded but not declared in `$PROFILE` itself, and
 /// misses `Set-Alias` declarations split across multiple lines.
-fn warn_if_report_already_defined(profile_paths: &[PathBuf]) {
+pub(crate) fn report_definitions(profile_paths: &[PathBuf]) -> Vec<String> {
     let mut hits: Vec<String> = Vec::new();
 
     // $PROFILE scan. Case-insensitive — PowerShell is case-insensitive
@@ -403,26 +495,13 @@ fn warn_if_report_already_defined(profile_paths: &[PathBuf]) {
             continue;
         };
         for (idx, raw) in content.lines().enumerate() {
-            let line = raw.trim();
-            // Skip TR-300's own block so re-running install doesn't warn
-            // about itself.
-            if line.contains(super::shared::MARKER_START)
-                || line.contains(super::shared::MARKER_END)
-                || (line.to_ascii_lowercase().contains("set-alias")
-                    && line.to_ascii_lowercase().contains("report")
-                    && line.to_ascii_lowercase().contains("tr300"))
-            {
-                continue;
-            }
-            let lower = line.to_ascii_lowercase();
-            let matches_alias = (lower.starts_with("set-alias") || lower.starts_with("new-alias"))
-                && lower.contains("report");
-            let matches_fn = lower.starts_with("function report ")
-                || lower.starts_with("function report{")
-                || lower.starts_with("function report(")
-                || lower == "function report";
-            if matches_alias || matches_fn {
-                hits.push(format!("{}:{}  {}", profile_path.display(), idx + 1, line));
+            if defines_report(raw) {
+                hits.push(format!(
+                    "{}:{}  {}",
+                    profile_path.display(),
+                    idx + 1,
+                    raw.trim()
+                ));
             }
         }
     }
@@ -445,21 +524,28 @@ fn warn_if_report_already_defined(profile_paths: &[PathBuf]) {
         }
     }
 
-    if hits.is_empty() {
-        return;
-    }
+    hits
+}
 
-    eprintln!();
-    eprintln!("Note: `report` is already defined in your PowerShell environment:");
-    for h in &hits {
-        eprintln!("    {}", h);
+/// Whether a `$PROFILE` line defines `report` as an alias or function.
+pub(crate) fn defines_report(raw: &str) -> bool {
+    let line = raw.trim();
+    let lower = line.to_ascii_lowercase();
+    // Skip TR-300's own block so re-running install doesn't warn
+    // about itself.
+    if line.contains(super::shared::MARKER_START)
+        || line.contains(super::shared::MARKER_END)
+        || (lower.contains("set-alias") && lower.contains("report") && lower.contains("tr300"))
+    {
+        return false;
     }
-    eprintln!("TR-300 is about to add `Set-Alias -Name report -Value tr300` to your");
-    eprintln!("PowerShell profile, which will shadow the existing definition for");
-    eprintln!("new sessions. If you want to keep your existing `report`, edit the");
-    eprintln!("TR-300 block out of $PROFILE after install (search for");
-    eprintln!("`# TR-300 Machine Report`).");
-    eprintln!();
+    let matches_alias = (lower.starts_with("set-alias") || lower.starts_with("new-alias"))
+        && lower.contains("report");
+    let matches_fn = lower.starts_with("function report ")
+        || lower.starts_with("function report{")
+        || lower.starts_with("function report(")
+        || lower == "function report";
+    matches_alias || matches_fn
 }
 
 /// Heuristic — does this PATH dir look like a TR-300 install location?
@@ -647,9 +733,12 @@ pub fn uninstall_complete() -> Result<()> {
     // First, uninstall from shell profiles
     uninstall()?;
 
-    // Then remove the binary and cleanup directory
+    // Then remove the binary (and its rollback copy) and cleanup directory
     if let Some(binary_path) = find_binary_location() {
         let parent_dir = get_binary_parent_dir(&binary_path);
+        if let Some(copy) = super::rollback_copy(&binary_path) {
+            remove_binary(&copy)?;
+        }
 
         // If the binary we're about to delete IS the currently-running
         // `tr300.exe`, Windows refuses `DeleteFile` with raw OS error 5
@@ -920,9 +1009,10 @@ fn fail_install(step: InstallStep, path: &Path, err: io::Error) -> AppError {
 #[cfg(test)]
 mod tests {
     use super::{
-        looks_like_onedrive_path, looks_like_redirected_path, policy_state, PolicyState,
-        POWERSHELL_ADDITIONS,
+        looks_like_onedrive_path, looks_like_redirected_path, policy_state, powershell_additions,
+        PolicyState, POWERSHELL_ADDITIONS,
     };
+    use crate::config::{AutorunPolicy, AutorunTrigger};
     use crate::install::shared::{
         ALIAS_NAME, AUTORUN_SENTINEL_VAR, BINARY_NAME, MARKER_END, MARKER_START,
     };
@@ -979,6 +1069,23 @@ mod tests {
         assert!(POWERSHELL_ADDITIONS.contains("\r\n"));
     }
 
+    #[test]
+    fn powershell_additions_render_the_autorun_policy() {
+        let classic = powershell_additions(&AutorunPolicy::default()).unwrap();
+        assert!(classic.contains("    tr300 --fast\r\n"));
+        assert!(!classic.contains("{TRIGGER}"));
+
+        let interval = AutorunPolicy::default()
+            .with_trigger(AutorunTrigger::EveryHours(8))
+            .with_args("--no-color")
+            .unwrap();
+        let rendered = powershell_additions(&interval).unwrap();
+        assert!(rendered.contains("tr300 --no-color --autorun-interval 8"));
+
+        let login = AutorunPolicy::default().with_trigger(AutorunTrigger::Login);
+        assert!(powershell_additions(&login).is_err());
+    }
+
     #[test]
     fn restricted_is_blocked_default() {
         assert_eq!(policy_state("Restricted"), PolicyState::BlockedDefault);
@@ -6,7 +6,7 @@
 //! # Example
 //!
 //! ```no_run
-//! use tr300::{collectors::{CollectMode, SystemInfo}, config::Config, report};
+//! use tr300::{collectors::{CollectMode, Section, SystemInfo}, config::Config, report};
 //!
 //! // Full report (default)
 //! let info = SystemInfo::collect().unwrap();
@@ -18,6 +18,14 @@
 //! let info = SystemInfo::collect_with_mode(CollectMode::Fast).unwrap();
 //! let report = report::generate(&info, &config);
 //! println!("{}", report);
+//!
+//! // Only some sections; the other collectors never run
+//! let info = SystemInfo::builder()
+//!     .sections([Section::Cpu, Section::Memory])
+//!     .mode(CollectMode::Fast)
+//!     .collect()
+//!     .unwrap();
+//! println!("{:.1}% memory used", info.mem_percent);
 //! ```
 //!
 //! # Public data types in v4
@@ -27,17 +35,21 @@
 //! external struct literals and exhaustive matches so later additive machine
 //! facts do not force another source-breaking release.
 
+pub mod check;
 pub mod cli;
 pub mod collectors;
 pub mod config;
 pub mod error;
+pub mod export;
 pub mod install;
 pub mod migrate;
+pub mod policy;
 pub mod render;
 pub mod report;
+pub(crate) mod signature;
 pub mod update;
 
-pub use collectors::{CollectMode, SystemInfo};
+pub use collectors::{CollectMode, Section, SystemInfo};
 pub use config::Config;
 pub use error::{AppError, Result};
 
@@ -5,17 +5,41 @@
 
 use clap::Parser;
 use tr300::{
+    check,
     cli::{Action, Cli},
-    collectors::{CollectMode, SystemInfo},
-    config::{Config, OutputFormat},
-    error::Result,
-    install, report, update,
+    collectors::{CollectMode, Section, SystemInfo, PLATFORM_FIELDS},
+    config::{
+        parse_interval_hours, AutorunPolicy, AutorunTrigger, Config, OutputFormat, ReleaseChannel,
+        ReleaseSource, SaveFormat,
+    },
+    error::{AppError, Result},
+    export, install, policy, report, update,
 };
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
     let action = cli.action;
 
+    if cli.pin.is_some() && !(cli.update || action == Some(Action::Update)) {
+        return Err(AppError::config(
+            "--pin X.Y.Z selects a release for `tr300 update`",
+        ));
+    }
+
+    if cli.from.is_some()
+        && !(cli.update || matches!(action, Some(Action::Update | Action::UpdateWorker)))
+    {
+        return Err(AppError::config(
+            "--from PATH installs a local release file; use `tr300 update --from PATH`",
+        ));
+    }
+
+    if cli.policy.is_some() && action != Some(Action::Assert) {
+        return Err(AppError::config(
+            "a policy file is only used by `tr300 assert POLICY`",
+        ));
+    }
+
     // Build configuration from CLI args (needed by --update)
     let mut config = Config::new().with_colors(!cli.no_color);
 
@@ -27,10 +51,20 @@ fn main() -> Result<()> {
         config = config.with_json();
     }
 
+    if let Some(ref format) = cli.format {
+        config = config.with_format(format.parse()?);
+    }
+
     if cli.no_elevation_hint {
         config = config.with_no_elevation_hint(true);
     }
 
+    // Fast mode can't sample per-process CPU usage, so --top/TR300_TOP is
+    // ignored there rather than failing the auto-run hook.
+    if let Some(top) = cli.top.filter(|_| !cli.fast) {
+        config = config.with_top_processes(top);
+    }
+
     if let Some(title) = cli.title {
         config = config.with_title(title);
     }
@@ -73,14 +107,23 @@ fn main() -> Result<()> {
     }
 
     if action == Some(Action::UpdateWorker) {
+        let config = config.with_release_source(release_source(
+            cli.releases_url.as_deref(),
+            cli.asset_base_url.as_deref(),
+            cli.trusted_key.as_deref(),
+        )?);
         let exit_code = match (
             cli.update_strategy.as_deref(),
             cli.update_version.as_deref(),
             cli.update_backup.as_deref(),
         ) {
-            (Some(strategy), Some(version), Some(backup)) => {
-                update::run_windows_update_worker(strategy, version, backup)
-            }
+            (Some(strategy), Some(version), Some(backup)) => update::run_windows_update_worker(
+                &config.release_source,
+                strategy,
+                version,
+                backup,
+                cli.from.as_deref(),
+            ),
             _ => 2,
         };
         #[cfg(windows)]
@@ -88,19 +131,155 @@ fn main() -> Result<()> {
         std::process::exit(exit_code);
     }
 
+    if action == Some(Action::UpdateNoticeRefresh) {
+        let source = release_source(cli.releases_url.as_deref(), None, None)
+            .and_then(|source| Ok(source.with_channel(release_channel(cli.channel.as_deref())?)));
+        let exit_code = match source {
+            Ok(source) => update::refresh_update_notice(&source),
+            Err(_) => 2,
+        };
+        #[cfg(windows)]
+        drop(_cp_guard);
+        std::process::exit(exit_code);
+    }
+
     if cli.update || action == Some(Action::Update) {
-        let exit_code = update::run(&config);
+        let config = config.with_release_source(
+            release_source(
+                cli.releases_url.as_deref(),
+                cli.asset_base_url.as_deref(),
+                cli.trusted_key.as_deref(),
+            )?
+            .with_channel(release_channel(cli.channel.as_deref())?),
+        );
+        let exit_code = if cli.check {
+            update::check(&config)
+        } else if cli.rollback {
+            update::rollback(&config)
+        } else {
+            let mut opts = update::UpdateOptions::default();
+            opts.version = cli.pin.clone();
+            opts.from = cli.from.clone();
+            update::run(&config, &opts)
+        };
+        #[cfg(windows)]
+        drop(_cp_guard);
+        std::process::exit(exit_code);
+    }
+
+    if (cli.install || action == Some(Action::Install)) && cli.status {
+        let status = install::check_status();
+        if config.format == OutputFormat::Json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&install::status::status_json(&status))
+                    .unwrap_or_else(|_| "{}".to_string())
+            );
+        } else {
+            print!("{}", install::status::render_status(&status));
+        }
+        // Inconsistent installs exit 2, like `migrate-cleanup --strict`.
+        let exit_code = if status.problems().is_empty() { 0 } else { 2 };
         #[cfg(windows)]
         drop(_cp_guard);
         std::process::exit(exit_code);
     }
 
     if cli.install || action == Some(Action::Install) {
-        return run_install();
+        let mut autorun = AutorunPolicy::default();
+        if let Some(ref when) = cli.autorun {
+            autorun = autorun.with_trigger(when.parse()?);
+        }
+        if let Some(ref args) = cli.autorun_args {
+            autorun = autorun.with_args(args)?;
+        }
+        let config = config.with_autorun(autorun);
+        if cli.dry_run {
+            return run_install_plan(&config, cli.system);
+        }
+        return run_install(&config, cli.system);
     }
 
     if cli.uninstall || action == Some(Action::Uninstall) {
-        return run_uninstall();
+        let option = if cli.complete {
+            Some(install::UninstallOption::Complete)
+        } else if cli.profile_only || cli.system {
+            Some(install::UninstallOption::ProfileOnly)
+        } else {
+            None
+        };
+        if cli.dry_run {
+            let option = option.unwrap_or(install::UninstallOption::ProfileOnly);
+            return run_uninstall_plan(&config, option, cli.system);
+        }
+        if cli.system {
+            return install::uninstall_system();
+        }
+        return run_uninstall(option, cli.yes);
+    }
+
+    if action == Some(Action::Check) {
+        let mode = if cli.fast {
+            CollectMode::Fast
+        } else {
+            CollectMode::Full
+        };
+        let exit_code = run_check(config, mode, cli.thresholds.as_deref());
+        #[cfg(windows)]
+        drop(_cp_guard);
+        std::process::exit(exit_code);
+    }
+
+    if action == Some(Action::Assert) {
+        let mode = if cli.fast {
+            CollectMode::Fast
+        } else {
+            CollectMode::Full
+        };
+        let exit_code = run_assert(
+            &config,
+            mode,
+            cli.policy.as_deref(),
+            cli.input.as_deref(),
+            cli.junit.as_deref(),
+        );
+        #[cfg(windows)]
+        drop(_cp_guard);
+        std::process::exit(exit_code);
+    }
+
+    // Interval-gated auto-run from the shell hook: stay silent until the
+    // configured number of hours has passed since the last report.
+    if let Some(hours) = cli.autorun_interval {
+        if !install::autorun::claim_interval_slot(hours) {
+            return Ok(());
+        }
+    }
+
+    // Opt-in passive update notice. Like the update paths' own settings, a
+    // bad value only disables the notice; it never breaks the report.
+    if let Some(ref every) = cli.update_notice {
+        match parse_interval_hours(every).and_then(|hours| {
+            let source = release_source(cli.releases_url.as_deref(), None, None)?
+                .with_channel(release_channel(cli.channel.as_deref())?);
+            Ok((hours, source))
+        }) {
+            Ok((hours, source)) => {
+                config = config
+                    .with_update_notice(Some(hours))
+                    .with_release_source(source);
+            }
+            Err(e) => eprintln!("Warning: update notice disabled: {}", e),
+        }
+    }
+
+    // Threshold overrides only recolour the table here; a bad spec keeps the
+    // defaults rather than breaking the report.
+    if let Some(ref spec) = cli.thresholds {
+        match config.thresholds.with_spec(spec) {
+            Ok(thresholds) => config = config.with_thresholds(thresholds),
+            Err(e) => eprintln!("Warning: thresholds ignored: {}", e),
+        }
     }
 
     // Determine collection mode
@@ -110,16 +289,123 @@ fn main() -> Result<()> {
         CollectMode::Full
     };
 
+    if let Some(ref path) = cli.get {
+        return run_get(path, mode, config.top_processes);
+    }
+    let config = config.with_sections(report_sections(cli.fields.as_deref())?);
+
+    // The save flag writes a file next to the printed table; other stdout
+    // formats are already the file (`tr300 --format html > report.html`).
+    let save = if cli.save_report {
+        if config.format != OutputFormat::Table {
+            return Err(AppError::config(
+                "--save only saves the table report; use --save-format to pick the file format",
+            ));
+        }
+        Some(
+            cli.save_format
+                .as_deref()
+                .map_or(Ok(SaveFormat::default()), str::parse)?,
+        )
+    } else {
+        None
+    };
+
     // Run the report
-    run_report(&config, mode, cli.save_report)
+    run_report(&config, mode, save)
+}
+
+/// Sections from `--fields` (default: all of them)
+fn report_sections(fields: Option<&str>) -> Result<Vec<Section>> {
+    let Some(fields) = fields else {
+        return Ok(Section::ALL.to_vec());
+    };
+    let sections = fields
+        .split(',')
+        .filter(|name| !name.trim().is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<Section>>>()?;
+    if sections.is_empty() {
+        return Err(AppError::config("--fields needs at least one section"));
+    }
+    Ok(sections)
+}
+
+/// Print one value for `--get PATH`, collecting only the section the path
+/// starts in (none for report metadata such as `schema_version`).
+fn run_get(path: &str, mode: CollectMode, top: usize) -> Result<()> {
+    let mut sections: Vec<Section> = path
+        .split('.')
+        .next()
+        .and_then(Section::from_json_key)
+        .into_iter()
+        .collect();
+    // Platform extras of other sections need the platform collector, which
+    // only runs along with the system section.
+    if PLATFORM_FIELDS
+        .iter()
+        .any(|field| path == *field || path.starts_with(&format!("{field}.")))
+    {
+        sections.push(Section::System);
+    }
+    let info = SystemInfo::builder()
+        .sections(sections)
+        .mode(mode)
+        .top_processes(top)
+        .collect()?;
+    match export::get(&report::json_value(&info), path) {
+        Some(value) => {
+            println!("{}", value);
+            Ok(())
+        }
+        None => Err(AppError::config(format!(
+            "the report has no field `{}` (see `tr300 --json` for field paths)",
+            path
+        ))),
+    }
+}
+
+/// Release host for `tr300 update`, from `--releases-url` / `--asset-base-url`
+/// / `--trusted-key` or their `TR300_*` environment variables. Validated only
+/// on update paths so a bad value never breaks the report itself.
+fn release_source(
+    api_url: Option<&str>,
+    asset_base_url: Option<&str>,
+    trusted_key: Option<&str>,
+) -> Result<ReleaseSource> {
+    let mut source = ReleaseSource::default();
+    if let Some(url) = api_url {
+        source = source.with_api_url(url)?;
+    }
+    if let Some(url) = asset_base_url {
+        source = source.with_asset_base_url(url)?;
+    }
+    if let Some(key) = trusted_key {
+        source = source.with_trusted_key(key)?;
+    }
+    Ok(source)
+}
+
+/// Release channel from `--channel` / `TR300_UPDATE_CHANNEL` (default stable)
+fn release_channel(channel: Option<&str>) -> Result<ReleaseChannel> {
+    channel.map_or(Ok(ReleaseChannel::default()), str::parse)
 }
 
 /// Run the main system report
-fn run_report(config: &Config, mode: CollectMode, save_report: bool) -> Result<()> {
+fn run_report(config: &Config, mode: CollectMode, save: Option<SaveFormat>) -> Result<()> {
     use std::io::Write;
 
-    let info = SystemInfo::collect_with_mode(mode)?;
-    let output = report::generate(&info, config);
+    let info = SystemInfo::builder()
+        .sections(config.sections.iter().copied())
+        .mode(mode)
+        .top_processes(config.top_processes)
+        .collect()?;
+    let mut output = report::generate(&info, config);
+    if config.format == OutputFormat::Table {
+        if let Some(latest) = update::update_notice(config) {
+            output.push_str(&report::render_update_notice(&latest, config.use_colors));
+        }
+    }
     print!("{}", output);
     std::io::stdout().flush()?;
 
@@ -128,29 +414,166 @@ fn run_report(config: &Config, mode: CollectMode, save_report: bool) -> Result<(
     // report-file write as suspicious and stall the host. Clap restricts the
     // explicit save aliases to full table mode; retain the defensive runtime
     // gate in case this function is later called from another entry point.
-    if save_report && mode == CollectMode::Full && config.format == OutputFormat::Table {
-        match report::save_markdown_report(&info) {
+    let save = save.filter(|_| mode == CollectMode::Full && config.format == OutputFormat::Table);
+    if let Some(format) = save {
+        match report::save_report(&info, config, format) {
             Ok(outcome) if outcome.used_cwd_fallback => eprintln!(
                 "Report saved: {} (Downloads folder not found — saved to the current directory)",
                 outcome.path.display()
             ),
             Ok(outcome) => eprintln!("Report saved: {}", outcome.path.display()),
-            Err(e) => eprintln!("Warning: could not save markdown report: {}", e),
+            Err(e) => eprintln!("Warning: could not save report: {}", e),
         }
     }
 
     Ok(())
 }
 
-/// Install tr300 to shell profile
-fn run_install() -> Result<()> {
+/// Run the threshold health check and return its Nagios exit code. A bad
+/// threshold spec or a failed collection is UNKNOWN (3), never a crash, so
+/// monitoring sees a plugin result either way.
+fn run_check(config: Config, mode: CollectMode, thresholds: Option<&str>) -> i32 {
+    let json = config.format == OutputFormat::Json;
+    let unknown = |message: String| {
+        if json {
+            println!(
+                "{}",
+                serde_json::json!({
+                    "status": "UNKNOWN",
+                    "exit_code": 3,
+                    "message": message,
+                    "checks": [],
+                })
+            );
+        } else {
+            println!("TR300 UNKNOWN - {}", message);
+        }
+        3
+    };
+    let config = match thresholds.map_or(Ok(config.thresholds), |spec| {
+        config.thresholds.with_spec(spec)
+    }) {
+        Ok(thresholds) => config.with_thresholds(thresholds),
+        Err(e) => return unknown(e.to_string()),
+    };
+    let info = match SystemInfo::collect_with_mode(mode) {
+        Ok(info) => info,
+        Err(e) => return unknown(format!("collection failed: {}", e)),
+    };
+    let result = check::evaluate(&info, &config.thresholds);
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&check::json(&result))
+                .unwrap_or_else(|_| "{}".to_string())
+        );
+    } else {
+        print!("{}", check::render(&result));
+    }
+    result.status.exit_code()
+}
+
+/// Evaluate a policy file against this machine (or a saved JSON report) and
+/// return 0 when every assertion passes, 1 when any fails, and 2 when the
+/// policy, the input, or the JUnit output could not be handled.
+fn run_assert(
+    config: &Config,
+    mode: CollectMode,
+    policy_path: Option<&std::path::Path>,
+    input: Option<&std::path::Path>,
+    junit: Option<&std::path::Path>,
+) -> i32 {
+    let Some(policy_path) = policy_path else {
+        eprintln!("Error: assert needs a policy file: `tr300 assert policy.toml`");
+        return 2;
+    };
+    let result = (|| -> Result<(Vec<policy::Assertion>, String)> {
+        let text = std::fs::read_to_string(policy_path).map_err(|e| {
+            AppError::config(format!("could not read {}: {}", policy_path.display(), e))
+        })?;
+        let rules = policy::Policy::parse(&text)?;
+        let report = match input {
+            Some(path) => {
+                let text = std::fs::read_to_string(path).map_err(|e| {
+                    AppError::config(format!("could not read {}: {}", path.display(), e))
+                })?;
+                serde_json::from_str(&text).map_err(|e| {
+                    AppError::config(format!("{} is not valid JSON: {}", path.display(), e))
+                })?
+            }
+            None => report::json_value(&SystemInfo::collect_with_mode(mode)?),
+        };
+        let hostname = report["network"]["hostname"]
+            .as_str()
+            .unwrap_or("unknown")
+            .to_string();
+        Ok((policy::evaluate(&rules, &report)?, hostname))
+    })();
+    let (assertions, hostname) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return 2;
+        }
+    };
+
+    let source = policy_path.display().to_string();
+    if config.format == OutputFormat::Json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&policy::json(&source, &assertions))
+                .unwrap_or_else(|_| "{}".to_string())
+        );
+    } else {
+        print!("{}", policy::render(&source, &assertions));
+    }
+    if let Some(path) = junit {
+        let suite = policy_path
+            .file_stem()
+            .map_or_else(|| source.clone(), |s| s.to_string_lossy().into_owned());
+        if let Err(e) = std::fs::write(path, policy::junit_xml(&suite, &hostname, &assertions)) {
+            eprintln!("Error: could not write {}: {}", path.display(), e);
+            return 2;
+        }
+    }
+    if assertions.iter().all(|a| a.passed) {
+        0
+    } else {
+        1
+    }
+}
+
+/// Install tr300 to shell profile (or system-wide with `--system`)
+fn run_install(config: &Config, system: bool) -> Result<()> {
+    let mut options = install::InstallOptions::default();
+    options.autorun = config.autorun.clone();
+    options.system = system;
+
     println!("Installing TR-300...");
-    install::install()?;
+    install::install_with_options(&options)?;
+    if system {
+        println!("Installation complete! New login shells for every user will pick it up.");
+        return Ok(());
+    }
     println!("Installation complete!");
     println!();
     println!("The following changes were made:");
     println!("  - Added 'report' alias for tr300");
-    println!("  - Added auto-run on new interactive shells");
+    let when = match options.autorun.trigger {
+        AutorunTrigger::Ssh => "on new interactive shells started over SSH".to_string(),
+        AutorunTrigger::Login => "on new interactive login shells".to_string(),
+        AutorunTrigger::EveryHours(hours) => format!(
+            "on new interactive shells, at most once every {} hour{}",
+            hours,
+            if hours == 1 { "" } else { "s" }
+        ),
+        _ => "on new interactive shells".to_string(),
+    };
+    println!(
+        "  - Added auto-run ({}) {}",
+        options.autorun.args.join(" "),
+        when
+    );
     println!();
     #[cfg(target_os = "macos")]
     println!("Please restart your shell or run 'source ~/.zshrc' (or the profile shown above)");
@@ -160,14 +583,65 @@ fn run_install() -> Result<()> {
     Ok(())
 }
 
-/// Uninstall tr300 from shell profile (interactive)
-fn run_uninstall() -> Result<()> {
+/// Print what `install` would change without changing it
+fn run_install_plan(config: &Config, system: bool) -> Result<()> {
+    let mut options = install::InstallOptions::default();
+    options.autorun = config.autorun.clone();
+    options.system = system;
+
+    let changes = install::plan_install(&options)?;
+    if config.format == OutputFormat::Json {
+        let requested = serde_json::json!({
+            "system": system,
+            "autorun": options.autorun.trigger.to_string(),
+            "autorun_args": options.autorun.args,
+        });
+        print_plan_json("install", requested, &changes);
+    } else {
+        print!("{}", install::plan::render_plan("install", &changes));
+    }
+    Ok(())
+}
+
+/// Print what `uninstall` would change without changing it
+fn run_uninstall_plan(
+    config: &Config,
+    option: install::UninstallOption,
+    system: bool,
+) -> Result<()> {
+    let changes = install::plan_uninstall(option, system)?;
+    if config.format == OutputFormat::Json {
+        let mode = if option == install::UninstallOption::Complete {
+            "complete"
+        } else {
+            "profile_only"
+        };
+        let requested = serde_json::json!({ "system": system, "mode": mode });
+        print_plan_json("uninstall", requested, &changes);
+    } else {
+        print!("{}", install::plan::render_plan("uninstall", &changes));
+    }
+    Ok(())
+}
+
+fn print_plan_json(action: &str, requested: serde_json::Value, changes: &[install::PlannedChange]) {
+    let output = install::plan::plan_json(action, requested, changes);
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&output).unwrap_or_else(|_| "{}".to_string())
+    );
+}
+
+/// Uninstall tr300 from shell profile. Prompts for the mode unless one was
+/// given on the command line, and confirms a complete uninstall unless
+/// `assume_yes`.
+fn run_uninstall(option: Option<install::UninstallOption>, assume_yes: bool) -> Result<()> {
     use install::{
         confirm_complete_uninstall, find_binary_location, get_binary_parent_dir,
         prompt_uninstall_option, UninstallOption,
     };
 
-    let option = prompt_uninstall_option();
+    let option = option.unwrap_or_else(prompt_uninstall_option);
 
     match option {
         UninstallOption::Cancel => {
@@ -192,7 +666,7 @@ fn run_uninstall() -> Result<()> {
                 .and_then(|p| get_binary_parent_dir(p.as_path()));
 
             if let Some(ref path) = binary_path {
-                if !confirm_complete_uninstall(path, parent_dir.as_deref()) {
+                if !assume_yes && !confirm_complete_uninstall(path, parent_dir.as_deref()) {
                     println!();
                     println!("Uninstall cancelled.");
                     return Ok(());
@@ -0,0 +1,602 @@
+//! Policy assertions (`tr300 assert policy.toml`)
+//!
+//! A policy file declares facts a machine must have before it is trusted,
+//! for example:
+//!
+//! ```toml
+//! [os]
+//! family = "linux"          # or ["linux", "macos"]
+//! min_kernel = "6.1"
+//!
+//! [hardware]
+//! min_memory_gib = 16
+//! min_cores = 8
+//! hypervisor = ["none", "KVM"] # "none": no hypervisor detected
+//!
+//! [security]
+//! encryption = true         # root / system volume encrypted
+//! secure_boot = true
+//! ```
+//!
+//! Rules are checked against the schema-v1 JSON field tree, so a machine can
+//! be evaluated live or from a report saved earlier with `tr300 --json`.
+
+use crate::error::{AppError, Result};
+use serde_json::Value;
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// One requirement from a policy file
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// `os.family`: one of these (`linux`, `macos`, `windows`)
+    OsFamily(Vec<String>),
+    /// `os.min_kernel`: kernel version at least this
+    MinKernel(String),
+    /// `os.min_version`: OS version at least this
+    MinOsVersion(String),
+    /// `hardware.min_memory_gib`: installed memory at least this many GiB
+    MinMemoryGib(f64),
+    /// `hardware.min_cores`: logical processors at least this
+    MinCores(u64),
+    /// `hardware.min_physical_cores`: physical cores at least this
+    MinPhysicalCores(u64),
+    /// `hardware.hypervisor`: one of these (`none` when none is detected)
+    Hypervisor(Vec<String>),
+    /// `security.encryption`: root / system volume encryption on (or off)
+    Encryption(bool),
+    /// `security.secure_boot`: UEFI Secure Boot on (or off)
+    SecureBoot(bool),
+}
+
+impl Rule {
+    /// The policy key this rule came from, e.g. `os.min_kernel`
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::OsFamily(_) => "os.family",
+            Self::MinKernel(_) => "os.min_kernel",
+            Self::MinOsVersion(_) => "os.min_version",
+            Self::MinMemoryGib(_) => "hardware.min_memory_gib",
+            Self::MinCores(_) => "hardware.min_cores",
+            Self::MinPhysicalCores(_) => "hardware.min_physical_cores",
+            Self::Hypervisor(_) => "hardware.hypervisor",
+            Self::Encryption(_) => "security.encryption",
+            Self::SecureBoot(_) => "security.secure_boot",
+        }
+    }
+}
+
+/// A parsed policy file
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Policy {
+    /// Rules in file-independent, fixed order (os, hardware, security)
+    pub rules: Vec<Rule>,
+}
+
+impl Policy {
+    /// Parse policy TOML. Unknown sections and keys are errors, so a typo
+    /// never silently drops a requirement.
+    pub fn parse(text: &str) -> Result<Self> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|e| AppError::config(format!("invalid policy file: {}", e)))?;
+        let mut rules = Vec::new();
+        for (section, keys) in [
+            ("os", &["family", "min_kernel", "min_version"][..]),
+            (
+                "hardware",
+                &[
+                    "min_memory_gib",
+                    "min_cores",
+                    "min_physical_cores",
+                    "hypervisor",
+                ][..],
+            ),
+            ("security", &["encryption", "secure_boot"][..]),
+        ] {
+            let Some(value) = table.get(section) else {
+                continue;
+            };
+            let entries = value.as_table().ok_or_else(|| {
+                AppError::config(format!(
+                    "policy `{}` must be a [{}] table",
+                    section, section
+                ))
+            })?;
+            if let Some(unknown) = entries.keys().find(|key| !keys.contains(&key.as_str())) {
+                return Err(AppError::config(format!(
+                    "unknown policy key `{}.{}` (expected one of {})",
+                    section,
+                    unknown,
+                    keys.join(", ")
+                )));
+            }
+            for key in keys {
+                if let Some(value) = entries.get(*key) {
+                    rules.push(parse_rule(section, key, value)?);
+                }
+            }
+        }
+        if let Some(unknown) = table
+            .keys()
+            .find(|key| !["os", "hardware", "security"].contains(&key.as_str()))
+        {
+            return Err(AppError::config(format!(
+                "unknown policy section `[{}]` (expected os, hardware, security)",
+                unknown
+            )));
+        }
+        if rules.is_empty() {
+            return Err(AppError::config("policy file declares no rules"));
+        }
+        Ok(Self { rules })
+    }
+}
+
+fn parse_rule(section: &str, key: &str, value: &toml::Value) -> Result<Rule> {
+    let invalid = |expected: &str| {
+        AppError::config(format!("policy `{}.{}` must be {}", section, key, expected))
+    };
+    let strings = || -> Result<Vec<String>> {
+        match value {
+            toml::Value::String(s) => Ok(vec![s.clone()]),
+            toml::Value::Array(items) if !items.is_empty() => items
+                .iter()
+                .map(|item| item.as_str().map(str::to_string))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| invalid("a string or a list of strings")),
+            _ => Err(invalid("a string or a list of strings")),
+        }
+    };
+    let version = || -> Result<String> {
+        value
+            .as_str()
+            .filter(|v| version_parts(v).is_some())
+            .map(str::to_string)
+            .ok_or_else(|| invalid("a version string such as \"6.1\""))
+    };
+    let count = || -> Result<u64> {
+        value
+            .as_integer()
+            .and_then(|n| u64::try_from(n).ok())
+            .ok_or_else(|| invalid("a non-negative integer"))
+    };
+    let flag = || value.as_bool().ok_or_else(|| invalid("true or false"));
+
+    Ok(match key {
+        "family" => {
+            let families = strings()?;
+            if let Some(bad) = families
+                .iter()
+                .find(|f| !["linux", "macos", "windows"].contains(&f.as_str()))
+            {
+                return Err(AppError::config(format!(
+                    "unknown OS family `{}` in policy (expected linux, macos, or windows)",
+                    bad
+                )));
+            }
+            Rule::OsFamily(families)
+        }
+        "min_kernel" => Rule::MinKernel(version()?),
+        "min_version" => Rule::MinOsVersion(version()?),
+        "min_memory_gib" => Rule::MinMemoryGib(
+            value
+                .as_float()
+                .or_else(|| value.as_integer().map(|n| n as f64))
+                .filter(|n| n.is_finite() && *n >= 0.0)
+                .ok_or_else(|| invalid("a non-negative number"))?,
+        ),
+        "min_cores" => Rule::MinCores(count()?),
+        "min_physical_cores" => Rule::MinPhysicalCores(count()?),
+        "hypervisor" => Rule::Hypervisor(strings()?),
+        "encryption" => Rule::Encryption(flag()?),
+        _ => Rule::SecureBoot(flag()?),
+    })
+}
+
+/// Outcome of one rule
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Assertion {
+    /// Policy key, e.g. `hardware.min_cores`
+    pub rule: &'static str,
+    pub expected: String,
+    /// What the report says, or `unknown` when it does not say
+    pub actual: String,
+    pub passed: bool,
+}
+
+/// Check a schema-v1 report (from `tr300 --json`) against a policy. A fact
+/// the report does not contain fails its rule: a policy gate must not pass
+/// on missing evidence.
+pub fn evaluate(policy: &Policy, report: &Value) -> Result<Vec<Assertion>> {
+    match report["schema_version"].as_u64() {
+        Some(1) => {}
+        Some(other) => {
+            return Err(AppError::config(format!(
+                "report schema version {} is not supported (expected 1)",
+                other
+            )))
+        }
+        None => return Err(AppError::config(
+            "input is not a TR-300 JSON report (no schema_version); save one with `tr300 --json`",
+        )),
+    }
+
+    let text = |pointer: &str| report.pointer(pointer).and_then(Value::as_str);
+    let number = |pointer: &str| report.pointer(pointer).and_then(Value::as_f64);
+    let unknown = || "unknown".to_string();
+
+    Ok(policy
+        .rules
+        .iter()
+        .map(|rule| {
+            let (expected, actual, passed) = match rule {
+                Rule::OsFamily(families) => {
+                    let actual = text("/os/family");
+                    (
+                        families.join(" or "),
+                        actual.map_or_else(unknown, str::to_string),
+                        actual.is_some_and(|a| families.iter().any(|f| f == a)),
+                    )
+                }
+                Rule::MinKernel(min) => version_at_least(text("/os/kernel"), min),
+                Rule::MinOsVersion(min) => version_at_least(text("/os/version"), min),
+                Rule::MinMemoryGib(min) => {
+                    let gib = number("/memory/total_bytes").map(|bytes| bytes / GIB);
+                    (
+                        format!(">= {} GiB", min),
+                        gib.map_or_else(unknown, |g| format!("{:.1} GiB", g)),
+                        // Firmware reserves some memory, so a nominal 16 GiB
+                        // machine reports a little less; allow 5%.
+                        gib.is_some_and(|g| g >= min * 0.95),
+                    )
+                }
+                Rule::MinCores(min) => count_at_least(number("/cpu/logical_processors"), *min),
+                Rule::MinPhysicalCores(min) => count_at_least(number("/cpu/physical_cores"), *min),
+                Rule::Hypervisor(accepted) => {
+                    // The collectors leave this null when they find no
+                    // hypervisor, so `none` matches that rather than failing
+                    // as an unknown fact.
+                    let actual = text("/cpu/hypervisor");
+                    (
+                        accepted.join(" or "),
+                        actual.unwrap_or("none detected").to_string(),
+                        accepted.iter().any(|h| match actual {
+                            Some(a) => h.eq_ignore_ascii_case(a),
+                            None => h.eq_ignore_ascii_case("none"),
+                        }),
+                    )
+                }
+                Rule::Encryption(required) => {
+                    let actual = text("/session/encryption");
+                    (
+                        if *required {
+                            "encrypted"
+                        } else {
+                            "not encrypted"
+                        }
+                        .to_string(),
+                        actual.map_or_else(unknown, str::to_string),
+                        actual.is_some_and(|a| encryption_on(a) == *required),
+                    )
+                }
+                Rule::SecureBoot(required) => {
+                    let actual = report
+                        .pointer("/system/secure_boot")
+                        .and_then(Value::as_bool);
+                    (
+                        on_off(*required),
+                        actual.map_or_else(unknown, on_off),
+                        actual == Some(*required),
+                    )
+                }
+            };
+            Assertion {
+                rule: rule.key(),
+                expected,
+                actual,
+                passed,
+            }
+        })
+        .collect())
+}
+
+fn on_off(on: bool) -> String {
+    if on { "on" } else { "off" }.to_string()
+}
+
+fn count_at_least(actual: Option<f64>, min: u64) -> (String, String, bool) {
+    (
+        format!(">= {}", min),
+        actual.map_or_else(|| "unknown".to_string(), |n| format!("{}", n)),
+        actual.is_some_and(|n| n >= min as f64),
+    )
+}
+
+fn version_at_least(actual: Option<&str>, min: &str) -> (String, String, bool) {
+    let passed = match (actual.and_then(version_parts), version_parts(min)) {
+        (Some(mut have), Some(mut want)) => {
+            let len = have.len().max(want.len());
+            have.resize(len, 0);
+            want.resize(len, 0);
+            have >= want
+        }
+        _ => false,
+    };
+    (
+        format!(">= {}", min),
+        actual.unwrap_or("unknown").to_string(),
+        passed,
+    )
+}
+
+/// Leading dotted numbers of a version: `6.8.0-45-generic` -> `[6, 8, 0]`
+fn version_parts(version: &str) -> Option<Vec<u64>> {
+    let parts: Vec<u64> = version
+        .trim()
+        .trim_start_matches('v')
+        .split('.')
+        .map_while(|part| {
+            let digits: String = part.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse().ok()
+        })
+        .collect();
+    (!parts.is_empty()).then_some(parts)
+}
+
+/// Whether a collector's encryption summary means the system volume is
+/// encrypted and protected. A suspended BitLocker volume leaves its key in
+/// the clear, so it does not count.
+fn encryption_on(summary: &str) -> bool {
+    summary.starts_with("Root volume:")
+        || summary == "FileVault On"
+        || summary.starts_with("BitLocker: encrypted; protection on")
+}
+
+/// Pass/fail table for the terminal
+pub fn render(source: &str, assertions: &[Assertion]) -> String {
+    let rule_width = assertions
+        .iter()
+        .map(|a| a.rule.len())
+        .max()
+        .unwrap_or(0)
+        .max("RULE".len());
+    let expected_width = assertions
+        .iter()
+        .map(|a| a.expected.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("EXPECTED".len());
+    let mut out = format!("TR-300 policy check: {}\n", source);
+    out.push_str(&format!(
+        "  {:<6} {:<rule_width$}  {:<expected_width$}  ACTUAL\n",
+        "RESULT", "RULE", "EXPECTED"
+    ));
+    for a in assertions {
+        out.push_str(&format!(
+            "  {:<6} {:<rule_width$}  {:<expected_width$}  {}\n",
+            if a.passed { "PASS" } else { "FAIL" },
+            a.rule,
+            a.expected,
+            a.actual
+        ));
+    }
+    let passed = assertions.iter().filter(|a| a.passed).count();
+    out.push_str(&format!(
+        "{} of {} assertions passed\n",
+        passed,
+        assertions.len()
+    ));
+    out
+}
+
+/// `--json` form of a policy result
+pub fn json(source: &str, assertions: &[Assertion]) -> Value {
+    serde_json::json!({
+        "policy": source,
+        "passed": assertions.iter().all(|a| a.passed),
+        "assertions": assertions.iter().map(|a| serde_json::json!({
+            "rule": a.rule,
+            "expected": a.expected,
+            "actual": a.actual,
+            "passed": a.passed,
+        })).collect::<Vec<_>>(),
+    })
+}
+
+/// JUnit XML for CI systems: one test case per rule, failures carrying the
+/// expected and actual values.
+pub fn junit_xml(suite: &str, hostname: &str, assertions: &[Assertion]) -> String {
+    let failures = assertions.iter().filter(|a| !a.passed).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"tr300 assert\" tests=\"{}\" failures=\"{}\">\n",
+        assertions.len(),
+        failures
+    ));
+    xml.push_str(&format!(
+        "  <testsuite name=\"{}\" hostname=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\">\n",
+        xml_escape(suite),
+        xml_escape(hostname),
+        assertions.len(),
+        failures
+    ));
+    for a in assertions {
+        let name = xml_escape(a.rule);
+        if a.passed {
+            xml.push_str(&format!(
+                "    <testcase classname=\"tr300.policy\" name=\"{}\"/>\n",
+                name
+            ));
+        } else {
+            xml.push_str(&format!(
+                "    <testcase classname=\"tr300.policy\" name=\"{}\">\n      <failure message=\"expected {}, found {}\"/>\n    </testcase>\n",
+                name,
+                xml_escape(&a.expected),
+                xml_escape(&a.actual)
+            ));
+        }
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+fn xml_escape(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n'))
+        .fold(String::new(), |mut out, c| {
+            match c {
+                '&' => out.push_str("&amp;"),
+                '<' => out.push_str("&lt;"),
+                '>' => out.push_str("&gt;"),
+                '"' => out.push_str("&quot;"),
+                '\'' => out.push_str("&apos;"),
+                c => out.push(c),
+            }
+            out
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::{json_value, tests::fixture_info};
+
+    fn report() -> Value {
+        let mut info = fixture_info();
+        info.kernel = "6.8.0-45-generic".to_string();
+        info.mem_total_bytes = 15_800_000_000; // a "16 GB" machine
+        info.encryption = Some("Root volume: LUKS/dm-crypt".to_string());
+        json_value(&info)
+    }
+
+    const POLICY: &str = r#"
+        [os]
+        family = ["linux", "macos"]
+        min_kernel = "6.1"
+
+        [hardware]
+        min_memory_gib = 14
+        min_cores = 8
+        min_physical_cores = 8
+        hypervisor = "kvm"
+
+        [security]
+        encryption = true
+        secure_boot = true
+    "#;
+
+    #[test]
+    fn evaluates_each_rule_against_the_report() {
+        let policy = Policy::parse(POLICY).unwrap();
+        let mut report = report();
+        report["os"]["family"] = "linux".into();
+        let results = evaluate(&policy, &report).unwrap();
+        let failed: Vec<_> = results
+            .iter()
+            .filter(|a| !a.passed)
+            .map(|a| a.rule)
+            .collect();
+        assert_eq!(results.len(), 8);
+        assert_eq!(failed, ["hardware.min_physical_cores"]);
+
+        let table = render("policy.toml", &results);
+        assert!(table.contains("FAIL   hardware.min_physical_cores  >= 8"));
+        assert!(table.ends_with("7 of 8 assertions passed\n"));
+        assert_eq!(json("policy.toml", &results)["passed"], false);
+    }
+
+    #[test]
+    fn missing_facts_fail_instead_of_passing() {
+        let policy = Policy::parse("[security]\nsecure_boot = false\nencryption = false").unwrap();
+        let mut report = report();
+        report["system"]["secure_boot"] = Value::Null;
+        report["session"]["encryption"] = Value::Null;
+        let results = evaluate(&policy, &report).unwrap();
+        assert!(results.iter().all(|a| !a.passed && a.actual == "unknown"));
+    }
+
+    #[test]
+    fn no_detected_hypervisor_matches_none() {
+        let policy = Policy::parse("[hardware]\nhypervisor = [\"none\", \"KVM\"]").unwrap();
+        let mut report = report();
+        report["cpu"]["hypervisor"] = Value::Null;
+        let results = evaluate(&policy, &report).unwrap();
+        assert!(results[0].passed);
+        assert_eq!(results[0].actual, "none detected");
+
+        report["cpu"]["hypervisor"] = "VMware".into();
+        assert!(!evaluate(&policy, &report).unwrap()[0].passed);
+    }
+
+    #[test]
+    fn versions_compare_numerically() {
+        assert!(version_at_least(Some("6.10.2"), "6.9").2);
+        assert!(version_at_least(Some("6.1"), "6.1.0").2);
+        assert!(!version_at_least(Some("5.15.0-91-generic"), "6.1").2);
+        assert!(!version_at_least(Some("unknown"), "6.1").2);
+        assert_eq!(version_parts("v10.0.22631"), Some(vec![10, 0, 22631]));
+    }
+
+    #[test]
+    fn encryption_requires_an_active_protected_volume() {
+        assert!(encryption_on("Root volume: ZFS native encryption"));
+        assert!(encryption_on("FileVault On"));
+        assert!(encryption_on(
+            "BitLocker: encrypted; protection on (XTS-AES 128)"
+        ));
+        assert!(!encryption_on("BitLocker: encrypted; protection suspended"));
+        assert!(!encryption_on("BitLocker: encrypting 40%; protection on"));
+        assert!(!encryption_on("FileVault Off"));
+    }
+
+    #[test]
+    fn rejects_typos_and_bad_values() {
+        for bad in [
+            "",
+            "[os]\nfamly = \"linux\"",
+            "[network]\nx = 1",
+            "[os]\nfamily = \"beos\"",
+            "[os]\nmin_kernel = \"latest\"",
+            "[hardware]\nmin_cores = -1",
+            "[security]\nsecure_boot = \"yes\"",
+            "os = 1",
+            "not toml [",
+        ] {
+            assert!(Policy::parse(bad).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn rejects_inputs_that_are_not_reports() {
+        let policy = Policy::parse("[hardware]\nmin_cores = 1").unwrap();
+        assert!(evaluate(&policy, &serde_json::json!({})).is_err());
+        assert!(evaluate(&policy, &serde_json::json!({"schema_version": 2})).is_err());
+    }
+
+    #[test]
+    fn junit_lists_failures_with_escaped_values() {
+        let results = vec![
+            Assertion {
+                rule: "os.family",
+                expected: "linux".to_string(),
+                actual: "linux".to_string(),
+                passed: true,
+            },
+            Assertion {
+                rule: "hardware.hypervisor",
+                expected: "KVM".to_string(),
+                actual: "VMware <ESXi> & \"co\"".to_string(),
+                passed: false,
+            },
+        ];
+        let xml = junit_xml("dev-baseline", "host", &results);
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase classname=\"tr300.policy\" name=\"os.family\"/>"));
+        assert!(xml
+            .contains("message=\"expected KVM, found VMware &lt;ESXi&gt; &amp; &quot;co&quot;\""));
+    }
+}
@@ -193,6 +193,17 @@ impl TableRenderer {
 
     /// Render a data row: │ LABEL      │ VALUE                │
     pub fn render_row(&self, label: &str, value: &str) -> String {
+        self.render_row_styled(label, value, None)
+    }
+
+    /// Render a data row whose value is wrapped in an ANSI SGR style such as
+    /// `"31"` (red). The value is fitted to the column before the escape
+    /// codes are added, so they never count toward its width.
+    pub fn render_colored_row(&self, label: &str, value: &str, sgr: &str) -> String {
+        self.render_row_styled(label, value, Some(sgr))
+    }
+
+    fn render_row_styled(&self, label: &str, value: &str, sgr: Option<&str>) -> String {
         let label_display = self.fit_string(label, self.label_width);
         let value_display = self.fit_string(value, self.data_width);
 
@@ -203,7 +214,15 @@ impl TableRenderer {
         line.push(' ');
         line.push(self.chars.vertical);
         line.push(' ');
-        line.push_str(&value_display);
+        match sgr {
+            Some(sgr) => {
+                // Keep trailing padding outside the colour span.
+                let text = value_display.trim_end_matches(' ');
+                line.push_str(&format!("\x1b[{}m{}\x1b[0m", sgr, text));
+                line.push_str(&value_display[text.len()..]);
+            }
+            None => line.push_str(&value_display),
+        }
         line.push(' ');
         line.push(self.chars.vertical);
         line.push('\n');
@@ -300,4 +319,16 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn colored_rows_wrap_only_the_fitted_value() {
+        let r = TableRenderer::new(12, 32, BoxChars::unicode());
+        let plain = r.render_row("DISK USAGE", "████░ 91.2% !!");
+        let colored = r.render_colored_row("DISK USAGE", "████░ 91.2% !!", "31");
+        assert_eq!(
+            colored.replace("\x1b[31m", "").replace("\x1b[0m", ""),
+            plain
+        );
+        assert!(colored.contains("\x1b[31m████░ 91.2% !!\x1b[0m "));
+    }
 }
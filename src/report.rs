@@ -6,8 +6,14 @@ use std::fs::OpenOptions;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 
-use crate::collectors::{CollectMode, SystemInfo};
-use crate::config::{Config, OutputFormat, MAX_DATA_WIDTH};
+use crate::collectors::containers::ContainerInfo;
+use crate::collectors::process::{self, ProcessInfo};
+use crate::collectors::services::{format_boot_ms, BootTiming};
+use crate::collectors::session::LoginSession;
+use crate::collectors::time::{format_offset_ms, TimeInfo};
+use crate::collectors::{CollectMode, CollectionError, Section, SystemInfo, PLATFORM_FIELDS};
+use crate::config::{Config, OutputFormat, SaveFormat, ThresholdLevel, Thresholds, MAX_DATA_WIDTH};
+use crate::export;
 use crate::render::bar::render_bar;
 use crate::render::table::TableRenderer;
 
@@ -17,9 +23,24 @@ pub const SCHEMA_VERSION: u32 = 1;
 
 /// Generate the complete system report
 pub fn generate(info: &SystemInfo, config: &Config) -> String {
+    // The config's section toggles also hide sections that were collected.
+    let hidden;
+    let info = if info.sections.iter().all(|s| config.sections.contains(s)) {
+        info
+    } else {
+        let mut shown = info.clone();
+        shown.sections.retain(|s| config.sections.contains(s));
+        hidden = shown;
+        &hidden
+    };
+
     match config.format {
         OutputFormat::Table => generate_table(info, config),
         OutputFormat::Json => generate_json(info),
+        OutputFormat::Html => generate_html(info, config),
+        OutputFormat::Env => export::env(&json_value(info)),
+        OutputFormat::Csv => export::csv(&json_value(info)),
+        OutputFormat::Yaml => export::yaml(&json_value(info)),
     }
 }
 
@@ -43,186 +64,364 @@ fn generate_table(info: &SystemInfo, config: &Config) -> String {
     output.push_str(&renderer.render_centered(config.subtitle()));
     output.push_str(&renderer.render_top_divider());
 
+    // Sections render as blocks joined by dividers, so sections left out of
+    // the collection (`--fields`) leave no empty block behind.
+    let mut blocks: Vec<String> = Vec::new();
+    let mut block = String::new();
+
     // OS Section
-    let os_display = format!("{} {}", info.os_name, info.os_version);
-    output.push_str(&renderer.render_row("OS", &os_display));
-    if let Some(ref edition) = info.os_edition {
-        output.push_str(&renderer.render_row("EDITION", edition));
-    }
-    if let Some(ref codename) = info.os_codename {
-        output.push_str(&renderer.render_row("CODENAME", codename));
-    }
-    if let Some(ref build) = info.os_build {
-        output.push_str(&renderer.render_row("BUILD", build));
-    }
-    output.push_str(&renderer.render_row("KERNEL", &info.kernel));
-    output.push_str(&renderer.render_row("ARCH", &info.architecture));
-    if let Some(ref model) = info.machine_model {
-        output.push_str(&renderer.render_row("MODEL", model));
-    }
-    if let Some(ref board) = info.motherboard {
-        output.push_str(&renderer.render_row("BOARD", board));
-    }
-    if let Some(ref bios) = info.bios {
-        output.push_str(&renderer.render_row("BIOS", bios));
-    }
-    if let Some(ref boot_mode) = info.boot_mode {
-        output.push_str(&renderer.render_row("BOOT MODE", boot_mode));
-    }
-    if let Some(ref desktop) = info.desktop_environment {
-        output.push_str(&renderer.render_row("DESKTOP", desktop));
-    }
-    if let Some(ref server) = info.display_server {
-        output.push_str(&renderer.render_row("SESSION", server));
+    if info.includes(Section::Os) {
+        let os_display = format!("{} {}", info.os_name, info.os_version);
+        block.push_str(&renderer.render_row("OS", &os_display));
+        if let Some(ref edition) = info.os_edition {
+            block.push_str(&renderer.render_row("EDITION", edition));
+        }
+        if let Some(ref codename) = info.os_codename {
+            block.push_str(&renderer.render_row("CODENAME", codename));
+        }
+        if let Some(ref build) = info.os_build {
+            block.push_str(&renderer.render_row("BUILD", build));
+        }
+        block.push_str(&renderer.render_row("KERNEL", &info.kernel));
+        block.push_str(&renderer.render_row("ARCH", &info.architecture));
+        if let Some(ref model) = info.machine_model {
+            block.push_str(&renderer.render_row("MODEL", model));
+        }
     }
-    if let Some(ref resolution) = info.display_resolution {
-        output.push_str(&renderer.render_row("DISPLAY", resolution));
+    if info.includes(Section::System) {
+        if let Some(ref board) = info.motherboard {
+            block.push_str(&renderer.render_row("BOARD", board));
+        }
+        if let Some(ref bios) = info.bios {
+            block.push_str(&renderer.render_row("BIOS", bios));
+        }
+        if let Some(ref boot_mode) = info.boot_mode {
+            block.push_str(&renderer.render_row(
+                "BOOT MODE",
+                &boot_mode_with_secure_boot(boot_mode, info.secure_boot),
+            ));
+        }
+        if let Some(ref desktop) = info.desktop_environment {
+            block.push_str(&renderer.render_row("DESKTOP", desktop));
+        }
+        if let Some(ref server) = info.display_server {
+            block.push_str(&renderer.render_row("SESSION", server));
+        }
+        if let Some(ref resolution) = info.display_resolution {
+            block.push_str(&renderer.render_row("DISPLAY", resolution));
+        }
     }
-    output.push_str(&renderer.render_middle_divider());
+    blocks.push(std::mem::take(&mut block));
 
     // Network Section
-    output.push_str(&renderer.render_row("HOSTNAME", &info.hostname));
-    if let Some(ref ip) = info.machine_ip {
-        output.push_str(&renderer.render_row("DEFAULT IP", ip));
-    }
-    output.push_str(&renderer.render_row(
-        "SSH CLIENT",
-        info.client_ip.as_deref().unwrap_or("Not an SSH session"),
-    ));
+    if info.includes(Section::Network) {
+        block.push_str(&renderer.render_row("HOSTNAME", &info.hostname));
+        if let Some(ref ip) = info.machine_ip {
+            block.push_str(&renderer.render_row("DEFAULT IP", ip));
+        }
+        block.push_str(&renderer.render_row(
+            "SSH CLIENT",
+            info.client_ip.as_deref().unwrap_or("Not an SSH session"),
+        ));
 
-    // DNS servers (up to 5)
-    for (i, dns) in info.dns_servers.iter().take(5).enumerate() {
-        let label = format!("DNS  IP {}", i + 1);
-        output.push_str(&renderer.render_row(&label, dns));
+        // DNS servers (up to 5)
+        for (i, dns) in info.dns_servers.iter().take(5).enumerate() {
+            let label = format!("DNS  IP {}", i + 1);
+            block.push_str(&renderer.render_row(&label, dns));
+        }
     }
 
-    output.push_str(&renderer.render_row("USER", &info.username));
-    output.push_str(&renderer.render_middle_divider());
+    if info.includes(Section::Session) {
+        block.push_str(&renderer.render_row("USER", &info.username));
+    }
+    blocks.push(std::mem::take(&mut block));
 
     // CPU Section
-    output.push_str(&renderer.render_row("PROCESSOR", &info.processor));
-    output.push_str(&renderer.render_row("CORES", &info.cores_str()));
-    if let Some(ref topology) = info.cpu_core_topology {
-        output.push_str(&renderer.render_row("CORE TYPE", topology));
-    }
+    if info.includes(Section::Cpu) {
+        block.push_str(&renderer.render_row("PROCESSOR", &info.processor));
+        block.push_str(&renderer.render_row("CORES", &info.cores_str()));
+        if let Some(ref topology) = info.cpu_core_topology {
+            block.push_str(&renderer.render_row("CORE TYPE", topology));
+        }
 
-    // GPU display: if ≤3 GPUs, show each on own row; if >3, show as compact list
-    if !info.gpus.is_empty() {
-        if info.gpus.len() <= 3 {
-            for (i, gpu) in info.gpus.iter().enumerate() {
-                let label = if info.gpus.len() == 1 {
-                    "GPU".to_string()
-                } else {
-                    format!("GPU {}", i + 1)
-                };
-                output.push_str(&renderer.render_row(&label, gpu));
+        // GPU display: if ≤3 GPUs, show each on own row; if >3, show as compact list
+        if !info.gpus.is_empty() {
+            if info.gpus.len() <= 3 {
+                for (i, gpu) in info.gpus.iter().enumerate() {
+                    let label = if info.gpus.len() == 1 {
+                        "GPU".to_string()
+                    } else {
+                        format!("GPU {}", i + 1)
+                    };
+                    block.push_str(&renderer.render_row(&label, gpu));
+                }
+            } else {
+                // Compact comma-separated list for >3 GPUs
+                let gpu_list = info.gpus.join(", ");
+                block.push_str(&renderer.render_row("GPUs", &gpu_list));
             }
-        } else {
-            // Compact comma-separated list for >3 GPUs
-            let gpu_list = info.gpus.join(", ");
-            output.push_str(&renderer.render_row("GPUs", &gpu_list));
         }
-    }
 
-    if let Some(ref hypervisor) = info.hypervisor {
-        output.push_str(&renderer.render_row("HYPERVISOR", hypervisor));
-    }
-    if info.cpu_freq_ghz > 0.0 && info.cpu_freq_ghz.is_finite() {
-        let label = if info.cpu_frequency_kind.as_deref() == Some("maximum") {
-            "MAX FREQ"
-        } else {
-            "REPORTED FREQ"
-        };
-        output.push_str(&renderer.render_row(label, &info.freq_str()));
-    }
-    if let Some(usage) = info.cpu_usage_percent {
-        output.push_str(&renderer.render_row(
-            "CPU USAGE",
-            &render_percent_bar(usage, data_width, bar_filled, bar_empty),
-        ));
-    }
+        if let Some(ref hypervisor) = info.hypervisor {
+            block.push_str(&renderer.render_row("HYPERVISOR", hypervisor));
+        }
+        if info.cpu_freq_ghz > 0.0 && info.cpu_freq_ghz.is_finite() {
+            let label = if info.cpu_frequency_kind.as_deref() == Some("maximum") {
+                "MAX FREQ"
+            } else {
+                "REPORTED FREQ"
+            };
+            block.push_str(&renderer.render_row(label, &info.freq_str()));
+        }
+        if let Some(usage) = info.cpu_usage_percent {
+            block.push_str(&renderer.render_row(
+                "CPU USAGE",
+                &render_percent_bar(usage, data_width, bar_filled, bar_empty, ""),
+            ));
+        }
 
-    // Load averages as bar graphs (only shown when available)
-    if let (Some(l1), Some(l5), Some(l15)) = (info.load_1m, info.load_5m, info.load_15m) {
-        output.push_str(&renderer.render_row(
-            "LOAD/CPU 1m",
-            &render_percent_bar(l1, data_width, bar_filled, bar_empty),
-        ));
-        output.push_str(&renderer.render_row(
-            "LOAD/CPU 5m",
-            &render_percent_bar(l5, data_width, bar_filled, bar_empty),
-        ));
-        output.push_str(&renderer.render_row(
-            "LOAD/CPU 15m",
-            &render_percent_bar(l15, data_width, bar_filled, bar_empty),
-        ));
+        // Load averages as bar graphs (only shown when available)
+        if let (Some(l1), Some(l5), Some(l15)) = (info.load_1m, info.load_5m, info.load_15m) {
+            for (label, load) in [
+                ("LOAD/CPU 1m", l1),
+                ("LOAD/CPU 5m", l5),
+                ("LOAD/CPU 15m", l15),
+            ] {
+                let level = config.thresholds.load.level(load);
+                let bar = render_percent_bar(
+                    load,
+                    data_width,
+                    bar_filled,
+                    bar_empty,
+                    threshold_marker(level, config),
+                );
+                block.push_str(&render_level_row(&renderer, label, &bar, level, config));
+            }
+        }
     }
-    output.push_str(&renderer.render_middle_divider());
+    blocks.push(std::mem::take(&mut block));
 
     // Disk Section
-    output.push_str(&renderer.render_row("VOLUME", &info.disk_usage_str()));
-    let disk_bar = render_percent_bar(info.disk_percent, data_width, bar_filled, bar_empty);
-    output.push_str(&renderer.render_row("DISK USAGE", &disk_bar));
+    if info.includes(Section::Disk) {
+        block.push_str(&renderer.render_row("VOLUME", &info.disk_usage_str()));
+        let disk_level = config.thresholds.disk.level(info.disk_percent);
+        let disk_bar = render_percent_bar(
+            info.disk_percent,
+            data_width,
+            bar_filled,
+            bar_empty,
+            threshold_marker(disk_level, config),
+        );
+        block.push_str(&render_level_row(
+            &renderer,
+            "DISK USAGE",
+            &disk_bar,
+            disk_level,
+            config,
+        ));
 
-    // ZFS health if available
-    if let Some(ref zfs_health) = info.zfs_health {
-        output.push_str(&renderer.render_row("ZFS HEALTH", zfs_health));
+        // ZFS health if available
+        if let Some(ref zfs_health) = info.zfs_health {
+            block.push_str(&renderer.render_row("ZFS HEALTH", zfs_health));
+        }
     }
-
-    output.push_str(&renderer.render_middle_divider());
+    blocks.push(std::mem::take(&mut block));
 
     // Memory Section
-    output.push_str(&renderer.render_row("MEMORY", &info.memory_usage_str()));
-    output.push_str(&renderer.render_row(
-        "AVAILABLE",
-        &format!("{} GiB", SystemInfo::format_gib(info.mem_available_bytes)),
-    ));
-    if info.swap_total_bytes > 0 {
-        output.push_str(&renderer.render_row("SWAP", &info.swap_usage_str()));
+    if info.includes(Section::Memory) {
+        block.push_str(&renderer.render_row("MEMORY", &info.memory_usage_str()));
+        block.push_str(&renderer.render_row(
+            "AVAILABLE",
+            &format!("{} GiB", SystemInfo::format_gib(info.mem_available_bytes)),
+        ));
+        if info.swap_total_bytes > 0 {
+            let level = config.thresholds.swap.level(info.swap_percent);
+            let swap = format!(
+                "{}{}",
+                info.swap_usage_str(),
+                threshold_marker(level, config)
+            );
+            block.push_str(&render_level_row(&renderer, "SWAP", &swap, level, config));
+        }
+        if let Some(ref ram_slots) = info.ram_slots {
+            block.push_str(&renderer.render_row("RAM SLOTS", ram_slots));
+        }
+        let mem_level = config.thresholds.memory.level(info.mem_percent);
+        let mem_bar = render_percent_bar(
+            info.mem_percent,
+            data_width,
+            bar_filled,
+            bar_empty,
+            threshold_marker(mem_level, config),
+        );
+        block.push_str(&render_level_row(
+            &renderer, "USAGE", &mem_bar, mem_level, config,
+        ));
     }
-    if let Some(ref ram_slots) = info.ram_slots {
-        output.push_str(&renderer.render_row("RAM SLOTS", ram_slots));
+    blocks.push(std::mem::take(&mut block));
+
+    // Top-process snapshot (only when requested with --top)
+    if info.includes(Section::Cpu) && !info.top_processes.is_empty() {
+        for proc_info in process::by_cpu(&info.top_processes) {
+            let label = format!("TOP CPU {}", proc_info.cpu_rank.unwrap_or_default());
+            let value = format!(
+                "{:.1}% {}",
+                proc_info.cpu_percent,
+                process_identity(proc_info)
+            );
+            block.push_str(&renderer.render_row(&label, &value));
+        }
+        for proc_info in process::by_memory(&info.top_processes) {
+            let label = format!("TOP MEM {}", proc_info.memory_rank.unwrap_or_default());
+            let value = format!(
+                "{} {}",
+                format_rss(proc_info.rss_bytes),
+                process_identity(proc_info)
+            );
+            block.push_str(&renderer.render_row(&label, &value));
+        }
+        blocks.push(std::mem::take(&mut block));
     }
-    let mem_bar = render_percent_bar(info.mem_percent, data_width, bar_filled, bar_empty);
-    output.push_str(&renderer.render_row("USAGE", &mem_bar));
-    output.push_str(&renderer.render_middle_divider());
 
-    // Session Section
-    if let Some(ref last_login) = info.last_login {
-        output.push_str(&renderer.render_row("LAST LOGIN", last_login));
-        if let Some(ref ip) = info.last_login_ip {
-            output.push_str(&renderer.render_row("LOGIN ORIGIN", ip));
+    // Services Section (systemd hosts, full mode)
+    if let Some(services) = info
+        .services
+        .as_ref()
+        .filter(|_| info.includes(Section::Services))
+    {
+        let state = services.system_state.as_deref().unwrap_or("unknown");
+        let summary = match services.failed_units.len() {
+            0 => state.to_string(),
+            1 => format!("{} (1 failed unit)", state),
+            n => format!("{} ({} failed units)", state, n),
+        };
+        block.push_str(&renderer.render_row("SYSTEMD", &summary));
+        for (i, unit) in services.failed_units.iter().take(5).enumerate() {
+            block.push_str(&renderer.render_row(&format!("FAILED {}", i + 1), unit));
+        }
+        if services.failed_units.len() > 5 {
+            block.push_str(&renderer.render_row(
+                "FAILED",
+                &format!("... and {} more", services.failed_units.len() - 5),
+            ));
         }
+        if let Some(ref timing) = services.boot_timing {
+            for (label, ms) in boot_stages(timing) {
+                block.push_str(&renderer.render_row(label, &format_boot_ms(ms)));
+            }
+        }
+        if let Some(ref last_boot) = services.last_boot {
+            block.push_str(&renderer.render_row("LAST BOOT", last_boot));
+        }
+        blocks.push(std::mem::take(&mut block));
     }
-    output.push_str(&renderer.render_row("UPTIME", &info.uptime_formatted()));
 
-    // Shell and Terminal (only show if available)
-    if let Some(ref shell) = info.shell {
-        output.push_str(&renderer.render_row("LOGIN SHELL", shell));
-    }
-    if let Some(ref terminal) = info.terminal {
-        output.push_str(&renderer.render_row("TERMINAL", terminal));
-    }
-    if let Some(ref locale) = info.locale {
-        output.push_str(&renderer.render_row("LOCALE", locale));
+    // Containers Section (only when a local runtime socket answered)
+    if let Some(containers) = info
+        .containers
+        .as_ref()
+        .filter(|_| info.includes(Section::Containers))
+    {
+        block.push_str(&renderer.render_row("CONTAINERS", &containers_summary(containers)));
+        let runtime = match containers.version {
+            Some(ref version) => format!("{} {}", containers.runtime, version),
+            None => containers.runtime.clone(),
+        };
+        block.push_str(&renderer.render_row("RUNTIME", &runtime));
+        if let Some(bytes) = containers.image_bytes {
+            block.push_str(
+                &renderer.render_row("IMAGES", &format!("{} GiB", SystemInfo::format_gib(bytes))),
+            );
+        }
+        blocks.push(std::mem::take(&mut block));
     }
-    // Battery only shown if present (laptops)
-    if let Some(ref battery) = info.battery {
-        if let Some((status, health)) = battery.split_once("; ") {
-            output.push_str(&renderer.render_row("BATTERY", status));
-            output.push_str(&renderer.render_row("BAT HEALTH", health));
-        } else {
-            output.push_str(&renderer.render_row("BATTERY", battery));
+
+    // Time Section
+    if let Some(time) = info.time.as_ref().filter(|_| info.includes(Section::Time)) {
+        if let Some(ref timezone) = time.timezone {
+            block.push_str(&renderer.render_row("TIMEZONE", timezone));
+        }
+        if let Some(sync) = ntp_sync_summary(time) {
+            block.push_str(&renderer.render_row("NTP SYNC", &sync));
+        }
+        if let Some(ref source) = time.ntp_source {
+            block.push_str(&renderer.render_row("TIME SOURCE", source));
+        }
+        if let Some(offset) = time.offset_ms {
+            block.push_str(&renderer.render_row("CLOCK OFFSET", &format_offset_ms(offset)));
+        }
+        if time.rtc_in_local_time == Some(true) {
+            block.push_str(&renderer.render_row("RTC", "local time (should be UTC)"));
         }
+        blocks.push(std::mem::take(&mut block));
     }
-    // Encryption status (BitLocker / FileVault / LUKS) is shown only when a
-    // collector can establish it. Absence is unknown, not "unencrypted".
-    if let Some(ref enc) = info.encryption {
-        output.push_str(&renderer.render_row("ENCRYPTION", enc));
+
+    // Session Section
+    if info.includes(Section::Session) {
+        if let Some(ref last_login) = info.last_login {
+            block.push_str(&renderer.render_row("LAST LOGIN", last_login));
+            if let Some(ref ip) = info.last_login_ip {
+                block.push_str(&renderer.render_row("LOGIN ORIGIN", ip));
+            }
+        }
+        if let Some(ref sessions) = info.active_sessions {
+            block.push_str(&renderer.render_row("USERS", &sessions_summary(sessions)));
+        }
+        block.push_str(&renderer.render_row("UPTIME", &info.uptime_formatted()));
+
+        // Shell and Terminal (only show if available)
+        if let Some(ref shell) = info.shell {
+            block.push_str(&renderer.render_row("LOGIN SHELL", shell));
+        }
+        if let Some(ref terminal) = info.terminal {
+            block.push_str(&renderer.render_row("TERMINAL", terminal));
+        }
+        if let Some(ref locale) = info.locale {
+            block.push_str(&renderer.render_row("LOCALE", locale));
+        }
+        // Battery only shown if present (laptops)
+        if let Some(ref battery) = info.battery {
+            if let Some((status, health)) = battery.split_once("; ") {
+                block.push_str(&renderer.render_row("BATTERY", status));
+                match crate::check::battery_health_percent(battery) {
+                    Some(percent) => {
+                        let level = config.thresholds.battery.level(percent);
+                        let health = format!("{}{}", health, threshold_marker(level, config));
+                        block.push_str(&render_level_row(
+                            &renderer,
+                            "BAT HEALTH",
+                            &health,
+                            level,
+                            config,
+                        ));
+                    }
+                    None => block.push_str(&renderer.render_row("BAT HEALTH", health)),
+                }
+            } else {
+                block.push_str(&renderer.render_row("BATTERY", battery));
+            }
+        }
+        // Encryption status (BitLocker / FileVault / LUKS) is shown only when a
+        // collector can establish it. Absence is unknown, not "unencrypted".
+        if let Some(ref enc) = info.encryption {
+            block.push_str(&renderer.render_row("ENCRYPTION", enc));
+        }
+        // Only a positive signal is worth a row; "no restart needed" is the norm.
+        if info.pending_reboot == Some(true) {
+            block.push_str(&renderer.render_row("REBOOT", "required to finish updates"));
+        }
     }
 
+    blocks.push(block);
+    blocks.retain(|block| !block.is_empty());
+    output.push_str(&blocks.join(&renderer.render_middle_divider()));
+
     // Simplified footer (single line, no bottom_divider)
     output.push_str(&renderer.render_footer());
+    output.push_str(&render_collection_errors(
+        &info.collection_errors,
+        config.use_colors,
+    ));
 
     // Elevation-tier footer hint: only shown when running unelevated on a platform
     // where sudo/admin would unlock additional data, in full mode, and not opted-out.
@@ -239,13 +438,177 @@ fn generate_table(info: &SystemInfo, config: &Config) -> String {
     output
 }
 
-fn render_percent_bar(percent: f64, width: usize, filled: char, empty: char) -> String {
+/// A bar with its percentage, plus a threshold `marker` that the bar
+/// shrinks to make room for.
+fn render_percent_bar(
+    percent: f64,
+    width: usize,
+    filled: char,
+    empty: char,
+    marker: &str,
+) -> String {
     let value = if percent.is_finite() { percent } else { 0.0 };
-    let suffix = format!(" {:.1}%", value);
+    let suffix = format!(" {:.1}%{}", value, marker);
     let bar_width = width.saturating_sub(suffix.len()).max(1);
     format!("{}{}", render_bar(value, bar_width, filled, empty), suffix)
 }
 
+/// `UEFI, Secure Boot on` when the Secure Boot state is known for a UEFI boot.
+fn boot_mode_with_secure_boot(boot_mode: &str, secure_boot: Option<bool>) -> String {
+    match secure_boot {
+        Some(on) if boot_mode.contains("UEFI") => format!(
+            "{}, Secure Boot {}",
+            boot_mode,
+            if on { "on" } else { "off" }
+        ),
+        _ => boot_mode.to_string(),
+    }
+}
+
+/// Threshold levels are coloured green/yellow/red in Unicode colour mode.
+/// ASCII and `--no-color` output shows a plain marker instead, so a value
+/// past its limit still stands out without escape codes.
+fn uses_level_colors(config: &Config) -> bool {
+    config.use_colors && config.use_unicode
+}
+
+/// ` !` past the warning limit, ` !!` past the critical one; empty when the
+/// level is shown by colour instead.
+fn threshold_marker(level: ThresholdLevel, config: &Config) -> &'static str {
+    match level {
+        _ if uses_level_colors(config) => "",
+        ThresholdLevel::Warning => " !",
+        ThresholdLevel::Critical => " !!",
+        _ => "",
+    }
+}
+
+fn render_level_row(
+    renderer: &TableRenderer,
+    label: &str,
+    value: &str,
+    level: ThresholdLevel,
+    config: &Config,
+) -> String {
+    if !uses_level_colors(config) {
+        return renderer.render_row(label, value);
+    }
+    let sgr = match level {
+        ThresholdLevel::Critical => "31",
+        ThresholdLevel::Warning => "33",
+        _ => "32",
+    };
+    renderer.render_colored_row(label, value, sgr)
+}
+
+/// `yes (chronyd)` / `no` sync state, when known.
+fn ntp_sync_summary(time: &TimeInfo) -> Option<String> {
+    let state = if time.ntp_synchronized? { "yes" } else { "no" };
+    Some(match time.sync_service {
+        Some(ref service) => format!("{} ({})", state, service),
+        None => state.to_string(),
+    })
+}
+
+/// `N users, N via SSH` summary. Like `uptime`, every session counts as a
+/// user, so one person with three terminals reads as three users.
+fn sessions_summary(sessions: &[LoginSession]) -> String {
+    let count = sessions.len();
+    let remote = sessions
+        .iter()
+        .filter(|session| session.is_remote())
+        .count();
+    format!(
+        "{} user{}, {} via SSH",
+        count,
+        if count == 1 { "" } else { "s" },
+        remote
+    )
+}
+
+/// Local `YYYY-MM-DD HH:MM` rendering of a utmp login timestamp.
+fn format_login_time(unix_seconds: i64) -> String {
+    chrono::DateTime::from_timestamp(unix_seconds, 0)
+        .map(|utc| {
+            utc.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Terminal idle time in the compact style of `w` (`42s`, `5m`, `3h12m`, `2d`).
+fn format_idle(seconds: u64) -> String {
+    match seconds {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3_600 => format!("{}m", s / 60),
+        s if s < 86_400 => format!("{}h{:02}m", s / 3_600, (s % 3_600) / 60),
+        s => format!("{}d", s / 86_400),
+    }
+}
+
+/// `N running, N stopped, N paused` container counts.
+fn containers_summary(containers: &ContainerInfo) -> String {
+    format!(
+        "{} running, {} stopped, {} paused",
+        containers.running, containers.stopped, containers.paused
+    )
+}
+
+/// Reported boot stages in boot order, with their table labels.
+fn boot_stages(timing: &BootTiming) -> Vec<(&'static str, u64)> {
+    [
+        ("BOOT FW", timing.firmware_ms),
+        ("BOOT LOADER", timing.loader_ms),
+        ("BOOT KERNEL", timing.kernel_ms),
+        ("BOOT INITRD", timing.initrd_ms),
+        ("BOOT USER", timing.userspace_ms),
+        ("BOOT TOTAL", timing.total_ms),
+    ]
+    .into_iter()
+    .filter_map(|(label, ms)| ms.map(|ms| (label, ms)))
+    .collect()
+}
+
+/// Compact `PID command (user)` identity for a fixed-width process row.
+fn process_identity(proc_info: &ProcessInfo) -> String {
+    match proc_info.user {
+        Some(ref user) => format!("{} {} ({})", proc_info.pid, proc_info.command, user),
+        None => format!("{} {}", proc_info.pid, proc_info.command),
+    }
+}
+
+/// Format a resident set size in binary units, matching the memory rows.
+fn format_rss(bytes: u64) -> String {
+    const MIB: f64 = 1024.0 * 1024.0;
+    if bytes as f64 >= 1024.0 * MIB {
+        format!("{} GiB", SystemInfo::format_gib(bytes))
+    } else {
+        format!("{:.0} MiB", bytes as f64 / MIB)
+    }
+}
+
+/// One yellow line per failed collector under the table, so a degraded
+/// report says what is missing and why.
+fn render_collection_errors(errors: &[CollectionError], use_colors: bool) -> String {
+    errors
+        .iter()
+        .map(|error| {
+            let line = format!(
+                "{} collector failed after {} ms: {}",
+                error.collector,
+                error.elapsed.as_millis(),
+                error.message
+            );
+            if use_colors {
+                format!("\x1b[33m{}\x1b[0m\n", line)
+            } else {
+                format!("{}\n", line)
+            }
+        })
+        .collect()
+}
+
 /// Decide whether the elevation-tier footer hint should appear under the table.
 /// Extracted so the gate is unit-testable independently from rendering.
 pub(crate) fn should_render_elevation_footer(
@@ -275,8 +638,30 @@ pub(crate) fn render_elevation_footer(use_colors: bool) -> String {
     }
 }
 
+/// Render the dim one-line notice `--update-notice` prints under the table
+/// when the cached release check found a newer version.
+pub fn render_update_notice(latest: &str, use_colors: bool) -> String {
+    let notice = format!(
+        "TR-300 v{} is available (running v{}): run `tr300 update`",
+        latest,
+        crate::VERSION
+    );
+    if use_colors {
+        format!("\x1b[2m{}\x1b[0m\n", notice)
+    } else {
+        format!("{}\n", notice)
+    }
+}
+
 /// Generate JSON format output
 fn generate_json(info: &SystemInfo) -> String {
+    serde_json::to_string_pretty(&json_value(info))
+        .expect("serializing a serde_json::Value cannot fail")
+}
+
+/// The schema-v1 JSON field tree behind `--json`, shared with commands that
+/// evaluate a report rather than print it.
+pub fn json_value(info: &SystemInfo) -> serde_json::Value {
     fn finite(value: f64) -> Option<f64> {
         value.is_finite().then_some(value)
     }
@@ -287,7 +672,7 @@ fn generate_json(info: &SystemInfo) -> String {
     // Build a typed JSON value tree and let serde_json own all escaping,
     // punctuation, and non-finite-number handling. This preserves schema v1
     // while making additive fields much harder to corrupt accidentally.
-    let value = serde_json::json!({
+    let mut value = serde_json::json!({
         "schema_version": SCHEMA_VERSION,
         "collection_mode": match info.mode {
             CollectMode::Full => "full",
@@ -299,11 +684,14 @@ fn generate_json(info: &SystemInfo) -> String {
             "motherboard": info.motherboard,
             "bios": info.bios,
             "boot_mode": info.boot_mode,
+            "secure_boot": info.secure_boot,
             "desktop_environment": info.desktop_environment,
             "display_server": info.display_server,
             "display_resolution": info.display_resolution,
         },
         "os": {
+            // Collection always runs on the reporting machine.
+            "family": std::env::consts::OS,
             "name": info.os_name,
             "version": info.os_version,
             "edition": info.os_edition,
@@ -379,9 +767,89 @@ fn generate_json(info: &SystemInfo) -> String {
             "locale": info.locale,
             "battery": info.battery,
             "encryption": info.encryption,
-        }
+            "pending_reboot": info.pending_reboot,
+        },
+        // systemd health summary; null on non-systemd hosts and in fast mode.
+        "services": info.services.as_ref().map(|services| serde_json::json!({
+            "system_state": services.system_state,
+            "failed_units": services.failed_units,
+            "last_boot": services.last_boot,
+            "boot_timing": services.boot_timing.as_ref().map(|timing| serde_json::json!({
+                "firmware_ms": timing.firmware_ms,
+                "loader_ms": timing.loader_ms,
+                "kernel_ms": timing.kernel_ms,
+                "initrd_ms": timing.initrd_ms,
+                "userspace_ms": timing.userspace_ms,
+                "total_ms": timing.total_ms,
+            })),
+        })),
+        // Active login sessions; null when utmp is unreadable (non-Linux,
+        // minimal containers). Read in fast mode too: utmp is one small file.
+        "sessions": info.active_sessions.as_ref().map(|sessions| {
+            sessions
+                .iter()
+                .map(|session| serde_json::json!({
+                    "user": session.user,
+                    "tty": session.tty,
+                    "host": session.host,
+                    "remote": session.is_remote(),
+                    "login_unix_seconds": session.login_unix_seconds,
+                    "idle_seconds": session.idle_seconds,
+                }))
+                .collect::<Vec<_>>()
+        }),
+        "time": info.time.as_ref().map(|time| serde_json::json!({
+            "timezone": time.timezone,
+            "ntp_synchronized": time.ntp_synchronized,
+            "sync_service": time.sync_service,
+            "ntp_source": time.ntp_source,
+            "offset_ms": time.offset_ms.and_then(finite),
+            "rtc_in_local_time": time.rtc_in_local_time,
+        })),
+        // Local container runtime inventory; null when no socket answered.
+        "containers": info.containers.as_ref().map(|containers| serde_json::json!({
+            "runtime": containers.runtime,
+            "version": containers.version,
+            "socket": containers.socket,
+            "running": containers.running,
+            "paused": containers.paused,
+            "stopped": containers.stopped,
+            "image_bytes": containers.image_bytes,
+        })),
+        // Top-process snapshot; empty unless requested with --top.
+        // `cpu_percent` is relative to one logical CPU, as in `top`.
+        "processes": info.top_processes.iter().map(|p| serde_json::json!({
+            "pid": p.pid,
+            "user": p.user,
+            "command": p.command,
+            "cpu_percent": finite(p.cpu_percent),
+            "rss_bytes": p.rss_bytes,
+            "cpu_rank": p.cpu_rank,
+            "memory_rank": p.memory_rank,
+        })).collect::<Vec<_>>(),
+        // Collectors that failed; their sections are left out below.
+        "errors": info.collection_errors.iter().map(|e| serde_json::json!({
+            "collector": e.collector,
+            "message": e.message,
+            "elapsed_ms": u64::try_from(e.elapsed.as_millis()).unwrap_or(u64::MAX),
+        })).collect::<Vec<_>>(),
     });
-    serde_json::to_string_pretty(&value).expect("serializing a serde_json::Value cannot fail")
+
+    // Sections that were not collected are left out rather than reported
+    // as empty; report metadata such as `schema_version` always stays.
+    if let Some(map) = value.as_object_mut() {
+        map.retain(|key, _| Section::from_json_key(key).is_none_or(|s| info.includes(s)));
+    }
+    // Platform extras that were never collected are absent, not null or empty.
+    if !info.platform_collected {
+        for field in PLATFORM_FIELDS {
+            let (section, key) = field.split_once('.').expect("section.key path");
+            if let Some(section) = value.get_mut(section).and_then(|v| v.as_object_mut()) {
+                section.remove(key);
+            }
+        }
+    }
+    value
 }
 
 /// Escape special characters for JSON.
@@ -413,6 +881,15 @@ fn escape_markdown_cell(s: &str) -> String {
         .replace('\n', "<br>")
 }
 
+/// Escape text for HTML element content and quoted attribute values.
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
 /// Get the user's Downloads directory (cross-platform).
 ///
 /// Returns `(dir, used_cwd_fallback)`. `used_cwd_fallback` is `true` only when
@@ -433,91 +910,131 @@ fn downloads_dir() -> (PathBuf, bool) {
     )
 }
 
-/// Generate a comprehensive markdown report from system info
-fn generate_markdown(info: &SystemInfo) -> String {
-    let timestamp = chrono::Local::now().to_rfc3339();
-    let version = env!("CARGO_PKG_VERSION");
-    let cell = |s: &str| escape_markdown_cell(s);
+/// One label/value line of a saved report section.
+struct Field {
+    label: String,
+    value: String,
+    /// Percentage drawn as a meter in HTML, with its threshold level when
+    /// the metric has limits (Markdown shows the value only)
+    meter: Option<(f64, Option<ThresholdLevel>)>,
+}
 
-    let mut md = String::new();
+impl Field {
+    fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            meter: None,
+        }
+    }
 
-    md.push_str("# TR-300 Machine Report\n\n");
-    md.push_str(&format!("**Date:** {}\n", timestamp));
-    md.push_str(&format!(
-        "**Hostname:** {}\n",
-        escape_markdown_cell(&info.hostname)
-    ));
-    md.push_str("\n---\n\n");
+    fn with_meter(mut self, percent: f64, level: Option<ThresholdLevel>) -> Self {
+        self.meter = Some((percent, level));
+        self
+    }
+}
+
+/// Content of a saved report section: label/value fields or a multi-column
+/// listing (processes, sessions).
+enum Block {
+    Fields(Vec<Field>),
+    Table {
+        caption: Option<&'static str>,
+        columns: &'static [&'static str],
+        rows: Vec<Vec<String>>,
+    },
+}
+
+/// A headed section of the saved report, shared by the Markdown and HTML
+/// renderers so both list the same facts in the same order.
+struct ReportSection {
+    title: &'static str,
+    blocks: Vec<Block>,
+}
+
+/// Build the saved report's sections. Threshold levels only colour the HTML
+/// meters.
+fn report_sections(info: &SystemInfo, thresholds: &Thresholds) -> Vec<ReportSection> {
+    let mut sections = Vec::new();
+    let section = |title, fields| ReportSection {
+        title,
+        blocks: vec![Block::Fields(fields)],
+    };
 
     // System section
-    md.push_str("## System\n\n");
-    md.push_str("| Field | Value |\n|-------|-------|\n");
-    md.push_str(&format!(
-        "| OS | {} {} |\n",
-        cell(&info.os_name),
-        cell(&info.os_version)
-    ));
-    if let Some(ref edition) = info.os_edition {
-        md.push_str(&format!("| Edition | {} |\n", cell(edition)));
-    }
-    if let Some(ref codename) = info.os_codename {
-        md.push_str(&format!("| Codename | {} |\n", cell(codename)));
-    }
-    if let Some(ref build) = info.os_build {
-        md.push_str(&format!("| Build | {} |\n", cell(build)));
-    }
-    md.push_str(&format!("| Kernel | {} |\n", cell(&info.kernel)));
-    md.push_str(&format!(
-        "| Architecture | {} |\n",
-        cell(&info.architecture)
-    ));
-    if let Some(ref model) = info.machine_model {
-        md.push_str(&format!("| Machine Model | {} |\n", cell(model)));
-    }
-    if let Some(ref motherboard) = info.motherboard {
-        md.push_str(&format!("| Motherboard | {} |\n", cell(motherboard)));
-    }
-    if let Some(ref bios) = info.bios {
-        md.push_str(&format!("| BIOS | {} |\n", cell(bios)));
-    }
-    if let Some(ref boot_mode) = info.boot_mode {
-        md.push_str(&format!("| Boot Mode | {} |\n", cell(boot_mode)));
-    }
-    if let Some(ref desktop) = info.desktop_environment {
-        md.push_str(&format!("| Desktop | {} |\n", cell(desktop)));
-    }
-    if let Some(ref server) = info.display_server {
-        md.push_str(&format!("| Display/Session Server | {} |\n", cell(server)));
+    let mut fields = Vec::new();
+    if info.includes(Section::Os) {
+        fields.push(Field::new(
+            "OS",
+            format!("{} {}", info.os_name, info.os_version),
+        ));
+        if let Some(ref edition) = info.os_edition {
+            fields.push(Field::new("Edition", edition));
+        }
+        if let Some(ref codename) = info.os_codename {
+            fields.push(Field::new("Codename", codename));
+        }
+        if let Some(ref build) = info.os_build {
+            fields.push(Field::new("Build", build));
+        }
+        fields.push(Field::new("Kernel", &info.kernel));
+        fields.push(Field::new("Architecture", &info.architecture));
+        if let Some(ref model) = info.machine_model {
+            fields.push(Field::new("Machine Model", model));
+        }
     }
-    if let Some(ref resolution) = info.display_resolution {
-        md.push_str(&format!("| Display | {} |\n", cell(resolution)));
+    if info.includes(Section::System) {
+        if let Some(ref motherboard) = info.motherboard {
+            fields.push(Field::new("Motherboard", motherboard));
+        }
+        if let Some(ref bios) = info.bios {
+            fields.push(Field::new("BIOS", bios));
+        }
+        if let Some(ref boot_mode) = info.boot_mode {
+            fields.push(Field::new(
+                "Boot Mode",
+                boot_mode_with_secure_boot(boot_mode, info.secure_boot),
+            ));
+        }
+        if let Some(ref desktop) = info.desktop_environment {
+            fields.push(Field::new("Desktop", desktop));
+        }
+        if let Some(ref server) = info.display_server {
+            fields.push(Field::new("Display/Session Server", server));
+        }
+        if let Some(ref resolution) = info.display_resolution {
+            fields.push(Field::new("Display", resolution));
+        }
     }
-    md.push('\n');
+    sections.push(section("System", fields));
 
     // Network section
-    md.push_str("## Network\n\n");
-    md.push_str("| Field | Value |\n|-------|-------|\n");
-    md.push_str(&format!("| Hostname | {} |\n", cell(&info.hostname)));
-    if let Some(ref ip) = info.machine_ip {
-        md.push_str(&format!("| Default-route IP | {} |\n", cell(ip)));
-    }
-    md.push_str(&format!(
-        "| SSH Client IP | {} |\n",
-        cell(info.client_ip.as_deref().unwrap_or("Not an SSH session"))
-    ));
-    for (i, dns) in info.dns_servers.iter().take(5).enumerate() {
-        md.push_str(&format!("| DNS Server {} | {} |\n", i + 1, cell(dns)));
+    let mut fields = Vec::new();
+    if info.includes(Section::Network) {
+        fields.push(Field::new("Hostname", &info.hostname));
+        if let Some(ref ip) = info.machine_ip {
+            fields.push(Field::new("Default-route IP", ip));
+        }
+        fields.push(Field::new(
+            "SSH Client IP",
+            info.client_ip.as_deref().unwrap_or("Not an SSH session"),
+        ));
+        for (i, dns) in info.dns_servers.iter().take(5).enumerate() {
+            fields.push(Field::new(format!("DNS Server {}", i + 1), dns));
+        }
     }
-    md.push_str(&format!("| User | {} |\n", cell(&info.username)));
-    md.push('\n');
+    if info.includes(Section::Session) {
+        fields.push(Field::new("User", &info.username));
+    }
+    sections.push(section("Network", fields));
 
     // CPU section
-    md.push_str("## CPU\n\n");
-    md.push_str("| Field | Value |\n|-------|-------|\n");
-    md.push_str(&format!("| Processor | {} |\n", cell(&info.processor)));
-    md.push_str(&format!("| Cores | {} |\n", info.cores_str()));
+    let mut fields = vec![
+        Field::new("Processor", &info.processor),
+        Field::new("Cores", info.cores_str()),
+    ];
     if let Some(ref topology) = info.cpu_core_topology {
-        md.push_str(&format!("| Core Topology | {} |\n", cell(topology)));
+        fields.push(Field::new("Core Topology", topology));
     }
     for (i, gpu) in info.gpus.iter().enumerate() {
         let label = if info.gpus.len() == 1 {
@@ -525,10 +1042,10 @@ fn generate_markdown(info: &SystemInfo) -> String {
         } else {
             format!("GPU {}", i + 1)
         };
-        md.push_str(&format!("| {} | {} |\n", label, cell(gpu)));
+        fields.push(Field::new(label, gpu));
     }
     if let Some(ref hypervisor) = info.hypervisor {
-        md.push_str(&format!("| Hypervisor | {} |\n", cell(hypervisor)));
+        fields.push(Field::new("Hypervisor", hypervisor));
     }
     if info.cpu_freq_ghz > 0.0 && info.cpu_freq_ghz.is_finite() {
         let label = if info.cpu_frequency_kind.as_deref() == Some("maximum") {
@@ -536,104 +1053,451 @@ fn generate_markdown(info: &SystemInfo) -> String {
         } else {
             "Reported Frequency"
         };
-        md.push_str(&format!("| {} | {} |\n", label, info.freq_str()));
+        fields.push(Field::new(label, info.freq_str()));
     }
     if let Some(usage) = info.cpu_usage_percent {
-        md.push_str(&format!("| CPU Usage | {:.2}% |\n", usage));
+        fields.push(Field::new("CPU Usage", format!("{:.2}%", usage)).with_meter(usage, None));
     }
     if let (Some(l1), Some(l5), Some(l15)) = (info.load_1m, info.load_5m, info.load_15m) {
-        md.push_str(&format!("| Load / CPU 1m | {:.2}% |\n", l1));
-        md.push_str(&format!("| Load / CPU 5m | {:.2}% |\n", l5));
-        md.push_str(&format!("| Load / CPU 15m | {:.2}% |\n", l15));
+        for (label, load) in [
+            ("Load / CPU 1m", l1),
+            ("Load / CPU 5m", l5),
+            ("Load / CPU 15m", l15),
+        ] {
+            fields.push(
+                Field::new(label, format!("{:.2}%", load))
+                    .with_meter(load, Some(thresholds.load.level(load))),
+            );
+        }
     }
     if let (Some(l1), Some(l5), Some(l15)) = (info.raw_load_1m, info.raw_load_5m, info.raw_load_15m)
     {
-        md.push_str(&format!("| Raw Load 1m | {:.2} |\n", l1));
-        md.push_str(&format!("| Raw Load 5m | {:.2} |\n", l5));
-        md.push_str(&format!("| Raw Load 15m | {:.2} |\n", l15));
+        fields.push(Field::new("Raw Load 1m", format!("{:.2}", l1)));
+        fields.push(Field::new("Raw Load 5m", format!("{:.2}", l5)));
+        fields.push(Field::new("Raw Load 15m", format!("{:.2}", l15)));
+    }
+    if info.includes(Section::Cpu) {
+        sections.push(section("CPU", fields));
     }
-    md.push('\n');
 
     // Storage section
-    md.push_str("## Storage\n\n");
-    md.push_str("| Field | Value |\n|-------|-------|\n");
-    md.push_str(&format!("| Volume | {} |\n", info.disk_usage_str()));
+    let mut fields = vec![Field::new("Volume", info.disk_usage_str())];
     if let Some(ref mount) = info.disk_mount_point {
-        md.push_str(&format!("| Mount Point | {} |\n", cell(mount)));
+        fields.push(Field::new("Mount Point", mount));
     }
     if let Some(ref filesystem) = info.disk_filesystem {
-        md.push_str(&format!("| Filesystem | {} |\n", cell(filesystem)));
+        fields.push(Field::new("Filesystem", filesystem));
     }
-    md.push_str(&format!(
-        "| Available | {} GiB |\n",
-        SystemInfo::format_gib(info.disk_available_bytes)
+    fields.push(Field::new(
+        "Available",
+        format!("{} GiB", SystemInfo::format_gib(info.disk_available_bytes)),
     ));
-    md.push_str(&format!("| Disk Usage | {:.2}% |\n", info.disk_percent));
+    fields.push(
+        Field::new("Disk Usage", format!("{:.2}%", info.disk_percent)).with_meter(
+            info.disk_percent,
+            Some(thresholds.disk.level(info.disk_percent)),
+        ),
+    );
     if let Some(ref zfs_health) = info.zfs_health {
-        md.push_str(&format!("| ZFS Health | {} |\n", cell(zfs_health)));
+        fields.push(Field::new("ZFS Health", zfs_health));
+    }
+    if info.includes(Section::Disk) {
+        sections.push(section("Storage", fields));
     }
-    md.push('\n');
 
     // Memory section
-    md.push_str("## Memory\n\n");
-    md.push_str("| Field | Value |\n|-------|-------|\n");
-    md.push_str(&format!("| Memory | {} |\n", info.memory_usage_str()));
-    md.push_str(&format!(
-        "| Available | {} GiB |\n",
-        SystemInfo::format_gib(info.mem_available_bytes)
-    ));
-    md.push_str(&format!(
-        "| Usage Definition | {} |\n",
-        cell(&info.memory_usage_kind)
-    ));
-    md.push_str(&format!(
-        "| Availability Definition | {} |\n",
-        cell(&info.memory_availability_kind)
-    ));
+    let mut fields = vec![
+        Field::new("Memory", info.memory_usage_str()),
+        Field::new(
+            "Available",
+            format!("{} GiB", SystemInfo::format_gib(info.mem_available_bytes)),
+        ),
+        Field::new("Usage Definition", &info.memory_usage_kind),
+        Field::new("Availability Definition", &info.memory_availability_kind),
+    ];
     if info.swap_total_bytes > 0 {
-        md.push_str(&format!("| Swap | {} |\n", info.swap_usage_str()));
+        fields.push(Field::new("Swap", info.swap_usage_str()).with_meter(
+            info.swap_percent,
+            Some(thresholds.swap.level(info.swap_percent)),
+        ));
     }
     if let Some(ref ram_slots) = info.ram_slots {
-        md.push_str(&format!("| RAM Slots | {} |\n", cell(ram_slots)));
+        fields.push(Field::new("RAM Slots", ram_slots));
+    }
+    fields.push(
+        Field::new("Usage", format!("{:.1}%", info.mem_percent)).with_meter(
+            info.mem_percent,
+            Some(thresholds.memory.level(info.mem_percent)),
+        ),
+    );
+    if info.includes(Section::Memory) {
+        sections.push(section("Memory", fields));
+    }
+
+    // Top processes section (only when a snapshot was requested)
+    if info.includes(Section::Cpu) && !info.top_processes.is_empty() {
+        let blocks = [
+            ("By CPU", process::by_cpu(&info.top_processes)),
+            ("By Memory", process::by_memory(&info.top_processes)),
+        ]
+        .into_iter()
+        .map(|(caption, ranked)| Block::Table {
+            caption: Some(caption),
+            columns: &["PID", "User", "Command", "CPU %", "RSS"],
+            rows: ranked
+                .into_iter()
+                .map(|proc_info| {
+                    vec![
+                        proc_info.pid.to_string(),
+                        proc_info.user.as_deref().unwrap_or("-").to_string(),
+                        proc_info.command.clone(),
+                        format!("{:.1}%", proc_info.cpu_percent),
+                        format_rss(proc_info.rss_bytes),
+                    ]
+                })
+                .collect(),
+        })
+        .collect();
+        sections.push(ReportSection {
+            title: "Top Processes",
+            blocks,
+        });
+    }
+
+    // Services section (systemd hosts, full mode)
+    if let Some(services) = info
+        .services
+        .as_ref()
+        .filter(|_| info.includes(Section::Services))
+    {
+        let mut fields = vec![
+            Field::new(
+                "System State",
+                services.system_state.as_deref().unwrap_or("unknown"),
+            ),
+            Field::new("Failed Units", services.failed_units.len().to_string()),
+        ];
+        for unit in &services.failed_units {
+            fields.push(Field::new("Failed Unit", unit));
+        }
+        if let Some(ref timing) = services.boot_timing {
+            for (label, ms) in [
+                ("Boot: Firmware", timing.firmware_ms),
+                ("Boot: Loader", timing.loader_ms),
+                ("Boot: Kernel", timing.kernel_ms),
+                ("Boot: Initrd", timing.initrd_ms),
+                ("Boot: Userspace", timing.userspace_ms),
+                ("Boot: Total", timing.total_ms),
+            ] {
+                if let Some(ms) = ms {
+                    fields.push(Field::new(label, format_boot_ms(ms)));
+                }
+            }
+        }
+        if let Some(ref last_boot) = services.last_boot {
+            fields.push(Field::new("Last Boot", last_boot));
+        }
+        sections.push(section("Services", fields));
+    }
+
+    // Containers section (only when a local runtime socket answered)
+    if let Some(containers) = info
+        .containers
+        .as_ref()
+        .filter(|_| info.includes(Section::Containers))
+    {
+        let mut fields = vec![Field::new("Runtime", &containers.runtime)];
+        if let Some(ref version) = containers.version {
+            fields.push(Field::new("Version", version));
+        }
+        fields.push(Field::new("Socket", &containers.socket));
+        fields.push(Field::new("Containers", containers_summary(containers)));
+        if let Some(bytes) = containers.image_bytes {
+            fields.push(Field::new(
+                "Image Disk Usage",
+                format!("{} GiB", SystemInfo::format_gib(bytes)),
+            ));
+        }
+        sections.push(section("Containers", fields));
+    }
+
+    // Time section
+    if let Some(time) = info.time.as_ref().filter(|_| info.includes(Section::Time)) {
+        let mut fields = Vec::new();
+        if let Some(ref timezone) = time.timezone {
+            fields.push(Field::new("Timezone", timezone));
+        }
+        if let Some(sync) = ntp_sync_summary(time) {
+            fields.push(Field::new("NTP Synchronized", sync));
+        }
+        if let Some(ref source) = time.ntp_source {
+            fields.push(Field::new("Time Source", source));
+        }
+        if let Some(offset) = time.offset_ms {
+            fields.push(Field::new("Clock Offset", format_offset_ms(offset)));
+        }
+        if let Some(local) = time.rtc_in_local_time {
+            fields.push(Field::new(
+                "RTC",
+                if local {
+                    "local time (should be UTC)"
+                } else {
+                    "UTC"
+                },
+            ));
+        }
+        sections.push(section("Time", fields));
     }
-    md.push_str(&format!("| Usage | {:.1}% |\n", info.mem_percent));
-    md.push('\n');
 
     // Session section
-    md.push_str("## Session\n\n");
-    md.push_str("| Field | Value |\n|-------|-------|\n");
+    let mut fields = Vec::new();
     if let Some(ref last_login) = info.last_login {
-        md.push_str(&format!("| Last Login | {} |\n", cell(last_login)));
+        fields.push(Field::new("Last Login", last_login));
         if let Some(ref origin) = info.last_login_ip {
-            md.push_str(&format!("| Login Origin | {} |\n", cell(origin)));
+            fields.push(Field::new("Login Origin", origin));
         }
     }
-    md.push_str(&format!("| Uptime | {} |\n", info.uptime_formatted()));
+    fields.push(Field::new("Uptime", info.uptime_formatted()));
     if let Some(ref shell) = info.shell {
-        md.push_str(&format!("| Login Shell | {} |\n", cell(shell)));
+        fields.push(Field::new("Login Shell", shell));
     }
     if let Some(ref terminal) = info.terminal {
-        md.push_str(&format!("| Terminal | {} |\n", cell(terminal)));
+        fields.push(Field::new("Terminal", terminal));
     }
     if let Some(ref locale) = info.locale {
-        md.push_str(&format!("| Locale | {} |\n", cell(locale)));
+        fields.push(Field::new("Locale", locale));
     }
     if let Some(ref battery) = info.battery {
-        md.push_str(&format!("| Battery | {} |\n", cell(battery)));
+        fields.push(Field::new("Battery", battery));
     }
     if let Some(ref encryption) = info.encryption {
-        md.push_str(&format!("| Encryption | {} |\n", cell(encryption)));
+        fields.push(Field::new("Encryption", encryption));
+    }
+    if info.pending_reboot == Some(true) {
+        fields.push(Field::new("Reboot", "required to finish updates"));
+    }
+    if let Some(ref sessions) = info.active_sessions {
+        fields.push(Field::new("Users", sessions_summary(sessions)));
+    }
+    if info.includes(Section::Session) {
+        sections.push(section("Session", fields));
+    }
+
+    // Active sessions (only when utmp listed at least one)
+    if let Some(sessions) = info.active_sessions.as_ref().filter(|s| !s.is_empty()) {
+        sections.push(ReportSection {
+            title: "Active Sessions",
+            blocks: vec![Block::Table {
+                caption: None,
+                columns: &["User", "TTY", "From", "Login", "Idle"],
+                rows: sessions
+                    .iter()
+                    .map(|session| {
+                        vec![
+                            session.user.clone(),
+                            session.tty.clone(),
+                            session.host.as_deref().unwrap_or("-").to_string(),
+                            format_login_time(session.login_unix_seconds),
+                            session
+                                .idle_seconds
+                                .map(format_idle)
+                                .unwrap_or_else(|| "-".to_string()),
+                        ]
+                    })
+                    .collect(),
+            }],
+        });
     }
 
+    // Sections may be empty when `--fields` left out everything they show.
+    sections.retain(|section| {
+        section.blocks.iter().any(|block| match block {
+            Block::Fields(fields) => !fields.is_empty(),
+            Block::Table { .. } => true,
+        })
+    });
+    sections
+}
+
+/// Generate a comprehensive markdown report from system info
+fn generate_markdown(info: &SystemInfo) -> String {
+    let timestamp = chrono::Local::now().to_rfc3339();
+    let version = env!("CARGO_PKG_VERSION");
+    let cell = |s: &str| escape_markdown_cell(s);
+
+    let mut md = String::new();
+
+    md.push_str("# TR-300 Machine Report\n\n");
+    md.push_str(&format!("**Date:** {}\n", timestamp));
+    md.push_str(&format!("**Hostname:** {}\n", cell(&info.hostname)));
     md.push_str("\n---\n\n");
+
+    for section in report_sections(info, &Thresholds::default()) {
+        md.push_str(&format!("## {}\n\n", section.title));
+        for block in section.blocks {
+            match block {
+                Block::Fields(fields) => {
+                    md.push_str("| Field | Value |\n|-------|-------|\n");
+                    for field in fields {
+                        md.push_str(&format!(
+                            "| {} | {} |\n",
+                            cell(&field.label),
+                            cell(&field.value)
+                        ));
+                    }
+                }
+                Block::Table {
+                    caption,
+                    columns,
+                    rows,
+                } => {
+                    if let Some(caption) = caption {
+                        md.push_str(&format!("### {}\n\n", caption));
+                    }
+                    md.push_str(&format!("| {} |\n", columns.join(" | ")));
+                    let rules: Vec<String> =
+                        columns.iter().map(|c| "-".repeat(c.len() + 2)).collect();
+                    md.push_str(&format!("|{}|\n", rules.join("|")));
+                    for row in rows {
+                        let cells: Vec<String> = row.iter().map(|c| cell(c)).collect();
+                        md.push_str(&format!("| {} |\n", cells.join(" | ")));
+                    }
+                }
+            }
+            md.push('\n');
+        }
+    }
+
+    md.push_str("---\n\n");
     md.push_str(&format!("*Generated by TR-300 v{}*\n", version));
 
     md
 }
 
-/// Outcome of a successful markdown report save.
+/// Inline stylesheet for the HTML report: screen and print layouts, with
+/// threshold colours on the meters.
+const HTML_STYLE: &str = "\
+body{font:14px/1.45 system-ui,-apple-system,\"Segoe UI\",Roboto,sans-serif;color:#1f2328;background:#f6f8fa;margin:0;padding:24px}
+main{max-width:860px;margin:0 auto}
+header{border-bottom:2px solid #1f2328;margin-bottom:16px}
+h1{font-size:22px;margin:0 0 4px}
+.meta{color:#57606a;margin:0 0 12px}
+section{background:#fff;border:1px solid #d0d7de;border-radius:6px;padding:12px 16px;margin:0 0 16px}
+h2{font-size:15px;text-transform:uppercase;letter-spacing:.06em;margin:0 0 8px}
+h3{font-size:13px;margin:12px 0 4px}
+table{width:100%;border-collapse:collapse}
+th,td{text-align:left;vertical-align:top;padding:4px 8px;border-top:1px solid #eaeef2;overflow-wrap:anywhere}
+th[scope=row]{width:30%;color:#57606a;font-weight:600}
+thead th{color:#57606a;border-top:none}
+.meter{display:inline-block;width:160px;height:10px;background:#eaeef2;border-radius:5px;overflow:hidden;vertical-align:middle;margin-right:8px}
+.meter span{display:block;height:100%;background:#57606a}
+.meter.ok span{background:#1a7f37}
+.meter.warning span{background:#bf8700}
+.meter.critical span{background:#cf222e}
+footer{color:#57606a;font-size:12px;text-align:center}
+@media print{
+body{background:#fff;padding:0;font-size:11pt}
+section{border:none;border-top:1px solid #000;border-radius:0;padding:8px 0;break-inside:avoid}
+.meter{border:1px solid #000;-webkit-print-color-adjust:exact;print-color-adjust:exact}
+}
+";
+
+/// Generate a self-contained HTML report: inline CSS, the same sections as
+/// the Markdown report, and percentages drawn as meters coloured by the
+/// configured thresholds.
+fn generate_html(info: &SystemInfo, config: &Config) -> String {
+    let timestamp = chrono::Local::now().to_rfc3339();
+    let version = env!("CARGO_PKG_VERSION");
+    let esc = |s: &str| escape_html(s);
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n");
+    html.push_str(&format!(
+        "<title>TR-300 Machine Report - {}</title>\n",
+        esc(&info.hostname)
+    ));
+    html.push_str(&format!(
+        "<style>\n{}</style>\n</head>\n<body>\n<main>\n",
+        HTML_STYLE
+    ));
+    html.push_str("<header>\n<h1>TR-300 Machine Report</h1>\n");
+    html.push_str(&format!(
+        "<p class=\"meta\"><strong>{}</strong> &middot; {}</p>\n</header>\n",
+        esc(&info.hostname),
+        esc(&timestamp)
+    ));
+
+    for section in report_sections(info, &config.thresholds) {
+        html.push_str(&format!("<section>\n<h2>{}</h2>\n", esc(section.title)));
+        for block in section.blocks {
+            match block {
+                Block::Fields(fields) => {
+                    html.push_str("<table>\n");
+                    for field in fields {
+                        let meter = field.meter.map_or_else(String::new, |(percent, level)| {
+                            let class = match level {
+                                Some(ThresholdLevel::Critical) => " critical",
+                                Some(ThresholdLevel::Warning) => " warning",
+                                Some(_) => " ok",
+                                None => "",
+                            };
+                            let width = if percent.is_finite() {
+                                percent.clamp(0.0, 100.0)
+                            } else {
+                                0.0
+                            };
+                            format!(
+                                "<span class=\"meter{}\"><span style=\"width:{:.1}%\"></span></span>",
+                                class, width
+                            )
+                        });
+                        html.push_str(&format!(
+                            "<tr><th scope=\"row\">{}</th><td>{}{}</td></tr>\n",
+                            esc(&field.label),
+                            meter,
+                            esc(&field.value)
+                        ));
+                    }
+                    html.push_str("</table>\n");
+                }
+                Block::Table {
+                    caption,
+                    columns,
+                    rows,
+                } => {
+                    if let Some(caption) = caption {
+                        html.push_str(&format!("<h3>{}</h3>\n", esc(caption)));
+                    }
+                    html.push_str("<table>\n<thead><tr>");
+                    for column in columns {
+                        html.push_str(&format!("<th scope=\"col\">{}</th>", esc(column)));
+                    }
+                    html.push_str("</tr></thead>\n<tbody>\n");
+                    for row in rows {
+                        html.push_str("<tr>");
+                        for value in row {
+                            html.push_str(&format!("<td>{}</td>", esc(&value)));
+                        }
+                        html.push_str("</tr>\n");
+                    }
+                    html.push_str("</tbody>\n</table>\n");
+                }
+            }
+        }
+        html.push_str("</section>\n");
+    }
+
+    html.push_str(&format!(
+        "<footer>Generated by TR-300 v{}</footer>\n</main>\n</body>\n</html>\n",
+        version
+    ));
+    html
+}
+
+/// Outcome of a successful report save.
 #[non_exhaustive]
-pub struct MarkdownSaveOutcome {
+pub struct SaveOutcome {
     /// Where the report was written.
     pub path: PathBuf,
     /// `true` when no Downloads folder was found and the report was written to
@@ -641,35 +1505,61 @@ pub struct MarkdownSaveOutcome {
     pub used_cwd_fallback: bool,
 }
 
+/// Former name of [`SaveOutcome`], from when only Markdown could be saved.
+pub type MarkdownSaveOutcome = SaveOutcome;
+
 /// Save a markdown report to the Downloads folder (or the current working
 /// directory if no Downloads folder exists).
 ///
 /// Returns the concrete `io::Error` on failure so the caller can tell the user
 /// *why* the save failed (permissions, full disk, missing directory) rather
 /// than swallowing it into a generic warning.
-pub fn save_markdown_report(info: &SystemInfo) -> std::io::Result<MarkdownSaveOutcome> {
+pub fn save_markdown_report(info: &SystemInfo) -> std::io::Result<SaveOutcome> {
+    save_report(info, &Config::default(), SaveFormat::Markdown)
+}
+
+/// Save the report in `format` to the Downloads folder (or the current
+/// working directory), like [`save_markdown_report`]. The HTML form colours
+/// its meters with `config.thresholds`.
+pub fn save_report(
+    info: &SystemInfo,
+    config: &Config,
+    format: SaveFormat,
+) -> std::io::Result<SaveOutcome> {
     let (dir, used_cwd_fallback) = downloads_dir();
     let filename_ts = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
     let filename_stem = format!("tr300-report-{}", filename_ts);
 
-    save_markdown_report_to_dir(info, &dir, used_cwd_fallback, &filename_stem)
+    let (contents, extension) = match format {
+        SaveFormat::Markdown => (generate_markdown(info), "md"),
+        SaveFormat::Html => (generate_html(info, config), "html"),
+    };
+    save_report_to_dir(
+        &contents,
+        extension,
+        &dir,
+        used_cwd_fallback,
+        &filename_stem,
+    )
 }
 
-fn save_markdown_report_to_dir(
-    info: &SystemInfo,
+/// Write `contents` to a new `<stem>.<extension>` in `dir`, adding `-1`,
+/// `-2`, ... on collisions. `create_new` never overwrites an existing file
+/// or follows a symlink planted at the target name.
+fn save_report_to_dir(
+    contents: &str,
+    extension: &str,
     dir: &Path,
     used_cwd_fallback: bool,
     filename_stem: &str,
-) -> std::io::Result<MarkdownSaveOutcome> {
+) -> std::io::Result<SaveOutcome> {
     std::fs::create_dir_all(dir)?;
 
-    let markdown = generate_markdown(info);
-
     for suffix in 0..=999u16 {
         let filename = if suffix == 0 {
-            format!("{}.md", filename_stem)
+            format!("{}.{}", filename_stem, extension)
         } else {
-            format!("{}-{}.md", filename_stem, suffix)
+            format!("{}-{}.{}", filename_stem, suffix, extension)
         };
         let path = dir.join(filename);
 
@@ -680,7 +1570,7 @@ fn save_markdown_report_to_dir(
         };
 
         if let Err(error) = file
-            .write_all(markdown.as_bytes())
+            .write_all(contents.as_bytes())
             .and_then(|_| file.sync_all())
         {
             drop(file);
@@ -688,7 +1578,7 @@ fn save_markdown_report_to_dir(
             return Err(error);
         }
 
-        return Ok(MarkdownSaveOutcome {
+        return Ok(SaveOutcome {
             path,
             used_cwd_fallback,
         });
@@ -701,7 +1591,7 @@ fn save_markdown_report_to_dir(
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
     #[test]
@@ -781,7 +1671,8 @@ mod tests {
         assert_eq!(SCHEMA_VERSION, 1);
     }
 
-    fn fixture_info() -> SystemInfo {
+    /// Fully populated report input, shared with other modules' tests
+    pub(crate) fn fixture_info() -> SystemInfo {
         SystemInfo {
             os_name: "TestOS".to_string(),
             os_version: "1.0".to_string(),
@@ -801,7 +1692,7 @@ mod tests {
             physical_cores: 4,
             sockets: Some(1),
             cpu_core_topology: Some("4P + 4E".to_string()),
-            hypervisor: Some("Bare Metal".to_string()),
+            hypervisor: Some("KVM".to_string()),
             cpu_freq_ghz: 3.2,
             cpu_frequency_kind: Some("maximum".to_string()),
             cpu_usage_percent: Some(12.5),
@@ -812,6 +1703,7 @@ mod tests {
             raw_load_5m: Some(1.6),
             raw_load_15m: Some(2.4),
             gpus: vec!["GPU".to_string()],
+            top_processes: Vec::new(),
             disk_used_bytes: 1,
             disk_total_bytes: 2,
             disk_available_bytes: 1,
@@ -833,6 +1725,7 @@ mod tests {
             ram_slots: Some("2x16GB | DDR5".to_string()),
             last_login: Some("now".to_string()),
             last_login_ip: None,
+            active_sessions: None,
             uptime_seconds: 60,
             session_uptime_seconds: None,
             shell: Some("shell".to_string()),
@@ -840,11 +1733,19 @@ mod tests {
             locale: Some("en-US".to_string()),
             battery: None,
             encryption: Some("Encrypted".to_string()),
+            pending_reboot: None,
             desktop_environment: Some("Desktop".to_string()),
             display_server: Some("Session".to_string()),
             display_resolution: Some("1920x1080".to_string()),
             boot_mode: Some("UEFI".to_string()),
+            secure_boot: Some(true),
+            services: None,
+            containers: None,
+            time: None,
             mode: CollectMode::Full,
+            sections: crate::collectors::Section::ALL.to_vec(),
+            platform_collected: true,
+            collection_errors: Vec::new(),
             is_elevated: true,
             elevation_unlocks_more: false,
         }
@@ -893,6 +1794,384 @@ mod tests {
         assert!(value["memory"]["swap_percent"].is_null());
     }
 
+    fn process(pid: u32, command: &str, cpu: f64, gib: u64, ranks: (usize, usize)) -> ProcessInfo {
+        ProcessInfo {
+            pid,
+            user: (pid != 7).then(|| "svc".to_string()),
+            command: command.to_string(),
+            cpu_percent: cpu,
+            rss_bytes: gib * 1024 * 1024 * 1024,
+            cpu_rank: Some(ranks.0),
+            memory_rank: Some(ranks.1),
+        }
+    }
+
+    /// The fixture plus a `--top` snapshot, a container runtime, and clock
+    /// health: the optional sections every format has to carry.
+    fn optional_sections_info() -> SystemInfo {
+        let mut info = fixture_info();
+        info.top_processes = vec![
+            process(4242, "worker|x", 187.5, 3, (1, 2)),
+            process(7, "db", 12.0, 4, (2, 1)),
+        ];
+        info.containers = Some(ContainerInfo {
+            runtime: "Podman".to_string(),
+            version: Some("4.9.3".to_string()),
+            socket: "/run/user/1000/podman/podman.sock".to_string(),
+            running: 3,
+            paused: 0,
+            stopped: 2,
+            image_bytes: Some(5 * 1024 * 1024 * 1024),
+        });
+        info.time = Some(TimeInfo {
+            timezone: Some("Europe/Berlin".to_string()),
+            ntp_synchronized: Some(true),
+            sync_service: Some("chronyd".to_string()),
+            ntp_source: Some("192.168.0.1".to_string()),
+            offset_ms: Some(-0.123),
+            rtc_in_local_time: Some(true),
+        });
+        info
+    }
+
+    #[test]
+    fn optional_sections_reach_every_output_format() {
+        let info = optional_sections_info();
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        let markdown = generate_markdown(&info);
+        let html = generate_html(&info, &Config::default());
+        let json = json_value(&info);
+
+        // (JSON pointer, JSON value, table text, Markdown text, HTML text)
+        let cases: [(&str, serde_json::Value, &str, &str, &str); 6] = [
+            (
+                "/processes/0/cpu_percent",
+                serde_json::json!(187.5),
+                "187.5% 4242 worker|x (svc)",
+                "| 4242 | svc | worker\\|x | 187.5% | 3.00 GiB |",
+                "<td>worker|x</td>",
+            ),
+            (
+                "/processes/1/user",
+                serde_json::Value::Null,
+                "4.00 GiB 7 db",
+                "| 7 | - | db | 12.0% | 4.00 GiB |",
+                "<td>db</td>",
+            ),
+            (
+                "/containers/running",
+                serde_json::json!(3),
+                "3 running, 2 stopped, 0 paused",
+                "| Image Disk Usage | 5.00 GiB |",
+                "Podman",
+            ),
+            (
+                "/containers/version",
+                serde_json::json!("4.9.3"),
+                "Podman 4.9.3",
+                "| Version | 4.9.3 |",
+                "4.9.3",
+            ),
+            (
+                "/time/offset_ms",
+                serde_json::json!(-0.123),
+                "-0.123 ms",
+                "| Time Source | 192.168.0.1 |",
+                "Europe/Berlin",
+            ),
+            (
+                "/time/rtc_in_local_time",
+                serde_json::json!(true),
+                "local time (should be UTC)",
+                "| RTC | local time (should be UTC) |",
+                "local time (should be UTC)",
+            ),
+        ];
+        for (pointer, value, in_table, in_markdown, in_html) in cases {
+            assert_eq!(json.pointer(pointer), Some(&value), "JSON {pointer}");
+            assert!(table.contains(in_table), "table lacks {in_table:?}");
+            assert!(
+                markdown.contains(in_markdown),
+                "Markdown lacks {in_markdown:?}"
+            );
+            assert!(html.contains(in_html), "HTML lacks {in_html:?}");
+        }
+    }
+
+    #[test]
+    fn optional_sections_keep_their_order_in_every_format() {
+        let info = optional_sections_info();
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        let markdown = generate_markdown(&info);
+
+        let in_order = |text: &str, markers: &[&str]| {
+            let positions: Vec<usize> = markers
+                .iter()
+                .map(|m| text.find(m).unwrap_or_else(|| panic!("missing {m}")))
+                .collect();
+            assert!(positions.windows(2).all(|w| w[0] < w[1]), "{markers:?}");
+        };
+        // Each top list is ranked on its own metric.
+        in_order(
+            &table,
+            &["TOP CPU 1", "187.5% 4242", "TOP CPU 2", "12.0% 7"],
+        );
+        in_order(
+            &table,
+            &["TOP MEM 1", "4.00 GiB 7", "TOP MEM 2", "3.00 GiB 4242"],
+        );
+        in_order(
+            &table,
+            &[
+                "TOP CPU",
+                "CONTAINERS",
+                "TIMEZONE",
+                "NTP SYNC",
+                "CLOCK OFFSET",
+            ],
+        );
+        in_order(&markdown, &["## Top Processes", "## Containers", "## Time"]);
+        in_order(
+            &markdown,
+            &["| Timezone |", "| NTP Synchronized |", "| Clock Offset |"],
+        );
+    }
+
+    #[test]
+    fn missing_optional_values_are_null_or_left_out() {
+        let mut info = optional_sections_info();
+        let containers = info.containers.as_mut().unwrap();
+        containers.version = None;
+        containers.image_bytes = None;
+        info.time = Some(TimeInfo {
+            timezone: Some("UTC".to_string()),
+            offset_ms: Some(f64::NAN),
+            ..TimeInfo::default()
+        });
+
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        assert!(table.contains("Podman ") && !table.contains("IMAGES"));
+        assert!(!table.contains("NTP SYNC") && !table.contains("RTC"));
+        let json = json_value(&info);
+        assert!(json["containers"]["version"].is_null());
+        assert!(json["containers"]["image_bytes"].is_null());
+        assert!(json["time"]["offset_ms"].is_null(), "NaN is not valid JSON");
+        assert!(json["time"]["ntp_synchronized"].is_null());
+
+        // A section with no data is null (or an empty list) and not rendered.
+        info.top_processes.clear();
+        info.containers = None;
+        info.time = None;
+        let json = json_value(&info);
+        assert_eq!(json["processes"], serde_json::json!([]));
+        assert!(json["containers"].is_null() && json["time"].is_null());
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        let markdown = generate_markdown(&info);
+        let html = generate_html(&info, &Config::default());
+        for (label, heading) in [
+            ("TOP CPU", "Top Processes"),
+            ("CONTAINERS", "Containers"),
+            ("TIMEZONE", "Time"),
+        ] {
+            assert!(!table.contains(label), "table shows {label}");
+            assert!(!markdown.contains(&format!("## {heading}\n")), "{heading}");
+            assert!(!html.contains(&format!(">{heading}</h2>")), "{heading}");
+        }
+    }
+
+    #[test]
+    fn long_optional_values_are_truncated_in_the_table_only() {
+        let mut info = optional_sections_info();
+        let command = "/usr/lib/jvm/bin/java -Xmx8g -jar /srv/app/service.jar";
+        info.top_processes[0].command = command.to_string();
+        info.time.as_mut().unwrap().ntp_source = Some("ntp.".repeat(20));
+
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        let widths: Vec<usize> = table.lines().map(|l| l.chars().count()).collect();
+        assert!(widths.windows(2).all(|w| w[0] == w[1]), "{widths:?}");
+        assert!(table.contains("│ 187.5% 4242 /usr/lib/jvm/bin/... │"));
+        assert!(!table.contains(command));
+
+        assert!(generate_markdown(&info).contains(command));
+        assert!(json_value(&info)["time"]["ntp_source"] == "ntp.".repeat(20));
+    }
+
+    #[test]
+    fn degraded_systemd_host_lists_failed_units_and_boot_split() {
+        use crate::collectors::services::ServicesInfo;
+
+        let mut info = fixture_info();
+        info.services = Some(ServicesInfo {
+            system_state: Some("degraded".to_string()),
+            failed_units: vec!["nginx.service".to_string()],
+            boot_timing: Some(BootTiming {
+                kernel_ms: Some(1_234),
+                userspace_ms: Some(10_200),
+                total_ms: Some(11_434),
+                ..BootTiming::default()
+            }),
+            last_boot: Some("Sat 2026-10-17 09:01:02 UTC".to_string()),
+        });
+
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        assert!(table.contains("degraded (1 failed unit)"));
+        assert!(table.contains("FAILED 1"));
+        assert!(table.contains("nginx.service"));
+        assert!(table.contains("BOOT KERNEL"));
+        assert!(table.contains("1.2s"));
+        assert!(!table.contains("BOOT FW"));
+
+        let value: serde_json::Value =
+            serde_json::from_str(&generate_json(&info)).expect("valid json");
+        assert_eq!(value["services"]["system_state"], "degraded");
+        assert_eq!(value["services"]["failed_units"][0], "nginx.service");
+        assert_eq!(value["services"]["boot_timing"]["userspace_ms"], 10_200);
+        assert!(value["services"]["boot_timing"]["firmware_ms"].is_null());
+
+        let markdown = generate_markdown(&info);
+        assert!(markdown.contains("| Failed Unit | nginx.service |"));
+        assert!(markdown.contains("| Last Boot | Sat 2026-10-17 09:01:02 UTC |"));
+    }
+
+    #[test]
+    fn json_processes_array_is_empty_without_a_snapshot() {
+        let value: serde_json::Value =
+            serde_json::from_str(&generate_json(&fixture_info())).expect("valid json");
+        assert_eq!(value["processes"], serde_json::json!([]));
+        assert!(!generate_markdown(&fixture_info()).contains("Top Processes"));
+    }
+
+    #[test]
+    fn unselected_sections_are_left_out_of_every_format() {
+        let mut info = fixture_info();
+        info.sections = vec![Section::Cpu, Section::Memory];
+
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        assert!(table.contains("PROCESSOR") && table.contains("AVAILABLE"));
+        for absent in ["KERNEL", "HOSTNAME", "VOLUME", "UPTIME", "USER", "BOARD"] {
+            assert!(!table.contains(absent), "{absent} should not be rendered");
+        }
+        let dividers = table.lines().filter(|l| l.contains('┼')).count();
+        assert_eq!(dividers, 1, "two blocks need exactly one divider");
+
+        let json = json_value(&info);
+        let keys: Vec<&str> = json
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                "collection_mode",
+                "cpu",
+                "elevated",
+                "elevation_unlocks_more",
+                "errors",
+                "memory",
+                "processes",
+                "schema_version"
+            ]
+        );
+
+        let markdown = generate_markdown(&info);
+        assert!(markdown.contains("## CPU") && markdown.contains("## Memory"));
+        assert!(!markdown.contains("## System") && !markdown.contains("## Network"));
+    }
+
+    #[test]
+    fn platform_fields_are_left_out_when_the_platform_collector_did_not_run() {
+        let mut info = fixture_info();
+        info.sections = vec![Section::Cpu, Section::Memory, Section::Disk];
+        let json = json_value(&info);
+        assert_eq!(json["cpu"]["core_topology"], "4P + 4E");
+        assert_eq!(json["memory"]["ram_slots"], "2x16GB | DDR5");
+
+        info.platform_collected = false;
+        let json = json_value(&info);
+        for field in PLATFORM_FIELDS {
+            assert!(
+                export::get(&json, field).is_none(),
+                "{field} should be absent"
+            );
+        }
+        assert_eq!(json["cpu"]["physical_cores"], 4);
+        assert_eq!(json["memory"]["available_bytes"], 1);
+    }
+
+    #[test]
+    fn collection_errors_render_under_the_table_and_in_json() {
+        let mut info = fixture_info();
+        info.sections.retain(|s| *s != Section::Disk);
+        info.collection_errors = vec![CollectionError {
+            collector: "disk".to_string(),
+            message: "mount table unreadable".to_string(),
+            elapsed: std::time::Duration::from_millis(42),
+        }];
+
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        assert!(table.contains("PROCESSOR") && !table.contains("VOLUME"));
+        assert!(table.contains("disk collector failed after 42 ms: mount table unreadable\n"));
+        let colored = generate_table(&info, &Config::default().with_colors(true));
+        assert!(colored.contains("\x1b[33mdisk collector failed"));
+
+        let json = json_value(&info);
+        assert!(json.get("disk").is_none());
+        assert_eq!(
+            json["errors"],
+            serde_json::json!([{
+                "collector": "disk",
+                "message": "mount table unreadable",
+                "elapsed_ms": 42,
+            }])
+        );
+        assert_eq!(json_value(&fixture_info())["errors"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn config_section_toggles_hide_collected_sections() {
+        let info = fixture_info();
+        let config = Config::default()
+            .with_colors(false)
+            .without_network()
+            .without_disks();
+        assert!(!config.sections.contains(&Section::Network));
+
+        let table = generate(&info, &config);
+        assert!(table.contains("PROCESSOR") && table.contains("KERNEL"));
+        assert!(!table.contains("HOSTNAME") && !table.contains("VOLUME"));
+
+        let json = generate(&info, &config.with_format(OutputFormat::Json));
+        assert!(!json.contains("\"network\"") && !json.contains("\"disk\""));
+        assert!(json.contains("\"cpu\""));
+
+        let mut info = optional_sections_info();
+        info.services = Some(crate::collectors::services::ServicesInfo {
+            system_state: Some("running".to_string()),
+            ..Default::default()
+        });
+        for (hidden, label, heading) in [
+            (Section::Services, "SYSTEMD", "Services"),
+            (Section::Containers, "RUNTIME", "Containers"),
+            (Section::Time, "TIMEZONE", "Time"),
+            (Section::Cpu, "TOP CPU", "Top Processes"),
+        ] {
+            let mut config = Config::default().with_colors(false);
+            config.sections.retain(|section| *section != hidden);
+            let table = generate(&info, &config);
+            assert!(!table.contains(label), "{label} should be hidden");
+            assert!(table.contains("KERNEL"));
+
+            let html = generate(&info, &config.with_format(OutputFormat::Html));
+            assert!(
+                !html.contains(&format!(">{heading}</h2>")),
+                "{heading} should be hidden"
+            );
+            assert!(html.contains(">Memory</h2>"));
+        }
+    }
+
     #[test]
     fn markdown_escapes_table_cell_pipes() {
         let markdown = generate_markdown(&fixture_info());
@@ -915,9 +2194,9 @@ mod tests {
         let existing = dir.join("tr300-report-fixed.md");
         std::fs::write(&existing, "keep me").unwrap();
 
+        let markdown = generate_markdown(&fixture_info());
         let outcome =
-            save_markdown_report_to_dir(&fixture_info(), &dir, false, "tr300-report-fixed")
-                .unwrap();
+            save_report_to_dir(&markdown, "md", &dir, false, "tr300-report-fixed").unwrap();
 
         assert_eq!(std::fs::read_to_string(existing).unwrap(), "keep me");
         assert_eq!(
@@ -944,9 +2223,9 @@ mod tests {
         std::fs::write(&target, "do not replace").unwrap();
         symlink(&target, dir.join("tr300-report-fixed.md")).unwrap();
 
+        let markdown = generate_markdown(&fixture_info());
         let outcome =
-            save_markdown_report_to_dir(&fixture_info(), &dir, false, "tr300-report-fixed")
-                .unwrap();
+            save_report_to_dir(&markdown, "md", &dir, false, "tr300-report-fixed").unwrap();
 
         assert_eq!(std::fs::read_to_string(target).unwrap(), "do not replace");
         assert_eq!(
@@ -957,6 +2236,50 @@ mod tests {
         let _ = std::fs::remove_dir_all(dir);
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn html_save_shares_the_collision_safe_writer() {
+        use std::os::unix::fs::symlink;
+
+        let dir = isolated_test_dir("html");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let target = dir.join("target.html");
+        std::fs::write(&target, "do not replace").unwrap();
+        symlink(&target, dir.join("tr300-report-fixed.html")).unwrap();
+
+        let html = generate_html(&fixture_info(), &Config::default());
+        let outcome = save_report_to_dir(&html, "html", &dir, false, "tr300-report-fixed").unwrap();
+
+        assert_eq!(std::fs::read_to_string(target).unwrap(), "do not replace");
+        assert_eq!(
+            outcome.path.file_name().and_then(|name| name.to_str()),
+            Some("tr300-report-fixed-1.html")
+        );
+
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn html_report_is_self_contained_with_threshold_meters() {
+        let mut info = fixture_info();
+        info.disk_percent = 91.0;
+        info.hostname = "lab<01>".to_string();
+        let html = generate(&info, &Config::default().with_format(OutputFormat::Html));
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<style>") && html.contains("@media print"));
+        assert!(!html.contains("<script") && !html.contains("<link"));
+        assert!(html.contains("<title>TR-300 Machine Report - lab&lt;01&gt;</title>"));
+        assert!(html.contains("<h2>Storage</h2>"));
+        assert!(html.contains(
+            "<th scope=\"row\">Disk Usage</th><td><span class=\"meter critical\"><span style=\"width:91.0%\"></span></span>91.00%</td>"
+        ));
+        assert!(html.contains("<th scope=\"row\">Machine Model</th><td>Model | One</td>"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
     #[test]
     fn escape_json_handles_backslash_and_quote() {
         // The two characters JSON requires escaped inside a string.
@@ -1001,4 +2324,96 @@ mod tests {
         // No special chars — output should match input.
         assert_eq!(super::escape_json("plain ascii"), "plain ascii");
     }
+
+    #[test]
+    fn active_sessions_summarize_remote_logins() {
+        let session = |user: &str, tty: &str, host: Option<&str>| LoginSession {
+            user: user.to_string(),
+            tty: tty.to_string(),
+            host: host.map(str::to_string),
+            login_unix_seconds: 1_700_000_000,
+            idle_seconds: Some(3_720),
+        };
+        let mut info = fixture_info();
+        info.active_sessions = Some(vec![
+            session("alice", "pts/0", Some("192.0.2.44")),
+            session("alice", "pts/1", Some("tmux(4242).%0")),
+            session("bob", "tty1", None),
+            session("carol", "pts/2", Some("bastion|1")),
+        ]);
+
+        let table = generate_table(&info, &Config::default().with_colors(false));
+        assert!(table.contains("USERS"));
+        assert!(table.contains("4 users, 2 via SSH"));
+
+        let value: serde_json::Value =
+            serde_json::from_str(&generate_json(&info)).expect("valid json");
+        assert_eq!(value["sessions"].as_array().map(Vec::len), Some(4));
+        assert_eq!(value["sessions"][0]["host"], "192.0.2.44");
+        assert_eq!(value["sessions"][0]["remote"], true);
+        assert_eq!(value["sessions"][1]["remote"], false);
+        assert!(value["sessions"][2]["host"].is_null());
+
+        let markdown = generate_markdown(&info);
+        assert!(markdown.contains("## Active Sessions"));
+        assert!(markdown.contains("| carol | pts/2 | bastion\\|1 |"));
+        assert!(markdown.contains("| 1h02m |"));
+
+        info.active_sessions = None;
+        let value: serde_json::Value =
+            serde_json::from_str(&generate_json(&info)).expect("valid json");
+        assert!(value["sessions"].is_null());
+        assert!(!generate_table(&info, &Config::default().with_colors(false)).contains("USERS"));
+    }
+
+    #[test]
+    fn idle_times_use_compact_w_style_units() {
+        assert_eq!(format_idle(42), "42s");
+        assert_eq!(format_idle(300), "5m");
+        assert_eq!(format_idle(11_520), "3h12m");
+        assert_eq!(format_idle(200_000), "2d");
+    }
+
+    #[test]
+    fn threshold_levels_color_bars_or_mark_them_without_color() {
+        let mut info = fixture_info();
+        info.disk_percent = 95.0;
+        info.mem_percent = 86.0;
+        info.battery = Some("73% (Discharging); health 55%".to_string());
+
+        let colored = generate_table(&info, &Config::default());
+        assert!(colored.contains("\x1b[31m"), "critical disk is red");
+        assert!(colored.contains("\x1b[33m"), "warning memory is yellow");
+        assert!(colored.contains("\x1b[32m"), "healthy load is green");
+        assert!(!colored.contains(" !"));
+
+        for config in [
+            Config::default().with_colors(false),
+            Config::default().with_ascii(),
+        ] {
+            let table = generate_table(&info, &config);
+            assert!(!table.contains("\x1b["));
+            assert!(table.contains("95.0% !!"));
+            assert!(table.contains("86.0% !"));
+            assert!(table.contains("health 55% !!"));
+            assert!(table.contains("20.0%")); // load stays unmarked
+            assert!(!table.contains("20.0% !"));
+        }
+
+        // The marker takes bar space, not table width.
+        let ascii = generate_table(&info, &Config::default().with_ascii());
+        for line in ascii.lines().filter(|line| line.starts_with(['+', '|'])) {
+            assert_eq!(line.chars().count(), 51, "{line}");
+        }
+
+        let mut thresholds = Config::default().thresholds;
+        thresholds.disk.warn = 96.0;
+        thresholds.disk.crit = 99.0;
+        let table = generate_table(
+            &info,
+            &Config::default().with_ascii().with_thresholds(thresholds),
+        );
+        assert!(table.contains("95.0%"));
+        assert!(!table.contains("95.0% !"));
+    }
 }
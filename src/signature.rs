@@ -0,0 +1,279 @@
+//! Minisign signature verification for downloaded release assets
+//!
+//! Release assets are signed with `minisign -S` and published next to the
+//! asset as `<asset>.minisig`. Both signature algorithms are accepted:
+//! prehashed `ED` (Ed25519 over the BLAKE2b-512 digest of the file, the
+//! minisign default since 0.10) and legacy `Ed` (Ed25519 over the raw file,
+//! `minisign -S -l`). The trusted public key is embedded at build time from
+//! the `TR300_RELEASE_PUBLIC_KEY` environment variable; an extra key can be
+//! trusted at runtime for a mirror that re-signs assets.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use blake2::{Blake2b512, Digest};
+use ring::signature::{UnparsedPublicKey, ED25519};
+
+/// Build-time release signing key (minisign public key text)
+pub(crate) const EMBEDDED_RELEASE_KEY: Option<&str> = option_env!("TR300_RELEASE_PUBLIC_KEY");
+
+const ALGORITHM_LEGACY: [u8; 2] = *b"Ed";
+const ALGORITHM_PREHASHED: [u8; 2] = *b"ED";
+const TRUSTED_COMMENT_PREFIX: &str = "trusted comment: ";
+
+/// A minisign Ed25519 public key
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PublicKey {
+    key_id: [u8; 8],
+    key: [u8; 32],
+}
+
+impl PublicKey {
+    /// Parse either the bare base64 key line (`RWQ...`) or the whole
+    /// `minisign.pub` file including its `untrusted comment:` line.
+    pub(crate) fn parse(text: &str) -> Result<Self, String> {
+        let line = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with("untrusted comment:"))
+            .ok_or_else(|| "minisign public key is empty".to_string())?;
+        let bytes = STANDARD
+            .decode(line)
+            .map_err(|e| format!("minisign public key is not valid base64: {}", e))?;
+        if bytes.len() != 42 || bytes[..2] != ALGORITHM_LEGACY {
+            return Err("not a minisign Ed25519 public key".to_string());
+        }
+        let mut key_id = [0u8; 8];
+        let mut key = [0u8; 32];
+        key_id.copy_from_slice(&bytes[2..10]);
+        key.copy_from_slice(&bytes[10..]);
+        Ok(Self { key_id, key })
+    }
+
+    /// The key in minisign's single-line base64 form
+    pub(crate) fn encoded(&self) -> String {
+        let mut bytes = ALGORITHM_LEGACY.to_vec();
+        bytes.extend_from_slice(&self.key_id);
+        bytes.extend_from_slice(&self.key);
+        STANDARD.encode(bytes)
+    }
+
+    /// Key ID as minisign prints it
+    pub(crate) fn key_id(&self) -> String {
+        key_id_hex(&self.key_id)
+    }
+}
+
+/// A signature that checked out against one of the trusted keys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Verified {
+    /// Key ID of the key that verified the signature
+    pub key_id: String,
+    /// Signed `trusted comment:` text
+    pub trusted_comment: String,
+}
+
+/// Verify a `.minisig` file against `message`. Both the file signature and
+/// the global signature over the trusted comment must be valid, and the
+/// signing key must be one of `keys`.
+pub(crate) fn verify(
+    message: &[u8],
+    minisig: &str,
+    keys: &[PublicKey],
+) -> Result<Verified, String> {
+    let mut lines = minisig.lines().map(|line| line.trim_end_matches('\r'));
+    let untrusted = lines.next().unwrap_or("");
+    if !untrusted.starts_with("untrusted comment:") {
+        return Err("malformed minisign signature: missing `untrusted comment:` line".into());
+    }
+    let signature = STANDARD
+        .decode(lines.next().unwrap_or("").trim())
+        .map_err(|e| format!("malformed minisign signature: {}", e))?;
+    if signature.len() != 74 {
+        return Err("malformed minisign signature: wrong signature length".into());
+    }
+    let trusted_comment = lines
+        .next()
+        .and_then(|line| line.strip_prefix(TRUSTED_COMMENT_PREFIX))
+        .ok_or_else(|| {
+            "malformed minisign signature: missing `trusted comment:` line".to_string()
+        })?;
+    let global_signature = STANDARD
+        .decode(lines.next().unwrap_or("").trim())
+        .map_err(|e| format!("malformed minisign global signature: {}", e))?;
+
+    let (algorithm, rest) = signature.split_at(2);
+    let (key_id, file_signature) = rest.split_at(8);
+    let prehashed;
+    let signed: &[u8] = match [algorithm[0], algorithm[1]] {
+        ALGORITHM_LEGACY => message,
+        ALGORITHM_PREHASHED => {
+            prehashed = Blake2b512::digest(message);
+            &prehashed
+        }
+        _ => return Err("unsupported minisign signature algorithm".into()),
+    };
+    let key = keys
+        .iter()
+        .find(|key| key.key_id[..] == *key_id)
+        .ok_or_else(|| {
+            format!(
+                "signed by untrusted key {} (trusted: {})",
+                key_id_hex(key_id),
+                keys.iter()
+                    .map(PublicKey::key_id)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+
+    let public_key = UnparsedPublicKey::new(&ED25519, &key.key);
+    public_key
+        .verify(signed, file_signature)
+        .map_err(|_| format!("signature by key {} does not match the file", key.key_id()))?;
+    let mut global_message = file_signature.to_vec();
+    global_message.extend_from_slice(trusted_comment.as_bytes());
+    public_key
+        .verify(&global_message, &global_signature)
+        .map_err(|_| "the signature's trusted comment has been altered".to_string())?;
+
+    Ok(Verified {
+        key_id: key.key_id(),
+        trusted_comment: trusted_comment.to_string(),
+    })
+}
+
+/// minisign prints key IDs as a little-endian 64-bit hex number
+fn key_id_hex(key_id: &[u8]) -> String {
+    key_id.iter().rev().map(|b| format!("{:02X}", b)).collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    /// Test signer producing minisign-format keys and signatures
+    pub(crate) struct Signer {
+        pair: Ed25519KeyPair,
+        key_id: [u8; 8],
+    }
+
+    impl Signer {
+        pub(crate) fn new(key_id: [u8; 8]) -> Self {
+            let rng = ring::rand::SystemRandom::new();
+            let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+            let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+            Self { pair, key_id }
+        }
+
+        pub(crate) fn public_key(&self) -> String {
+            let mut bytes = ALGORITHM_LEGACY.to_vec();
+            bytes.extend_from_slice(&self.key_id);
+            bytes.extend_from_slice(self.pair.public_key().as_ref());
+            format!(
+                "untrusted comment: minisign public key\n{}\n",
+                STANDARD.encode(bytes)
+            )
+        }
+
+        pub(crate) fn sign_with(&self, message: &[u8], algorithm: [u8; 2]) -> String {
+            let file_signature = if algorithm == ALGORITHM_PREHASHED {
+                self.pair.sign(&Blake2b512::digest(message))
+            } else {
+                self.pair.sign(message)
+            };
+            let trusted_comment = "timestamp:1760000000\tfile:installer";
+            let mut signature = algorithm.to_vec();
+            signature.extend_from_slice(&self.key_id);
+            signature.extend_from_slice(file_signature.as_ref());
+            let mut global = file_signature.as_ref().to_vec();
+            global.extend_from_slice(trusted_comment.as_bytes());
+            format!(
+                "untrusted comment: signature from minisign secret key\n{}\n{}{}\n{}\n",
+                STANDARD.encode(signature),
+                TRUSTED_COMMENT_PREFIX,
+                trusted_comment,
+                STANDARD.encode(self.pair.sign(&global))
+            )
+        }
+
+        pub(crate) fn sign(&self, message: &[u8]) -> String {
+            self.sign_with(message, ALGORITHM_LEGACY)
+        }
+    }
+
+    #[test]
+    fn public_keys_parse_from_bare_line_or_file() {
+        let signer = Signer::new([1, 2, 3, 4, 5, 6, 7, 8]);
+        let file = signer.public_key();
+        let bare = file.lines().nth(1).unwrap();
+        assert_eq!(
+            PublicKey::parse(&file).unwrap(),
+            PublicKey::parse(bare).unwrap()
+        );
+        assert_eq!(PublicKey::parse(&file).unwrap().encoded(), bare);
+        assert_eq!(PublicKey::parse(bare).unwrap().key_id(), "0807060504030201");
+        assert!(PublicKey::parse("").is_err());
+        assert!(PublicKey::parse("not base64!").is_err());
+        assert!(PublicKey::parse(&STANDARD.encode([0u8; 42])).is_err());
+    }
+
+    #[test]
+    fn verification_fails_closed() {
+        let release = Signer::new([7; 8]);
+        let other = Signer::new([9; 8]);
+        let keys = vec![PublicKey::parse(&release.public_key()).unwrap()];
+        let message = b"installer bytes";
+
+        let verified = verify(message, &release.sign(message), &keys).unwrap();
+        assert_eq!(verified.key_id, "0707070707070707");
+        assert!(verified.trusted_comment.starts_with("timestamp:"));
+
+        let tampered = verify(b"installer bytez", &release.sign(message), &keys);
+        assert!(tampered.unwrap_err().contains("does not match"));
+        let untrusted = verify(message, &other.sign(message), &keys);
+        assert!(untrusted.unwrap_err().contains("untrusted key"));
+        assert!(verify(message, "", &keys).is_err());
+
+        let sig = release.sign(message);
+        let altered = sig.replace("file:installer", "file:other");
+        assert!(verify(message, &altered, &keys)
+            .unwrap_err()
+            .contains("trusted comment"));
+
+        let both = vec![
+            PublicKey::parse(&other.public_key()).unwrap(),
+            keys[0].clone(),
+        ];
+        assert!(verify(message, &other.sign(message), &both).is_ok());
+    }
+
+    #[test]
+    fn prehashed_signatures_cover_the_blake2b_digest() {
+        // BLAKE2b-512("abc"), RFC 7693 appendix A
+        assert_eq!(
+            format!("{:x}", Blake2b512::digest(b"abc")),
+            "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+             7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+        );
+
+        let release = Signer::new([7; 8]);
+        let keys = vec![PublicKey::parse(&release.public_key()).unwrap()];
+        let message = b"installer bytes";
+        let prehashed = release.sign_with(message, ALGORITHM_PREHASHED);
+        assert!(verify(message, &prehashed, &keys).is_ok());
+        assert!(verify(b"installer bytez", &prehashed, &keys)
+            .unwrap_err()
+            .contains("does not match"));
+        // A prehashed signature is not a legacy one over the same file.
+        let relabelled = prehashed.replacen(
+            &STANDARD.encode([&ALGORITHM_PREHASHED[..], &[7; 8]].concat())[..12],
+            &STANDARD.encode([&ALGORITHM_LEGACY[..], &[7; 8]].concat())[..12],
+            1,
+        );
+        assert!(verify(message, &relabelled, &keys).is_err());
+        assert!(verify(message, &release.sign_with(message, *b"Xx"), &keys)
+            .unwrap_err()
+            .contains("unsupported"));
+    }
+}
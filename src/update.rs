@@ -10,7 +10,7 @@
 // installs use their own durable metadata/receipt. Ambiguous or portable
 // origins do not mutate the machine.
 
-use crate::config::{Config, OutputFormat};
+use crate::config::{Config, OutputFormat, ReleaseChannel, ReleaseSource};
 use crate::VERSION;
 
 #[cfg(target_os = "macos")]
@@ -21,12 +21,6 @@ use std::sync::atomic::{AtomicBool, Ordering};
 
 static JSON_UPDATE_MODE: AtomicBool = AtomicBool::new(false);
 
-/// GitHub API endpoint for the latest release.
-const RELEASES_URL: &str =
-    "https://api.github.com/repos/QubeTX/qube-machine-report/releases/latest";
-
-const RELEASE_DOWNLOAD_BASE: &str =
-    "https://github.com/QubeTX/qube-machine-report/releases/download";
 const SHELL_INSTALLER_ASSET: &str = "tr300-installer.sh";
 const PS_INSTALLER_ASSET: &str = "tr300-installer.ps1";
 const MSI_GLOBAL_ASSET: &str = "tr300-x86_64-pc-windows-msvc.msi";
@@ -34,6 +28,16 @@ const MSI_CORPORATE_ASSET: &str = "tr300-x86_64-pc-windows-msvc-corporate.msi";
 const EXE_GLOBAL_ASSET: &str = "tr300-x86_64-pc-windows-msvc-setup.exe";
 const EXE_CORPORATE_ASSET: &str = "tr300-x86_64-pc-windows-msvc-corporate-setup.exe";
 const MAC_PKG_ASSET: &str = "tr300-universal-apple-darwin.pkg";
+/// The cargo-dist archive for the target this binary was built for; the
+/// shell and PowerShell installers unpack the same file.
+#[cfg(windows)]
+const RELEASE_ARCHIVE_ASSET: &str = concat!("tr300-", env!("TR300_BUILD_TARGET"), ".zip");
+#[cfg(not(windows))]
+const RELEASE_ARCHIVE_ASSET: &str = concat!("tr300-", env!("TR300_BUILD_TARGET"), ".tar.xz");
+#[cfg(windows)]
+const RELEASE_ARCHIVE_SUFFIX: &str = "zip";
+#[cfg(not(windows))]
+const RELEASE_ARCHIVE_SUFFIX: &str = "tar.xz";
 #[cfg(any(test, target_os = "macos"))]
 const MAC_PKG_ID: &str = "com.qubetx.tr300.pkg";
 #[cfg(any(test, target_os = "macos"))]
@@ -47,12 +51,6 @@ const CRATE_NAME: &str = "tr300";
 const MANUAL_INSTALL_URL: &str = "https://github.com/QubeTX/qube-machine-report#installation";
 const RELEASES_PAGE: &str = "https://github.com/QubeTX/qube-machine-report/releases/latest";
 
-/// Public docs and filenames stay versionless, but an update transaction pins
-/// every downloaded byte to the immutable tag returned by the releases API.
-fn release_asset_url(version: &str, asset: &str) -> String {
-    format!("{RELEASE_DOWNLOAD_BASE}/v{version}/{asset}")
-}
-
 // ── Strategy types ─────────────────────────────────────────────────
 
 /// Ordered candidate strategies for updating the binary.
@@ -62,8 +60,7 @@ fn release_asset_url(version: &str, asset: &str) -> String {
 /// fall back to a different installer type on failure — re-running a
 /// different product would create coexistence problems (two ARP entries,
 /// PATH ordering decides which wins). Cargo and cargo-dist users also get only
-/// their detected channel; multiple launchers such as curl/wget may implement
-/// that one channel, but no strategy crosses into another install method.
+/// their detected channel; no strategy crosses into another install method.
 // The four MSI/EXE variants are only ever constructed by
 // build_strategy_list() inside its #[cfg(windows)] block, so on non-Windows
 // targets the dead_code lint flags them as never-constructed. The variants
@@ -75,10 +72,9 @@ fn release_asset_url(version: &str, asset: &str) -> String {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum UpdateStrategy {
     Cargo,
-    InstallerCurl,
-    InstallerWget,
-    InstallerPowerShell,
-    InstallerPwsh,
+    /// Replaces the binary from the verified cargo-dist archive for this
+    /// target (shell and PowerShell installer channels).
+    ReleaseArchive,
     /// Re-runs the Global perMachine MSI (UAC required, replaces v3.15.0+).
     MsiGlobal,
     /// Re-runs the Corporate perUser MSI (no UAC required).
@@ -89,36 +85,34 @@ enum UpdateStrategy {
     ExeCorporate,
     /// Opens the signed universal PKG directly and waits for Apple Installer.
     MacPkg,
+    /// Replaces the binary from a local release archive (`update --from`).
+    LocalArchive,
 }
 
 impl UpdateStrategy {
     fn label(self) -> &'static str {
         match self {
             UpdateStrategy::Cargo => "cargo install",
-            UpdateStrategy::InstallerCurl => "curl shell installer",
-            UpdateStrategy::InstallerWget => "wget shell installer",
-            UpdateStrategy::InstallerPowerShell => "PowerShell installer",
-            UpdateStrategy::InstallerPwsh => "pwsh installer",
+            UpdateStrategy::ReleaseArchive => "release archive",
             UpdateStrategy::MsiGlobal => "Global MSI installer",
             UpdateStrategy::MsiCorporate => "Corporate MSI installer",
             UpdateStrategy::ExeGlobal => "Global EXE installer",
             UpdateStrategy::ExeCorporate => "Corporate EXE installer",
             UpdateStrategy::MacPkg => "macOS PKG installer",
+            UpdateStrategy::LocalArchive => "local release archive",
         }
     }
 
     fn json_id(self) -> &'static str {
         match self {
             UpdateStrategy::Cargo => "cargo",
-            UpdateStrategy::InstallerCurl => "installer_curl",
-            UpdateStrategy::InstallerWget => "installer_wget",
-            UpdateStrategy::InstallerPowerShell => "installer_powershell",
-            UpdateStrategy::InstallerPwsh => "installer_pwsh",
+            UpdateStrategy::ReleaseArchive => "release_archive",
             UpdateStrategy::MsiGlobal => "msi_global",
             UpdateStrategy::MsiCorporate => "msi_corporate",
             UpdateStrategy::ExeGlobal => "exe_global",
             UpdateStrategy::ExeCorporate => "exe_corporate",
             UpdateStrategy::MacPkg => "mac_pkg",
+            UpdateStrategy::LocalArchive => "local_archive",
         }
     }
 
@@ -237,6 +231,9 @@ enum StrategyError {
     /// A write, staged file, or launcher was blocked by endpoint/filesystem
     /// policy. Stop instead of trying more write-heavy strategies.
     PolicyBlocked(String),
+    /// A downloaded or local installer had a missing or bad signature. Stop:
+    /// every other channel would download from the same release host.
+    Unverified(String),
 }
 
 #[cfg(windows)]
@@ -247,14 +244,18 @@ impl StrategyError {
             Self::Preflight(message) => Self::Preflight(format!("{message}; {detail}")),
             Self::Runtime(message) => Self::Runtime(format!("{message}; {detail}")),
             Self::PolicyBlocked(message) => Self::PolicyBlocked(format!("{message}; {detail}")),
+            Self::Unverified(message) => Self::Unverified(format!("{message}; {detail}")),
         }
     }
+}
 
+impl StrategyError {
     fn message(&self) -> &str {
         match self {
-            Self::Preflight(message) | Self::Runtime(message) | Self::PolicyBlocked(message) => {
-                message
-            }
+            Self::Preflight(message)
+            | Self::Runtime(message)
+            | Self::PolicyBlocked(message)
+            | Self::Unverified(message) => message,
         }
     }
 }
@@ -264,6 +265,7 @@ enum AttemptKind {
     Skipped,
     Failed,
     Blocked,
+    Unverified,
 }
 
 #[derive(Debug)]
@@ -322,23 +324,63 @@ fn fail_icon(config: &Config) -> &'static str {
 
 // ── Public entry point ─────────────────────────────────────────────
 
+/// Options for [`run`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Default)]
+pub struct UpdateOptions {
+    /// Install exactly this release (`X.Y.Z`) instead of the latest one.
+    /// Older releases are allowed, so this also downgrades.
+    pub version: Option<String>,
+    /// Install this local release archive or installer instead of
+    /// downloading one. `version` then names the release it contains and
+    /// allows it to be older than the running binary.
+    pub from: Option<std::path::PathBuf>,
+}
+
+/// Where a strategy gets its release asset.
+#[derive(Debug, Clone, Copy)]
+enum Payload<'a> {
+    /// Download it from the release source.
+    Download,
+    /// Copy it from a local file given to `update --from`.
+    Local(&'a std::path::Path),
+}
+
 /// Run the self-update flow. Returns an exit code (0 = success, 2 = error).
-pub fn run(config: &Config) -> i32 {
+pub fn run(config: &Config, opts: &UpdateOptions) -> i32 {
+    if let Some(file) = &opts.from {
+        return run_from(config, file, opts);
+    }
     if config.format == OutputFormat::Json {
-        return run_json();
+        return run_json(&config.release_source, opts);
     }
 
+    let pinned = opts.version.is_some();
     println!();
-    println!("  {} Checking for updates...", cyan("*", config));
+    if let Some(version) = &opts.version {
+        println!("  {} Resolving release v{}...", cyan("*", config), version);
+    } else {
+        println!(
+            "  {} Checking for updates ({} channel)...",
+            cyan("*", config),
+            config.release_source.channel
+        );
+    }
 
-    // Fetch latest version from GitHub
-    let latest = match fetch_latest_version() {
+    // Fetch the target version from GitHub
+    let source = &config.release_source;
+    let target = match resolve_target(source, opts) {
         Ok(v) => v,
         Err(e) => {
+            let what = if pinned {
+                "Failed to resolve the requested release"
+            } else {
+                "Failed to check for updates"
+            };
             println!(
                 "  {} {}",
                 red(fail_icon(config), config),
-                red(&format!("Failed to check for updates: {}", e), config),
+                red(&format!("{}: {}", what, e), config),
             );
             return 2;
         }
@@ -346,24 +388,34 @@ pub fn run(config: &Config) -> i32 {
 
     let current = VERSION.to_string();
 
-    if !is_newer(&current, &latest) {
+    if !needs_install(&current, &target, pinned) {
+        let message = if pinned {
+            format!("Already on v{}", current)
+        } else {
+            format!("Already on the latest version (v{})", current)
+        };
         println!(
             "  {} {}",
             green(success_icon(config), config),
-            green(
-                &format!("Already on the latest version (v{})", current),
-                config
-            ),
+            green(&message, config),
         );
         return 0;
     }
 
+    let heading = if !pinned {
+        "Update available"
+    } else if is_newer(&target, &current) {
+        "Downgrading to pinned release"
+    } else {
+        "Installing pinned release"
+    };
     println!(
-        "  {} Update available: v{} {} v{}",
+        "  {} {}: v{} {} v{}",
         cyan("*", config),
+        heading,
         current,
         cyan("->", config),
-        latest,
+        target,
     );
 
     let channel = detect_install_channel();
@@ -377,72 +429,243 @@ pub fn run(config: &Config) -> i32 {
     }
     println!();
 
-    match execute_update(&latest, &strategies) {
+    match execute_update(source, Payload::Download, &target, &strategies) {
         Ok(used) => {
             println!();
+            let verb = if pinned { "Installed" } else { "Updated to" };
             println!(
                 "  {} {}",
                 green(success_icon(config), config),
                 green(
-                    &format!("Updated to v{} via {}", latest, used.label()),
+                    &format!("{} v{} via {}", verb, target, used.label()),
                     config
                 ),
             );
             0
         }
         Err(failure) => {
-            println!();
-            if failure.attempts.is_empty() {
-                println!(
-                    "  {} {}",
-                    red(fail_icon(config), config),
-                    red(
-                        "No update was attempted because the installation channel is unknown or conflicting.",
-                        config,
-                    ),
-                );
-            } else {
-                println!(
-                    "  {} {}",
-                    red(fail_icon(config), config),
-                    red("Update failed. Strategies attempted:", config),
-                );
-                for record in &failure.attempts {
-                    let kind = match record.kind {
-                        AttemptKind::Skipped => "skipped",
-                        AttemptKind::Failed => "failed",
-                        AttemptKind::Blocked => "blocked",
-                    };
-                    println!(
-                        "      · {} — {}: {}",
-                        record.strategy.label(),
-                        kind,
-                        record.message
-                    );
-                }
-            }
-            println!();
-            println!("  Your existing installation was left in place.");
-            if let Some(asset) = recovery_asset_for_channel(channel) {
-                println!(
-                    "  Matching v{} installer: {}",
-                    latest,
-                    release_asset_url(&latest, asset)
-                );
-            }
-            println!("  Fresh installer: {}", MANUAL_INSTALL_URL);
-            println!("  Official latest release: {}", RELEASES_PAGE);
+            print_update_failure(config, source, channel, &target, &failure);
             2
         }
     }
 }
 
+fn print_update_failure(
+    config: &Config,
+    source: &ReleaseSource,
+    channel: InstallChannel,
+    target: &str,
+    failure: &UpdateFailure,
+) {
+    println!();
+    if failure.attempts.is_empty() {
+        println!(
+            "  {} {}",
+            red(fail_icon(config), config),
+            red(
+                "No update was attempted because the installation channel is unknown or conflicting.",
+                config,
+            ),
+        );
+    } else {
+        println!(
+            "  {} {}",
+            red(fail_icon(config), config),
+            red("Update failed. Strategies attempted:", config),
+        );
+        for record in &failure.attempts {
+            let kind = match record.kind {
+                AttemptKind::Skipped => "skipped",
+                AttemptKind::Failed => "failed",
+                AttemptKind::Blocked => "blocked",
+                AttemptKind::Unverified => "unverified",
+            };
+            println!(
+                "      · {} — {}: {}",
+                record.strategy.label(),
+                kind,
+                record.message
+            );
+        }
+    }
+    println!();
+    println!("  Your existing installation was left in place.");
+    if let Some(asset) = recovery_asset_for_channel(channel) {
+        println!(
+            "  Matching v{} installer: {}",
+            target,
+            source.asset_url(target, asset)
+        );
+    }
+    println!("  Fresh installer: {}", MANUAL_INSTALL_URL);
+    println!("  Official latest release: {}", RELEASES_PAGE);
+}
+
+/// The release `run` installs: the pinned version when one was requested
+/// (confirmed to exist), otherwise the latest release.
+fn resolve_target(source: &ReleaseSource, opts: &UpdateOptions) -> Result<String, String> {
+    match &opts.version {
+        Some(version) => fetch_pinned_version(source, version),
+        None => fetch_latest_version(source),
+    }
+}
+
+/// Whether `target` should be installed over `current`. A pinned release
+/// only has to differ; the latest release has to be newer.
+fn needs_install(current: &str, target: &str, pinned: bool) -> bool {
+    if pinned {
+        is_newer(current, target) || is_newer(target, current)
+    } else {
+        is_newer(current, target)
+    }
+}
+
+/// Exit code for `tr300 update --check` when a newer release exists.
+pub const UPDATE_AVAILABLE_EXIT_CODE: i32 = 10;
+
+/// Check for a newer release without installing anything. Returns an exit
+/// code: 0 = already current, [`UPDATE_AVAILABLE_EXIT_CODE`] = a newer
+/// release exists, 2 = the check itself failed.
+pub fn check(config: &Config) -> i32 {
+    if config.format == OutputFormat::Json {
+        return check_json(&config.release_source);
+    }
+
+    println!();
+    println!(
+        "  {} Checking for updates ({} channel)...",
+        cyan("*", config),
+        config.release_source.channel
+    );
+
+    let source = &config.release_source;
+    let latest = match fetch_latest_version(source) {
+        Ok(v) => v,
+        Err(e) => {
+            println!(
+                "  {} {}",
+                red(fail_icon(config), config),
+                red(&format!("Failed to check for updates: {}", e), config),
+            );
+            return 2;
+        }
+    };
+
+    let current = VERSION.to_string();
+    if !is_newer(&current, &latest) {
+        println!(
+            "  {} {}",
+            green(success_icon(config), config),
+            green(
+                &format!("Already on the latest version (v{})", current),
+                config
+            ),
+        );
+        return 0;
+    }
+
+    println!(
+        "  {} Update available: v{} {} v{}",
+        cyan("*", config),
+        current,
+        cyan("->", config),
+        latest,
+    );
+    let channel = detect_install_channel();
+    let strategies = build_strategy_list(channel);
+    println!(
+        "  {} Install channel: {}",
+        cyan("*", config),
+        channel.json_id()
+    );
+    if strategies.is_empty() {
+        println!(
+            "  {} No automatic update is available for this installation channel.",
+            cyan("*", config)
+        );
+        println!("  Official latest release: {}", RELEASES_PAGE);
+    } else {
+        let labels: Vec<&str> = strategies.iter().map(|s| s.label()).collect();
+        println!(
+            "  {} Would update via: {}",
+            cyan("*", config),
+            labels.join(", then ")
+        );
+        println!("  Run `tr300 update` to install it.");
+    }
+    UPDATE_AVAILABLE_EXIT_CODE
+}
+
+fn check_json(source: &ReleaseSource) -> i32 {
+    let channel = detect_install_channel();
+    let latest = match fetch_latest_version(source) {
+        Ok(v) => v,
+        Err(e) => {
+            let mut payload = serde_json::json!({
+                "action": "update-check",
+                "success": false,
+                "message": format!("Failed to check for updates: {}", e),
+                "current_version": VERSION,
+            });
+            inject_update_context(&mut payload, source, channel, true);
+            println!("{}", payload);
+            return 2;
+        }
+    };
+
+    let payload = update_check_payload(source, VERSION, &latest, channel);
+    println!("{}", payload);
+    if payload["update_available"] == true {
+        UPDATE_AVAILABLE_EXIT_CODE
+    } else {
+        0
+    }
+}
+
+/// Result of a successful `update --check`. `strategies` lists what
+/// `tr300 update` would try, in order; it is empty for an unknown channel,
+/// which is the case that needs a person to update by hand.
+fn update_check_payload(
+    source: &ReleaseSource,
+    current: &str,
+    latest: &str,
+    channel: InstallChannel,
+) -> serde_json::Value {
+    let update_available = is_newer(current, latest);
+    let strategies: Vec<&str> = build_strategy_list(channel)
+        .iter()
+        .map(|s| s.json_id())
+        .collect();
+    let message = if update_available {
+        format!("Update available: v{} -> v{}", current, latest)
+    } else {
+        "Already on the latest version".to_string()
+    };
+    let mut payload = serde_json::json!({
+        "action": "update-check",
+        "success": true,
+        "message": message,
+        "current_version": current,
+        "latest_version": latest,
+        "update_available": update_available,
+        "strategies": strategies,
+    });
+    inject_update_context(
+        &mut payload,
+        source,
+        channel,
+        update_available && strategies.is_empty(),
+    );
+    payload
+}
+
 // ── JSON output mode ───────────────────────────────────────────────
 
-fn run_json() -> i32 {
+fn run_json(source: &ReleaseSource, opts: &UpdateOptions) -> i32 {
     JSON_UPDATE_MODE.store(true, Ordering::Relaxed);
+    let pinned = opts.version.is_some();
     let channel = detect_install_channel();
-    let latest = match fetch_latest_version() {
+    let target = match resolve_target(source, opts) {
         Ok(v) => v,
         Err(e) => {
             let mut payload = serde_json::json!({
@@ -451,56 +674,85 @@ fn run_json() -> i32 {
                 "message": format!("Failed to check for updates: {}", e),
                 "current_version": VERSION,
             });
-            inject_update_context(&mut payload, channel, true);
+            inject_update_context(&mut payload, source, channel, true);
+            if let Some(version) = &opts.version {
+                payload["message"] = serde_json::Value::String(format!(
+                    "Failed to resolve the requested release: {}",
+                    e
+                ));
+                payload["target_version"] = serde_json::Value::String(version.clone());
+                mark_pinned(&mut payload);
+            }
             println!("{}", payload);
             return 2;
         }
     };
 
     let current = VERSION.to_string();
-    let update_available = is_newer(&current, &latest);
+    let update_available = needs_install(&current, &target, pinned);
 
-    if !update_available {
+    let (mut payload, exit_code) = if !update_available {
+        let message = if pinned {
+            format!("Already on v{}", current)
+        } else {
+            "Already on the latest version".to_string()
+        };
         let mut payload = serde_json::json!({
             "action": "update",
             "success": true,
-            "message": "Already on the latest version",
+            "message": message,
             "current_version": current,
-            "latest_version": latest,
+            "latest_version": target,
             "update_available": false,
         });
-        inject_update_context(&mut payload, channel, false);
-        println!("{}", payload);
-        return 0;
+        inject_update_context(&mut payload, source, channel, false);
+        (payload, 0)
+    } else {
+        let strategies = build_strategy_list(channel);
+        match execute_update(source, Payload::Download, &target, &strategies) {
+            Ok(used) => {
+                let mut payload = serde_json::json!({
+                    "action": "update",
+                    "success": true,
+                    "message": format!("Updated from v{} to v{}", current, target),
+                    "current_version": current,
+                    "latest_version": target,
+                    "update_available": true,
+                    "method": used.json_method(),
+                    "strategy": used.json_id(),
+                });
+                inject_update_context(&mut payload, source, channel, false);
+                (payload, 0)
+            }
+            Err(failure) => {
+                let mut payload =
+                    update_failure_payload(source, &current, &target, &failure, channel);
+                inject_update_context(&mut payload, source, channel, true);
+                (payload, 2)
+            }
+        }
+    };
+    if pinned {
+        mark_pinned(&mut payload);
     }
+    println!("{}", payload);
+    exit_code
+}
 
-    let strategies = build_strategy_list(channel);
-    match execute_update(&latest, &strategies) {
-        Ok(used) => {
-            let mut payload = serde_json::json!({
-                "action": "update",
-                "success": true,
-                "message": format!("Updated from v{} to v{}", current, latest),
-                "current_version": current,
-                "latest_version": latest,
-                "update_available": true,
-                "method": used.json_method(),
-                "strategy": used.json_id(),
-            });
-            inject_update_context(&mut payload, channel, false);
-            println!("{}", payload);
-            0
-        }
-        Err(failure) => {
-            let mut payload = update_failure_payload(&current, &latest, &failure, channel);
-            inject_update_context(&mut payload, channel, true);
-            println!("{}", payload);
-            2
+/// Pinned installs report the requested release as `target_version` and add
+/// `pinned: true`. `latest_version` keeps meaning "newest published release"
+/// for existing consumers, so it is moved rather than overloaded.
+fn mark_pinned(payload: &mut serde_json::Value) {
+    if let Some(obj) = payload.as_object_mut() {
+        if let Some(target) = obj.remove("latest_version") {
+            obj.insert("target_version".to_string(), target);
         }
+        obj.insert("pinned".to_string(), serde_json::Value::Bool(true));
     }
 }
 
 fn update_failure_payload(
+    source: &ReleaseSource,
     current: &str,
     latest: &str,
     failure: &UpdateFailure,
@@ -516,6 +768,7 @@ fn update_failure_payload(
                     AttemptKind::Skipped => "skipped",
                     AttemptKind::Failed => "failed",
                     AttemptKind::Blocked => "blocked",
+                    AttemptKind::Unverified => "unverified",
                 },
                 "message": record.message,
             })
@@ -543,12 +796,18 @@ fn update_failure_payload(
         "requires_user_action": true,
     });
     if let Some(asset) = recovery_asset_for_channel(channel) {
-        payload["exact_installer_url"] =
-            serde_json::Value::String(release_asset_url(latest, asset));
+        payload["exact_installer_url"] = serde_json::Value::String(source.asset_url(latest, asset));
     }
     if blocked {
         payload["official_releases_url"] = serde_json::Value::String(RELEASES_PAGE.to_string());
     }
+    if failure
+        .attempts
+        .iter()
+        .any(|attempt| attempt.kind == AttemptKind::Unverified)
+    {
+        payload["signature_rejected"] = serde_json::Value::Bool(true);
+    }
     payload
 }
 
@@ -573,15 +832,30 @@ fn inject_install_origin(_payload: &mut serde_json::Value) {
 
 fn inject_update_context(
     payload: &mut serde_json::Value,
+    source: &ReleaseSource,
     channel: InstallChannel,
     requires_user_action: bool,
 ) {
     inject_install_origin(payload);
     if let Some(obj) = payload.as_object_mut() {
+        if !source.is_default() {
+            obj.insert(
+                "release_source".to_string(),
+                serde_json::json!({
+                    "api_url": source.api_url,
+                    "asset_base_url": source.asset_base_url,
+                    "trusted_key": source.trusted_key,
+                }),
+            );
+        }
         obj.insert(
             "install_channel".to_string(),
             serde_json::Value::String(channel.json_id().to_string()),
         );
+        obj.insert(
+            "release_channel".to_string(),
+            serde_json::Value::String(source.channel.to_string()),
+        );
         obj.insert(
             "recovery_url".to_string(),
             serde_json::Value::String(RELEASES_PAGE.to_string()),
@@ -592,6 +866,485 @@ fn inject_update_context(
         );
     }
 }
+// ── Offline update (`--from`) ──────────────────────────────────────
+
+/// Release archive suffixes `update --from` unpacks. cargo-dist ships
+/// `.tar.xz` on Unix and `.zip` on Windows.
+const ARCHIVE_SUFFIXES: [&str; 4] = ["tar.xz", "tar.gz", "tgz", "zip"];
+
+/// The archive suffix of `file`, if it is a release archive.
+fn archive_suffix(file: &std::path::Path) -> Option<&'static str> {
+    let name = file.file_name()?.to_str()?.to_ascii_lowercase();
+    ARCHIVE_SUFFIXES
+        .into_iter()
+        .find(|suffix| name.ends_with(&format!(".{}", suffix)))
+}
+
+/// Install a release file already copied onto this machine. The file is
+/// staged, verified against its `.sha256` and `.minisig` sidecars, and
+/// installed through the channel's own strategy (the installer, or a binary
+/// swap for archive-based channels) with the usual post-install check.
+fn run_from(config: &Config, file: &std::path::Path, opts: &UpdateOptions) -> i32 {
+    let json = config.format == OutputFormat::Json;
+    let source = &config.release_source;
+    let channel = detect_install_channel();
+    let current = VERSION.to_string();
+    let from = file.display().to_string();
+    if json {
+        JSON_UPDATE_MODE.store(true, Ordering::Relaxed);
+    } else {
+        println!();
+        println!("  {} Inspecting {}...", cyan("*", config), from);
+    }
+
+    let refuse = |error: StrategyError, target: Option<&str>| -> i32 {
+        if json {
+            let mut payload = serde_json::json!({
+                "action": "update",
+                "success": false,
+                "message": error.message(),
+                "current_version": current,
+                "latest_version": target,
+            });
+            if matches!(error, StrategyError::Unverified(_)) {
+                payload["signature_rejected"] = serde_json::Value::Bool(true);
+            }
+            inject_update_context(&mut payload, source, channel, true);
+            mark_local(&mut payload, &from);
+            println!("{}", payload);
+        } else {
+            println!(
+                "  {} {}",
+                red(fail_icon(config), config),
+                red(error.message(), config),
+            );
+        }
+        2
+    };
+
+    let target = match local_release_version(source, file, opts.version.as_deref()) {
+        Ok(target) => target,
+        Err(error) => return refuse(error, None),
+    };
+    if !is_newer(&current, &target) && !is_newer(&target, &current) {
+        if json {
+            let mut payload = serde_json::json!({
+                "action": "update",
+                "success": true,
+                "message": format!("Already on v{}", current),
+                "current_version": current,
+                "latest_version": target,
+                "update_available": false,
+            });
+            inject_update_context(&mut payload, source, channel, false);
+            mark_local(&mut payload, &from);
+            println!("{}", payload);
+        } else {
+            println!(
+                "  {} {}",
+                green(success_icon(config), config),
+                green(&format!("Already on v{}", current), config),
+            );
+        }
+        return 0;
+    }
+    if is_newer(&target, &current) && opts.version.is_none() {
+        return refuse(
+            StrategyError::Preflight(format!(
+                "{} holds v{}, which is older than the running v{}; add `--pin {}` to install it anyway",
+                from, target, current, target
+            )),
+            Some(&target),
+        );
+    }
+    let strategies = match local_strategies(channel, file) {
+        Ok(strategies) => strategies,
+        Err(message) => return refuse(StrategyError::Preflight(message), Some(&target)),
+    };
+
+    if !json {
+        println!(
+            "  {} Installing v{} {} v{} from {}",
+            cyan("*", config),
+            current,
+            cyan("->", config),
+            target,
+            from
+        );
+        if let Some(strategy) = strategies.first() {
+            println!(
+                "  {} Updating via {}...",
+                cyan("*", config),
+                strategy.label()
+            );
+        }
+        println!();
+    }
+
+    let (mut payload, exit_code) =
+        match execute_update(source, Payload::Local(file), &target, &strategies) {
+            Ok(used) => {
+                if !json {
+                    println!();
+                    println!(
+                        "  {} {}",
+                        green(success_icon(config), config),
+                        green(
+                            &format!("Installed v{} via {}", target, used.label()),
+                            config
+                        ),
+                    );
+                    return 0;
+                }
+                let mut payload = serde_json::json!({
+                    "action": "update",
+                    "success": true,
+                    "message": format!("Updated from v{} to v{}", current, target),
+                    "current_version": current,
+                    "latest_version": target,
+                    "update_available": true,
+                    "method": used.json_method(),
+                    "strategy": used.json_id(),
+                });
+                inject_update_context(&mut payload, source, channel, false);
+                (payload, 0)
+            }
+            Err(failure) => {
+                if !json {
+                    print_update_failure(config, source, channel, &target, &failure);
+                    return 2;
+                }
+                let mut payload =
+                    update_failure_payload(source, &current, &target, &failure, channel);
+                inject_update_context(&mut payload, source, channel, true);
+                (payload, 2)
+            }
+        };
+    mark_local(&mut payload, &from);
+    println!("{}", payload);
+    exit_code
+}
+
+/// `--from` results report the file as `from` and its release as
+/// `target_version`, like pinned installs.
+fn mark_local(payload: &mut serde_json::Value, from: &str) {
+    if let Some(obj) = payload.as_object_mut() {
+        if let Some(target) = obj.remove("latest_version") {
+            if !target.is_null() {
+                obj.insert("target_version".to_string(), target);
+            }
+        }
+        obj.insert(
+            "from".to_string(),
+            serde_json::Value::String(from.to_string()),
+        );
+    }
+}
+
+/// The release inside `file`. An archive is staged, verified, unpacked, and
+/// asked for its `--version`; an installer cannot be inspected that way, so
+/// it needs `--pin X.Y.Z`, which post-install verification then holds it
+/// to. A `--pin` given with an archive must match what it contains.
+fn local_release_version(
+    source: &ReleaseSource,
+    file: &std::path::Path,
+    version: Option<&str>,
+) -> Result<String, StrategyError> {
+    let version = version.map(|v| v.trim_start_matches('v'));
+    if let Some(v) = version.filter(|v| !is_plain_release_version(v)) {
+        return Err(StrategyError::Preflight(format!(
+            "`{}` is not a release version; use --pin X.Y.Z",
+            v
+        )));
+    }
+    if !file.is_file() {
+        return Err(StrategyError::Preflight(format!(
+            "{} is not a file",
+            file.display()
+        )));
+    }
+    let Some(suffix) = archive_suffix(file) else {
+        let installer = file
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ["msi", "exe", "pkg"].contains(&ext.to_ascii_lowercase().as_str()));
+        if !installer {
+            return Err(StrategyError::Preflight(format!(
+                "{} is not a release archive (.tar.xz, .tar.gz, .zip) or installer (.msi, .exe, .pkg)",
+                file.display()
+            )));
+        }
+        return version.map(str::to_string).ok_or_else(|| {
+            StrategyError::Preflight(format!(
+                "add `--pin X.Y.Z` to name the release in {}; installers are checked against it after they run",
+                file.display()
+            ))
+        });
+    };
+
+    let origin = file.display().to_string();
+    let staged = StagedInstaller::new(suffix).map_err(|error| {
+        prelaunch_installer_io_error(
+            "creating a private update staging directory",
+            &std::env::temp_dir(),
+            &origin,
+            error,
+        )
+    })?;
+    let found = (|| -> Result<String, StrategyError> {
+        stage_local_file(source, file, staged.path())?;
+        let binary = extract_release_binary(staged.path(), &origin)?;
+        binary_version(&binary).ok_or_else(|| {
+            StrategyError::Runtime(format!(
+                "the tr300 binary in {} did not report a version",
+                origin
+            ))
+        })
+    })();
+    let _ = staged.close();
+    let found = found?;
+    match version {
+        Some(v) if !post_install_version_ok(&found, v) => Err(StrategyError::Preflight(format!(
+            "{} holds v{}, not v{}",
+            origin, found, v
+        ))),
+        _ => Ok(found),
+    }
+}
+
+/// Strategies that can install `file` without changing channel. Archives
+/// replace the binary of the archive-based channels (cargo-dist installers
+/// and Cargo); an installer must be the exact release asset the channel's
+/// own update would download, so a Corporate MSI never lands on a Global
+/// install. An unknown channel gets no strategy, as for network updates.
+fn local_strategies(
+    channel: InstallChannel,
+    file: &std::path::Path,
+) -> Result<Vec<UpdateStrategy>, String> {
+    let name = file
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    match channel {
+        InstallChannel::Unknown => Ok(Vec::new()),
+        InstallChannel::ShellInstaller
+        | InstallChannel::PowerShellInstaller
+        | InstallChannel::Cargo => {
+            if archive_suffix(file).is_some() {
+                Ok(vec![UpdateStrategy::LocalArchive])
+            } else {
+                Err(format!(
+                    "this {} installation updates from the release archive for this platform (.{}), not {}",
+                    channel.json_id(),
+                    RELEASE_ARCHIVE_SUFFIX,
+                    name
+                ))
+            }
+        }
+        _ => match recovery_asset_for_channel(channel) {
+            Some(asset) if name.eq_ignore_ascii_case(asset) => Ok(build_strategy_list(channel)),
+            Some(asset) => Err(format!(
+                "this {} installation updates from {}, not {}",
+                channel.json_id(),
+                asset,
+                name
+            )),
+            None => Ok(Vec::new()),
+        },
+    }
+}
+
+/// Unpack a staged release archive beside it with the system `tar` (GNU tar
+/// reads `.tar.*`; bsdtar, including the one built into Windows, also reads
+/// `.zip`) and return the `tr300` binary inside.
+fn extract_release_binary(
+    archive: &std::path::Path,
+    origin: &str,
+) -> Result<std::path::PathBuf, StrategyError> {
+    let dir = archive.with_file_name("extracted");
+    std::fs::create_dir(&dir).map_err(|error| {
+        prelaunch_installer_io_error("creating the extraction directory", &dir, origin, error)
+    })?;
+    run_command_status(
+        "tar",
+        &[
+            "-xf",
+            &archive.to_string_lossy(),
+            "-C",
+            &dir.to_string_lossy(),
+        ],
+    )?;
+    let name = if cfg!(windows) { "tr300.exe" } else { "tr300" };
+    let mut dirs = vec![dir];
+    // cargo-dist archives hold `tr300-<target>/tr300`; look one level down.
+    for _ in 0..2 {
+        let mut next = Vec::new();
+        for dir in dirs {
+            let candidate = dir.join(name);
+            if candidate.is_file() {
+                return Ok(candidate);
+            }
+            if let Ok(entries) = std::fs::read_dir(&dir) {
+                next.extend(
+                    entries
+                        .flatten()
+                        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_dir()))
+                        .map(|entry| entry.path()),
+                );
+            }
+        }
+        dirs = next;
+    }
+    Err(StrategyError::Runtime(format!(
+        "{} does not contain a {} binary",
+        origin, name
+    )))
+}
+
+/// Install the binary from a local release archive over the running one.
+fn try_local_archive(
+    source: &ReleaseSource,
+    file: &std::path::Path,
+    latest: &str,
+) -> Result<(), StrategyError> {
+    let origin = file.display().to_string();
+    let suffix = archive_suffix(file)
+        .ok_or_else(|| StrategyError::Preflight(format!("{} is not a release archive", origin)))?;
+    install_from_archive(suffix, &origin, latest, |staged| {
+        stage_local_file(source, file, staged)
+    })
+}
+
+/// Download the cargo-dist archive for this target and install its binary
+/// over the running one. This is the file the shell and PowerShell
+/// installers unpack, so checking its own `.sha256` and `.minisig` covers
+/// the bytes that land on disk, and `--asset-base-url` mirrors it like any
+/// other asset.
+fn try_release_archive(source: &ReleaseSource, latest: &str) -> Result<(), StrategyError> {
+    let url = source.asset_url(latest, RELEASE_ARCHIVE_ASSET);
+    install_from_archive(RELEASE_ARCHIVE_SUFFIX, &url, latest, |staged| {
+        download_release_archive(source, &url, staged)
+    })?;
+    refresh_cargo_dist_receipt(latest);
+    Ok(())
+}
+
+fn download_release_archive(
+    source: &ReleaseSource,
+    url: &str,
+    staged: &std::path::Path,
+) -> Result<(), StrategyError> {
+    eprintln!("  Downloading {}...", RELEASE_ARCHIVE_ASSET);
+    download_to_file(url, staged)?;
+    verify_checksum(staged, url)?;
+    verify_signature(staged, url, source)
+}
+
+/// Put a verified release archive in private staging with `stage`, then
+/// swap the binary inside it in for the installed one.
+fn install_from_archive(
+    suffix: &str,
+    origin: &str,
+    latest: &str,
+    stage: impl FnOnce(&std::path::Path) -> Result<(), StrategyError>,
+) -> Result<(), StrategyError> {
+    let staged = StagedInstaller::new(suffix).map_err(|error| {
+        prelaunch_installer_io_error(
+            "creating a private update staging directory",
+            &std::env::temp_dir(),
+            origin,
+            error,
+        )
+    })?;
+
+    let result = (|| -> Result<(), StrategyError> {
+        stage(staged.path())?;
+        let binary = extract_release_binary(staged.path(), origin)?;
+        let live = installed_binary_path().ok_or_else(|| {
+            StrategyError::Preflight("could not resolve the installed tr300 binary".into())
+        })?;
+        replace_installed_binary(&live, &binary, latest)
+    })();
+
+    finish_staged_attempt(staged, result)
+}
+
+/// Put `new` at `live`, the installed binary path, and check that it reports
+/// `latest`. `new` is copied to a sibling temp file and renamed into place,
+/// so the path never holds a partial binary; the old binary is held aside
+/// and put back if the swap or the version check fails. On Windows the
+/// running image was already moved aside by `with_windows_live_image_handoff`.
+///
+/// `live` is resolved before the swap: on Linux `current_exe()` names the
+/// replaced file `tr300 (deleted)` afterwards.
+fn replace_installed_binary(
+    live: &std::path::Path,
+    new: &std::path::Path,
+    latest: &str,
+) -> Result<(), StrategyError> {
+    let pid = std::process::id();
+    let partial = live.with_file_name(format!(".tr300-update-{}.partial", pid));
+    let old = live.with_file_name(format!(".tr300-update-{}.old", pid));
+    let io_failure = |error: std::io::Error| {
+        let message = format!(
+            "could not replace {}: {}. The existing installation was left in place",
+            live.display(),
+            error
+        );
+        if likely_endpoint_policy_error(&error) {
+            StrategyError::PolicyBlocked(message)
+        } else {
+            StrategyError::Runtime(message)
+        }
+    };
+
+    let copied = std::fs::copy(new, &partial).map(drop);
+    #[cfg(unix)]
+    let copied = copied.and_then(|()| {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&partial, std::fs::Permissions::from_mode(0o755))
+    });
+    if let Err(error) = copied {
+        let _ = std::fs::remove_file(&partial);
+        return Err(io_failure(error));
+    }
+    let held = match std::fs::rename(live, &old) {
+        Ok(()) => true,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => false,
+        Err(error) => {
+            let _ = std::fs::remove_file(&partial);
+            return Err(io_failure(error));
+        }
+    };
+    let restore = || {
+        if held {
+            let _ = std::fs::rename(&old, live);
+        }
+    };
+    if let Err(error) = std::fs::rename(&partial, live) {
+        let _ = std::fs::remove_file(&partial);
+        restore();
+        return Err(io_failure(error));
+    }
+
+    let verified = match binary_version(live) {
+        Some(installed) if post_install_version_ok(&installed, latest) => Ok(()),
+        Some(installed) => Err(StrategyError::Runtime(format!(
+            "the binary installed from the archive reports v{} (expected v{}); the previous binary was restored",
+            installed, latest
+        ))),
+        None => Err(StrategyError::Runtime(format!(
+            "the binary installed from the archive did not run to report v{}; the previous binary was restored",
+            latest
+        ))),
+    };
+    if verified.is_err() {
+        restore();
+    } else if held {
+        let _ = std::fs::remove_file(&old);
+    }
+    verified
+}
 
 // ── Version check ──────────────────────────────────────────────────
 
@@ -599,7 +1352,18 @@ fn inject_update_context(
 /// message. The most common intermittent failure is GitHub's unauthenticated
 /// rate limit (60 requests/hour per IP) — worth naming explicitly so a user
 /// who "ran update and it didn't work" knows to just wait.
-fn classify_fetch_error(e: ureq::Error) -> String {
+fn classify_fetch_error(e: ureq::Error, source: &ReleaseSource) -> String {
+    if !source.is_github_api() {
+        return match e {
+            ureq::Error::Status(code, _) => format!(
+                "Release mirror returned HTTP {} when checking for updates",
+                code
+            ),
+            ureq::Error::Transport(t) => {
+                format!("Network error reaching the release mirror: {}", t)
+            }
+        };
+    }
     match e {
         ureq::Error::Status(code, ref resp) => {
             http_status_message(code, resp.header("x-ratelimit-remaining"))
@@ -621,36 +1385,111 @@ fn http_status_message(code: u16, ratelimit_remaining: Option<&str>) -> String {
     }
 }
 
-/// Fetch the latest version tag from GitHub releases API.
-fn fetch_latest_version() -> Result<String, String> {
+/// Fetch the latest version tag from GitHub releases API.
+fn fetch_latest_version(source: &ReleaseSource) -> Result<String, String> {
+    match source.channel {
+        ReleaseChannel::Beta => fetch_newest_beta_version(source),
+        _ => fetch_release_version(source, &source.latest_url(), "Latest release"),
+    }
+}
+
+/// Newest non-draft release in the full list, prereleases included. Taking
+/// the maximum means a beta install moves from `X.Y.Z-rc.N` to `X.Y.Z` once
+/// it ships, but never back to an older stable release.
+fn fetch_newest_beta_version(source: &ReleaseSource) -> Result<String, String> {
+    let body = fetch_release_json(source, &source.releases_url(), "Release list")?;
+    let releases = body
+        .as_array()
+        .ok_or("Release list response is not a JSON array")?;
+    newest_release_version(releases).ok_or_else(|| "No releases were found".to_string())
+}
+
+fn newest_release_version(releases: &[serde_json::Value]) -> Option<String> {
+    releases
+        .iter()
+        .filter(|release| release["draft"] != true)
+        .filter_map(|release| release_tag_version(release, "Release").ok())
+        .reduce(|newest, version| {
+            if is_newer(&newest, &version) {
+                version
+            } else {
+                newest
+            }
+        })
+}
+
+/// Confirm that a pinned `version` names a published release before any
+/// strategy runs, so a typo fails here rather than inside an installer.
+fn fetch_pinned_version(source: &ReleaseSource, version: &str) -> Result<String, String> {
+    let version = version.strip_prefix('v').unwrap_or(version);
+    if !is_plain_release_version(version) {
+        return Err(format!(
+            "expected a release version such as 4.1.0, got {version:?}"
+        ));
+    }
+    let found = fetch_release_version(
+        source,
+        &source.tag_url(version),
+        &format!("Release v{version}"),
+    )?;
+    if found != version {
+        let answered = if source.is_github_api() {
+            "GitHub".to_string()
+        } else {
+            format!("The release mirror at {}", source.api_url)
+        };
+        return Err(format!(
+            "{answered} returned release v{found} when asked for v{version}"
+        ));
+    }
+    Ok(found)
+}
+
+/// Fetch one release object from the releases API and return its validated
+/// version (tag without the leading `v`). `label` names the release in errors.
+fn fetch_release_version(source: &ReleaseSource, url: &str, label: &str) -> Result<String, String> {
+    release_tag_version(&fetch_release_json(source, url, label)?, label)
+}
+
+fn fetch_release_json(
+    source: &ReleaseSource,
+    url: &str,
+    label: &str,
+) -> Result<serde_json::Value, String> {
     let agent = ureq::AgentBuilder::new()
         .timeout(std::time::Duration::from_secs(15))
         .build();
 
     let mut request = agent
-        .get(RELEASES_URL)
+        .get(url)
         .set("User-Agent", &format!("tr300/{}", VERSION))
         .set("Accept", "application/vnd.github+json");
-    if let Some(token) = github_api_token() {
+    // Never hand a GitHub token to a mirror.
+    if let Some(token) = github_api_token().filter(|_| source.is_github_api()) {
         request = request.set("Authorization", &format!("Bearer {token}"));
     }
-    let resp = request.call().map_err(classify_fetch_error)?;
+    let resp = request.call().map_err(|e| match e {
+        ureq::Error::Status(404, _) => format!(
+            "{label} was not found (HTTP 404 from {url}); published versions are listed at {RELEASES_PAGE}"
+        ),
+        e => classify_fetch_error(e, source),
+    })?;
 
-    let body: serde_json::Value = resp
-        .into_json()
-        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    resp.into_json()
+        .map_err(|e| format!("Failed to parse response: {}", e))
+}
 
-    let tag = body["tag_name"]
+/// The version named by a release object's `tag_name`.
+fn release_tag_version(release: &serde_json::Value, label: &str) -> Result<String, String> {
+    let tag = release["tag_name"]
         .as_str()
         .ok_or("Missing tag_name in response")?;
 
     // Strip leading 'v' if present, then reject malformed release tags rather
     // than letting a lossy numeric parser silently reinterpret them.
     let version = tag.strip_prefix('v').unwrap_or(tag);
-    if parse_numeric_version(version).is_none() {
-        return Err(format!(
-            "Latest release has an invalid version tag: {tag:?}"
-        ));
+    if parse_numeric_version(strip_prerelease_metadata(version)).is_none() {
+        return Err(format!("{label} has an invalid version tag: {tag:?}"));
     }
     Ok(version.to_string())
 }
@@ -692,9 +1531,8 @@ fn strip_prerelease_metadata(v: &str) -> &str {
 /// previous stable patch (`3.15.2-rc.1` > `3.15.1`), and a stable
 /// release IS newer than any prerelease of the same triple
 /// (`3.15.2` > `3.15.2-rc.1`). Two prereleases of the same triple are
-/// treated as equal — we don't promote within a prerelease line because
-/// GitHub's `/releases/latest` endpoint already filters prereleases
-/// out, so the case is theoretical.
+/// ordered by their identifiers (`3.15.2-rc.2` > `3.15.2-rc.1`), which
+/// the beta channel relies on to move along a prerelease line.
 fn is_newer(current: &str, latest: &str) -> bool {
     let current_stripped = strip_prerelease_metadata(current);
     let latest_stripped = strip_prerelease_metadata(latest);
@@ -722,13 +1560,50 @@ fn is_newer(current: &str, latest: &str) -> bool {
     // Numeric parts equal. If the current binary is on a prerelease
     // of the same triple (e.g. `3.15.2-rc.1`) and the latest is the
     // stable release (`3.15.2`), the stable IS newer per semver
-    // ordering. Inverse case (current stable, latest prerelease)
-    // shouldn't happen via GitHub's `latest` endpoint.
+    // ordering. A prerelease of the current stable triple is older.
+    if let (Some(c), Some(l)) = (prerelease_part(current), prerelease_part(latest)) {
+        return compare_prerelease(c, l) == std::cmp::Ordering::Less;
+    }
     let current_has_suffix = current.len() != current_stripped.len();
     let latest_has_suffix = latest.len() != latest_stripped.len();
     current_has_suffix && !latest_has_suffix
 }
 
+/// The prerelease identifiers of a version (`rc.1` in `4.3.0-rc.1+sha.1`).
+fn prerelease_part(version: &str) -> Option<&str> {
+    let suffix = &version[strip_prerelease_metadata(version).len()..];
+    suffix
+        .split('+')
+        .next()
+        .and_then(|pre| pre.strip_prefix('-'))
+        .filter(|pre| !pre.is_empty())
+}
+
+/// Semver prerelease precedence: identifiers left to right, numeric ones
+/// numerically and below alphanumeric ones, and a longer list wins a tie.
+fn compare_prerelease(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+    loop {
+        let order = match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => match (x.parse::<u64>(), y.parse::<u64>()) {
+                (Ok(x), Ok(y)) => x.cmp(&y),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => x.cmp(y),
+            },
+        };
+        if order != Ordering::Equal {
+            return order;
+        }
+    }
+}
+
 fn parse_numeric_version(version: &str) -> Option<Vec<u64>> {
     let parts: Vec<u64> = version
         .split('.')
@@ -738,6 +1613,90 @@ fn parse_numeric_version(version: &str) -> Option<Vec<u64>> {
     (!parts.is_empty()).then_some(parts)
 }
 
+// ── Passive update notice (`--update-notice`) ──────────────────────
+
+fn notice_cache_dir() -> Option<std::path::PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("tr300"))
+}
+
+/// Stamp recording the last background release check, and the latest
+/// release version written by the last successful one. Each release channel
+/// has its own pair so switching channels never shows the other's release.
+fn notice_files(channel: ReleaseChannel) -> (String, String) {
+    match channel {
+        ReleaseChannel::Stable => ("update-check.stamp".into(), "latest-release".into()),
+        other => (
+            format!("update-check-{}.stamp", other),
+            format!("latest-{}-release", other),
+        ),
+    }
+}
+
+/// The newer release to mention under the report, if the last background
+/// check found one. Only the cache is read here: when it is older than
+/// `config.update_notice` hours a detached `tr300 update-notice-refresh` is
+/// started to renew it for a later run, and the report does not wait for it.
+pub fn update_notice(config: &Config) -> Option<String> {
+    let hours = config.update_notice?;
+    let dir = notice_cache_dir()?;
+    let (stamp, cache) = notice_files(config.release_source.channel);
+    if crate::install::autorun::claim_interval_slot_at(&dir.join(stamp), hours) {
+        let _ = spawn_notice_refresh(&config.release_source);
+    }
+    let cached = std::fs::read_to_string(dir.join(cache)).ok()?;
+    newer_cached_release(VERSION, &cached)
+}
+
+/// `cached` if it names a well-formed release newer than `current`. The cache
+/// outlives updates, so it is compared against the running version each time.
+fn newer_cached_release(current: &str, cached: &str) -> Option<String> {
+    let cached = cached.trim();
+    parse_numeric_version(strip_prerelease_metadata(cached))?;
+    is_newer(current, cached).then(|| cached.to_string())
+}
+
+fn spawn_notice_refresh(source: &ReleaseSource) -> std::io::Result<()> {
+    let mut command = Command::new(std::env::current_exe()?);
+    command.arg("update-notice-refresh");
+    if source.api_url != crate::config::DEFAULT_RELEASES_API_URL {
+        command.arg("--releases-url").arg(&source.api_url);
+    }
+    command.arg("--channel").arg(source.channel.to_string());
+    command
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+
+        const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+    command.spawn().map(|_| ())
+}
+
+/// Background half of `--update-notice`: ask the release host for the latest
+/// version and cache it. Returns an exit code (0 = cached, 2 = error).
+pub fn refresh_update_notice(source: &ReleaseSource) -> i32 {
+    let Some(dir) = notice_cache_dir() else {
+        return 2;
+    };
+    let Ok(latest) = fetch_latest_version(source) else {
+        return 2;
+    };
+    let (_, cache) = notice_files(source.channel);
+    let partial = dir.join(format!("{}-{}.partial", cache, std::process::id()));
+    let written = std::fs::create_dir_all(&dir)
+        .and_then(|_| std::fs::write(&partial, format!("{}\n", latest)))
+        .and_then(|_| std::fs::rename(&partial, dir.join(cache)));
+    if written.is_err() {
+        let _ = std::fs::remove_file(&partial);
+        return 2;
+    }
+    0
+}
+
 // ── Strategy ordering ───────────────────────────────────────────────
 
 fn tool_exists(tool: &str) -> bool {
@@ -756,12 +1715,8 @@ fn build_strategy_list(channel: InstallChannel) -> Vec<UpdateStrategy> {
         InstallChannel::ExeGlobal => vec![UpdateStrategy::ExeGlobal],
         InstallChannel::ExeCorporate => vec![UpdateStrategy::ExeCorporate],
         InstallChannel::Cargo => vec![UpdateStrategy::Cargo],
-        InstallChannel::PowerShellInstaller => vec![
-            UpdateStrategy::InstallerPwsh,
-            UpdateStrategy::InstallerPowerShell,
-        ],
-        InstallChannel::ShellInstaller => {
-            vec![UpdateStrategy::InstallerCurl, UpdateStrategy::InstallerWget]
+        InstallChannel::PowerShellInstaller | InstallChannel::ShellInstaller => {
+            vec![UpdateStrategy::ReleaseArchive]
         }
         InstallChannel::MacPkg => vec![UpdateStrategy::MacPkg],
         InstallChannel::Unknown => Vec::new(),
@@ -786,10 +1741,14 @@ fn rustup_update_stable_best_effort() {
 }
 
 fn execute_update(
+    source: &ReleaseSource,
+    payload: Payload,
     latest: &str,
     strategies: &[UpdateStrategy],
 ) -> Result<UpdateStrategy, UpdateFailure> {
-    execute_update_with(latest, strategies, try_strategy)
+    execute_update_with(latest, strategies, |strategy, latest| {
+        try_strategy(source, payload, strategy, latest)
+    })
 }
 
 fn execute_update_with<F>(
@@ -832,36 +1791,80 @@ where
                 // freeze and does not make an unsafe direct overwrite sound.
                 break;
             }
+            Err(StrategyError::Unverified(message)) => {
+                eprintln!("  · {} refused: {}", strategy.label(), message);
+                attempts.push(AttemptRecord {
+                    strategy,
+                    kind: AttemptKind::Unverified,
+                    message,
+                });
+                break;
+            }
         }
     }
     Err(UpdateFailure { attempts })
 }
 
-fn try_strategy(strategy: UpdateStrategy, latest: &str) -> Result<(), StrategyError> {
+fn try_strategy(
+    source: &ReleaseSource,
+    payload: Payload,
+    strategy: UpdateStrategy,
+    latest: &str,
+) -> Result<(), StrategyError> {
     #[cfg(windows)]
     if matches!(
         strategy,
         UpdateStrategy::MsiGlobal | UpdateStrategy::ExeGlobal
     ) {
-        return with_elevated_windows_live_image_handoff(strategy, latest);
+        return with_elevated_windows_live_image_handoff(source, payload, strategy, latest);
     }
 
     #[cfg(windows)]
     if matches!(
         strategy,
         UpdateStrategy::Cargo
-            | UpdateStrategy::InstallerPowerShell
-            | UpdateStrategy::InstallerPwsh
+            | UpdateStrategy::ReleaseArchive
             | UpdateStrategy::MsiCorporate
             | UpdateStrategy::ExeCorporate
+            | UpdateStrategy::LocalArchive
     ) {
-        return with_windows_live_image_handoff(|| try_strategy_inner(strategy, latest));
+        return with_windows_live_image_handoff(|| {
+            try_strategy_inner(source, payload, strategy, latest)
+        });
     }
 
-    try_strategy_inner(strategy, latest)
+    #[cfg(not(windows))]
+    {
+        with_previous_binary_kept(|| try_strategy_inner(source, payload, strategy, latest))
+    }
+    #[cfg(windows)]
+    {
+        try_strategy_inner(source, payload, strategy, latest)
+    }
 }
 
-fn try_strategy_inner(strategy: UpdateStrategy, latest: &str) -> Result<(), StrategyError> {
+fn try_strategy_inner(
+    source: &ReleaseSource,
+    payload: Payload,
+    strategy: UpdateStrategy,
+    latest: &str,
+) -> Result<(), StrategyError> {
+    if let Payload::Local(file) = payload {
+        match strategy {
+            UpdateStrategy::LocalArchive => return try_local_archive(source, file, latest),
+            UpdateStrategy::MsiGlobal
+            | UpdateStrategy::MsiCorporate
+            | UpdateStrategy::ExeGlobal
+            | UpdateStrategy::ExeCorporate
+            | UpdateStrategy::MacPkg => {}
+            _ => {
+                return Err(StrategyError::Preflight(format!(
+                    "{} needs the network; offline updates of this channel use the release archive",
+                    strategy.label()
+                )))
+            }
+        }
+    }
     match strategy {
         UpdateStrategy::Cargo => {
             rustup_update_stable_best_effort();
@@ -881,35 +1884,19 @@ fn try_strategy_inner(strategy: UpdateStrategy, latest: &str) -> Result<(), Stra
             // this channel on mismatch; never switch to a prebuilt installer.
             verify_cargo_post_install(latest)
         }
-        UpdateStrategy::InstallerCurl => {
-            try_installer_curl(latest)?;
-            verify_installer_post_install(latest, "shell installer")
-        }
-        UpdateStrategy::InstallerWget => {
-            try_installer_wget(latest)?;
-            verify_installer_post_install(latest, "shell installer")
-        }
-        UpdateStrategy::InstallerPowerShell => {
-            try_installer_powershell("powershell", latest)?;
-            verify_installer_post_install(latest, "PowerShell installer")
-        }
-        UpdateStrategy::InstallerPwsh => {
-            try_installer_powershell("pwsh", latest)?;
-            verify_installer_post_install(latest, "PowerShell installer")
-        }
-        UpdateStrategy::MsiGlobal => {
-            try_msi_install(&release_asset_url(latest, MSI_GLOBAL_ASSET), latest)
-        }
+        UpdateStrategy::ReleaseArchive => try_release_archive(source, latest),
+        UpdateStrategy::MsiGlobal => try_msi_install(source, payload, MSI_GLOBAL_ASSET, latest),
         UpdateStrategy::MsiCorporate => {
-            try_msi_install(&release_asset_url(latest, MSI_CORPORATE_ASSET), latest)
-        }
-        UpdateStrategy::ExeGlobal => {
-            try_exe_install(&release_asset_url(latest, EXE_GLOBAL_ASSET), latest)
+            try_msi_install(source, payload, MSI_CORPORATE_ASSET, latest)
         }
+        UpdateStrategy::ExeGlobal => try_exe_install(source, payload, EXE_GLOBAL_ASSET, latest),
         UpdateStrategy::ExeCorporate => {
-            try_exe_install(&release_asset_url(latest, EXE_CORPORATE_ASSET), latest)
+            try_exe_install(source, payload, EXE_CORPORATE_ASSET, latest)
         }
-        UpdateStrategy::MacPkg => try_macos_pkg_install(latest),
+        UpdateStrategy::MacPkg => try_macos_pkg_install(source, payload, latest),
+        UpdateStrategy::LocalArchive => Err(StrategyError::Preflight(
+            "a local release archive needs `update --from PATH`".into(),
+        )),
     }
 }
 
@@ -938,17 +1925,24 @@ where
 /// transaction with an elevated token and never changes installer channels.
 #[cfg(windows)]
 fn with_elevated_windows_live_image_handoff(
+    source: &ReleaseSource,
+    payload: Payload,
     strategy: UpdateStrategy,
     latest: &str,
 ) -> Result<(), StrategyError> {
     let handoff = WindowsLiveImageHandoff::plan()?;
-    let exit_code = launch_elevated_windows_update_worker(strategy, latest, &handoff.backup)?;
+    let exit_code =
+        launch_elevated_windows_update_worker(source, payload, strategy, latest, &handoff.backup)?;
     match exit_code {
         0 => verify_post_install(latest).map_err(StrategyError::Runtime),
         3 => Err(StrategyError::PolicyBlocked(format!(
             "the elevated {} update worker was blocked by Windows or endpoint policy. Its safe transaction attempts to retain or restore the old executable; verify `tr300 --version` before retrying",
             strategy.label()
         ))),
+        4 => Err(StrategyError::Unverified(format!(
+            "the elevated {} update worker refused the installer because its signature is missing or invalid; the installation was not changed",
+            strategy.label()
+        ))),
         code => Err(StrategyError::Runtime(format!(
             "the elevated {} update worker exited with code {code}, so the transaction was not accepted as successful. It attempts to retain or restore the old executable; verify `tr300 --version` and use the matching tagged installer if the failure persists",
             strategy.label()
@@ -959,14 +1953,21 @@ fn with_elevated_windows_live_image_handoff(
 /// Hidden elevated-worker entry point. Only the two Global strategies are
 /// accepted; the version must be a plain numeric release, the current product
 /// registration must prove the same channel, and the backup must be a strict
-/// private sibling of the running Program Files image.
+/// private sibling of the running Program Files image. With `from`, the
+/// installer is a local file the worker stages and verifies itself.
 #[cfg(windows)]
-pub fn run_windows_update_worker(strategy_id: &str, latest: &str, backup: &std::path::Path) -> i32 {
+pub fn run_windows_update_worker(
+    source: &ReleaseSource,
+    strategy_id: &str,
+    latest: &str,
+    backup: &std::path::Path,
+    from: Option<&std::path::Path>,
+) -> i32 {
     JSON_UPDATE_MODE.store(true, Ordering::Relaxed);
     let Some(strategy) = UpdateStrategy::from_global_worker_id(strategy_id) else {
         return 2;
     };
-    if !is_worker_release_version(latest) {
+    if !is_release_version(latest) {
         return 2;
     }
     let expected_origin = match strategy {
@@ -996,7 +1997,8 @@ pub fn run_windows_update_worker(strategy_id: &str, latest: &str, backup: &std::
             return strategy_error_exit_code(&error);
         }
     };
-    match handoff.finish(try_strategy_inner(strategy, latest)) {
+    let payload = from.map_or(Payload::Download, Payload::Local);
+    match handoff.finish(try_strategy_inner(source, payload, strategy, latest)) {
         Ok(()) => 0,
         Err(error) => {
             eprintln!("  · elevated update worker failed: {}", error.message());
@@ -1007,24 +2009,45 @@ pub fn run_windows_update_worker(strategy_id: &str, latest: &str, backup: &std::
 
 #[cfg(not(windows))]
 pub fn run_windows_update_worker(
+    _source: &ReleaseSource,
     _strategy_id: &str,
     _latest: &str,
     _backup: &std::path::Path,
+    _from: Option<&std::path::Path>,
 ) -> i32 {
     2
 }
 
+/// A release as the updater may install it: plain `X.Y.Z`, or a beta-channel
+/// prerelease such as `X.Y.Z-rc.1` with dot-separated alphanumeric
+/// identifiers. The only forms accepted on the elevated worker command line.
+#[cfg(any(windows, test))]
+fn is_release_version(version: &str) -> bool {
+    let (plain, prerelease) = match version.split_once('-') {
+        Some((plain, prerelease)) => (plain, Some(prerelease)),
+        None => (version, None),
+    };
+    version.len() <= 64
+        && is_plain_release_version(plain)
+        && prerelease.is_none_or(|prerelease| {
+            prerelease
+                .split('.')
+                .all(|id| !id.is_empty() && id.bytes().all(|byte| byte.is_ascii_alphanumeric()))
+        })
+}
+
 #[cfg(windows)]
 fn strategy_error_exit_code(error: &StrategyError) -> i32 {
-    if matches!(error, StrategyError::PolicyBlocked(_)) {
-        3
-    } else {
-        2
+    match error {
+        StrategyError::PolicyBlocked(_) => 3,
+        StrategyError::Unverified(_) => 4,
+        _ => 2,
     }
 }
 
-#[cfg(any(windows, test))]
-fn is_worker_release_version(version: &str) -> bool {
+/// A plain `X.Y.Z` release number: the only form accepted for pinned
+/// installs.
+fn is_plain_release_version(version: &str) -> bool {
     version.len() <= 64
         && version.split('.').count() == 3
         && version
@@ -1034,6 +2057,8 @@ fn is_worker_release_version(version: &str) -> bool {
 
 #[cfg(windows)]
 fn launch_elevated_windows_update_worker(
+    source: &ReleaseSource,
+    payload: Payload,
     strategy: UpdateStrategy,
     latest: &str,
     backup: &std::path::Path,
@@ -1053,7 +2078,7 @@ fn launch_elevated_windows_update_worker(
     if !matches!(
         strategy,
         UpdateStrategy::MsiGlobal | UpdateStrategy::ExeGlobal
-    ) || !is_worker_release_version(latest)
+    ) || !is_release_version(latest)
     {
         return Err(StrategyError::Preflight(
             "refused an invalid elevated Global update request".to_string(),
@@ -1072,12 +2097,35 @@ fn launch_elevated_windows_update_worker(
                 .to_string(),
         )
     })?;
-    let parameters = format!(
+    let mut parameters = format!(
         "update-worker --update-strategy {} --update-version {} --update-backup {}",
         strategy.json_id(),
         latest,
         windows_quote_command_arg(backup_text)
     );
+    // The elevated worker does not inherit this process's environment, so a
+    // mirror has to travel on the command line. Only assets are downloaded
+    // there; the release was already resolved here.
+    if source.asset_base_url != ReleaseSource::default().asset_base_url {
+        parameters.push_str(" --asset-base-url ");
+        parameters.push_str(&windows_quote_command_arg(&source.asset_base_url));
+    }
+    if let Some(key) = &source.trusted_key {
+        parameters.push_str(" --trusted-key ");
+        parameters.push_str(&windows_quote_command_arg(key));
+    }
+    // An offline installer is re-staged and re-verified by the worker; only
+    // the user's file path crosses the elevation boundary.
+    if let Payload::Local(file) = payload {
+        let file = file.to_str().ok_or_else(|| {
+            StrategyError::Preflight(
+                "the offline installer path cannot be represented safely in the worker command line"
+                    .to_string(),
+            )
+        })?;
+        parameters.push_str(" --from ");
+        parameters.push_str(&windows_quote_command_arg(file));
+    }
     let verb: Vec<u16> = std::ffi::OsStr::new("runas")
         .encode_wide()
         .chain(Some(0))
@@ -1301,6 +2349,11 @@ impl WindowsLiveImageHandoff {
     fn finish(self, result: Result<(), StrategyError>) -> Result<(), StrategyError> {
         match result {
             Ok(()) => {
+                // Keep the old image for `update --rollback`; only when that
+                // fails does the new binary delete it after this process exits.
+                if self.keep_as_previous().is_ok() {
+                    return Ok(());
+                }
                 self.spawn_cleanup().map_err(|error| {
                     StrategyError::Runtime(format!(
                         "the update installed and verified the new binary, but could not start safe cleanup for {}: {error}",
@@ -1319,6 +2372,23 @@ impl WindowsLiveImageHandoff {
         }
     }
 
+    /// Move the renamed old image into the rollback slot. Windows permits
+    /// renaming an executing image, so this works while the updater runs.
+    fn keep_as_previous(&self) -> std::io::Result<()> {
+        let previous = previous_binary_path(&self.original).ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "executable has no parent directory",
+            )
+        })?;
+        match std::fs::remove_file(&previous) {
+            Ok(()) => {}
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error),
+        }
+        std::fs::rename(&self.backup, &previous)
+    }
+
     fn rollback(&self) -> std::io::Result<()> {
         if self.original.exists() {
             std::fs::remove_file(&self.original)?;
@@ -1405,6 +2475,243 @@ pub fn cleanup_windows_update_backup(_backup: &std::path::Path) -> i32 {
     2
 }
 
+// ── Rollback to the previous binary ────────────────────────────────
+
+/// The binary replaced by the last successful update is kept beside the live
+/// one under this name. The leading dot keeps it out of shell completion.
+const PREVIOUS_BINARY_NAME: &str = if cfg!(windows) {
+    ".tr300-previous.exe"
+} else {
+    ".tr300-previous"
+};
+
+/// Rollback slot for the binary at `live`.
+pub(crate) fn previous_binary_path(live: &std::path::Path) -> Option<std::path::PathBuf> {
+    live.parent()
+        .map(|parent| parent.join(PREVIOUS_BINARY_NAME))
+}
+
+/// Keep a copy of the running binary as the rollback slot around a Unix
+/// strategy. Unix installers replace the file beneath the running process,
+/// so no rename is needed first (compare `WindowsLiveImageHandoff`).
+#[cfg(not(windows))]
+fn with_previous_binary_kept<F>(attempt: F) -> Result<(), StrategyError>
+where
+    F: FnOnce() -> Result<(), StrategyError>,
+{
+    match std::env::current_exe() {
+        Ok(live) => keep_previous_around(&live, attempt),
+        Err(_) => attempt(),
+    }
+}
+
+/// Copy `live` aside, run `attempt`, and promote the copy to the rollback
+/// slot only after the attempt has verified the new version. A copy that
+/// cannot be made (for example in a root-owned `/usr/local/bin`) only costs
+/// rollback; it never blocks the update.
+#[cfg(any(not(windows), test))]
+fn keep_previous_around<F>(live: &std::path::Path, attempt: F) -> Result<(), StrategyError>
+where
+    F: FnOnce() -> Result<(), StrategyError>,
+{
+    let Some(previous) = previous_binary_path(live) else {
+        return attempt();
+    };
+    let staged = previous.with_file_name(format!(
+        "{}-{}.partial",
+        PREVIOUS_BINARY_NAME,
+        std::process::id()
+    ));
+    let copied = match std::fs::copy(live, &staged) {
+        Ok(_) => true,
+        Err(error) => {
+            eprintln!(
+                "  · warning: could not keep a rollback copy of {}: {}",
+                live.display(),
+                error
+            );
+            let _ = std::fs::remove_file(&staged);
+            false
+        }
+    };
+    let result = attempt();
+    if copied {
+        let kept = result.is_ok() && std::fs::rename(&staged, &previous).is_ok();
+        if !kept {
+            let _ = std::fs::remove_file(&staged);
+        }
+    }
+    result
+}
+
+/// Restore the binary kept by the last successful update. Returns an exit
+/// code (0 = restored and verified, 2 = error).
+pub fn rollback(config: &Config) -> i32 {
+    let result = rollback_previous_binary();
+    if config.format == OutputFormat::Json {
+        let payload = match &result {
+            Ok(outcome) => serde_json::json!({
+                "action": "update-rollback",
+                "success": true,
+                "message": format!("Rolled back from v{} to v{}", outcome.from, outcome.to),
+                "current_version": outcome.from,
+                "restored_version": outcome.to,
+                "path": outcome.live.display().to_string(),
+                "previous_path": outcome.previous.display().to_string(),
+                "requires_user_action": false,
+            }),
+            Err(message) => serde_json::json!({
+                "action": "update-rollback",
+                "success": false,
+                "message": message,
+                "current_version": VERSION,
+                "recovery_url": RELEASES_PAGE,
+                "requires_user_action": true,
+            }),
+        };
+        println!("{}", payload);
+        return if result.is_ok() { 0 } else { 2 };
+    }
+
+    println!();
+    match result {
+        Ok(outcome) => {
+            println!(
+                "  {} {}",
+                green(success_icon(config), config),
+                green(
+                    &format!("Rolled back from v{} to v{}", outcome.from, outcome.to),
+                    config
+                ),
+            );
+            println!(
+                "  v{} is kept at {}; run `tr300 update --rollback` again to return to it.",
+                outcome.from,
+                outcome.previous.display()
+            );
+            0
+        }
+        Err(message) => {
+            println!(
+                "  {} {}",
+                red(fail_icon(config), config),
+                red(&format!("Rollback failed: {}", message), config),
+            );
+            println!("  Official latest release: {}", RELEASES_PAGE);
+            2
+        }
+    }
+}
+
+struct RollbackOutcome {
+    from: String,
+    to: String,
+    live: std::path::PathBuf,
+    previous: std::path::PathBuf,
+}
+
+/// Swap the live binary with the rollback slot and verify the result, so
+/// running rollback twice returns to the newer version. Installer metadata
+/// (MSI/PKG registrations, Cargo's install list) still names the newer
+/// release; `update --pin` is the registered way to downgrade.
+fn rollback_previous_binary() -> Result<RollbackOutcome, String> {
+    let live = std::env::current_exe()
+        .map_err(|error| format!("could not resolve the running executable: {error}"))?;
+    if live
+        .file_name()
+        .is_some_and(|name| name.eq_ignore_ascii_case(PREVIOUS_BINARY_NAME))
+    {
+        return Err(format!(
+            "{} is the kept rollback copy; run `tr300 update --rollback` from the installed tr300 instead",
+            live.display()
+        ));
+    }
+    let previous = previous_binary_path(&live)
+        .filter(|path| path.is_file())
+        .ok_or_else(|| {
+            format!(
+                "no previous binary is kept next to {}. One is kept after each successful `tr300 update`; use `tr300 update --pin X.Y.Z` to install an older release instead",
+                live.display()
+            )
+        })?;
+    let to = binary_version(&previous).ok_or_else(|| {
+        format!(
+            "the kept binary at {} did not report a version, so nothing was changed",
+            previous.display()
+        )
+    })?;
+    let parking = rollback_parking_path(&live)?;
+
+    swap_with_previous(&live, &previous, &parking)?;
+    match binary_version(&live) {
+        Some(installed) if post_install_version_ok(&installed, &to) => Ok(RollbackOutcome {
+            from: VERSION.to_string(),
+            to,
+            live,
+            previous,
+        }),
+        installed => {
+            let undo = swap_with_previous(&live, &previous, &parking)
+                .map(|()| "the newer binary was restored".to_string())
+                .unwrap_or_else(|error| format!("restoring the newer binary also failed: {error}"));
+            Err(format!(
+                "the restored binary reports {} instead of v{to}; {undo}",
+                installed.map_or_else(|| "no version".to_string(), |v| format!("v{v}"))
+            ))
+        }
+    }
+}
+
+/// Where the live binary waits while the slots swap. On Windows this is the
+/// live-image handoff's private sibling, so stale-backup cleanup covers an
+/// interrupted swap.
+#[cfg(windows)]
+fn rollback_parking_path(_live: &std::path::Path) -> Result<std::path::PathBuf, String> {
+    WindowsLiveImageHandoff::plan()
+        .map(|handoff| handoff.backup)
+        .map_err(|error| error.message().to_string())
+}
+
+#[cfg(not(windows))]
+fn rollback_parking_path(live: &std::path::Path) -> Result<std::path::PathBuf, String> {
+    Ok(live.with_file_name(format!(".tr300-rollback-{}", std::process::id())))
+}
+
+/// Exchange `live` and `previous` through `parking` using renames only (an
+/// executing image may be renamed on every platform). A failed middle step
+/// puts the live binary back.
+fn swap_with_previous(
+    live: &std::path::Path,
+    previous: &std::path::Path,
+    parking: &std::path::Path,
+) -> Result<(), String> {
+    std::fs::rename(live, parking).map_err(|error| {
+        format!(
+            "could not move {} aside: {error}. Nothing was changed{}",
+            live.display(),
+            if cfg!(windows) {
+                "; an installation under Program Files needs an elevated prompt"
+            } else {
+                ""
+            }
+        )
+    })?;
+    if let Err(error) = std::fs::rename(previous, live) {
+        let _ = std::fs::rename(parking, live);
+        return Err(format!(
+            "could not move {} into place: {error}. Nothing was changed",
+            previous.display()
+        ));
+    }
+    if let Err(error) = std::fs::rename(parking, previous) {
+        eprintln!(
+            "  · warning: the replaced binary stays at {} ({error})",
+            parking.display()
+        );
+    }
+    Ok(())
+}
+
 fn recovery_asset_for_channel(channel: InstallChannel) -> Option<&'static str> {
     match channel {
         InstallChannel::MsiGlobal => Some(MSI_GLOBAL_ASSET),
@@ -1419,28 +2726,8 @@ fn recovery_asset_for_channel(channel: InstallChannel) -> Option<&'static str> {
 }
 
 fn run_command_status(launcher: &str, args: &[&str]) -> Result<(), StrategyError> {
-    run_command_status_inner(launcher, args, None)
-}
-
-fn run_command_status_with_env(
-    launcher: &str,
-    args: &[&str],
-    env_name: &str,
-    env_value: &str,
-) -> Result<(), StrategyError> {
-    run_command_status_inner(launcher, args, Some((env_name, env_value)))
-}
-
-fn run_command_status_inner(
-    launcher: &str,
-    args: &[&str],
-    extra_env: Option<(&str, &str)>,
-) -> Result<(), StrategyError> {
     let mut command = Command::new(launcher);
     command.args(args);
-    if let Some((name, value)) = extra_env {
-        command.env(name, value);
-    }
     suppress_stdout_for_json(&mut command);
     match command.status() {
         Err(e) if e.kind() == std::io::ErrorKind::NotFound => Err(StrategyError::Preflight(
@@ -1474,7 +2761,6 @@ fn likely_endpoint_policy_error(error: &std::io::Error) -> bool {
     )
 }
 
-#[cfg(any(windows, target_os = "macos"))]
 fn prelaunch_installer_io_error(
     operation: &str,
     path: &std::path::Path,
@@ -1497,97 +2783,6 @@ fn prelaunch_installer_io_error(
     }
 }
 
-#[cfg(unix)]
-fn try_installer_curl(latest: &str) -> Result<(), StrategyError> {
-    if !tool_exists("curl") {
-        return Err(StrategyError::Preflight("curl not on PATH".into()));
-    }
-    if !tool_exists("bash") {
-        return Err(StrategyError::Preflight("bash not on PATH".into()));
-    }
-    let script = format!(
-        "set -euo pipefail; curl --proto '=https' --tlsv1.2 -fLsS {} | sh",
-        release_asset_url(latest, SHELL_INSTALLER_ASSET)
-    );
-    let prefix = cargo_dist_install_prefix().ok_or_else(|| {
-        StrategyError::Preflight("matching cargo-dist receipt is unavailable".into())
-    })?;
-    run_command_status_with_env(
-        "bash",
-        &["-c", &script],
-        "CARGO_DIST_FORCE_INSTALL_DIR",
-        &prefix,
-    )
-}
-
-#[cfg(not(unix))]
-fn try_installer_curl(_latest: &str) -> Result<(), StrategyError> {
-    Err(StrategyError::Preflight(
-        "curl installer is Unix-only".into(),
-    ))
-}
-
-#[cfg(unix)]
-fn try_installer_wget(latest: &str) -> Result<(), StrategyError> {
-    if !tool_exists("wget") {
-        return Err(StrategyError::Preflight("wget not on PATH".into()));
-    }
-    if !tool_exists("bash") {
-        return Err(StrategyError::Preflight("bash not on PATH".into()));
-    }
-    let script = format!(
-        "set -euo pipefail; wget -qO- {} | sh",
-        release_asset_url(latest, SHELL_INSTALLER_ASSET)
-    );
-    let prefix = cargo_dist_install_prefix().ok_or_else(|| {
-        StrategyError::Preflight("matching cargo-dist receipt is unavailable".into())
-    })?;
-    run_command_status_with_env(
-        "bash",
-        &["-c", &script],
-        "CARGO_DIST_FORCE_INSTALL_DIR",
-        &prefix,
-    )
-}
-
-#[cfg(not(unix))]
-fn try_installer_wget(_latest: &str) -> Result<(), StrategyError> {
-    Err(StrategyError::Preflight(
-        "wget installer is Unix-only".into(),
-    ))
-}
-
-#[cfg(windows)]
-fn try_installer_powershell(launcher: &str, latest: &str) -> Result<(), StrategyError> {
-    let script = format!(
-        "$ErrorActionPreference='Stop'; irm {} | iex",
-        release_asset_url(latest, PS_INSTALLER_ASSET)
-    );
-    let prefix = cargo_dist_install_prefix().ok_or_else(|| {
-        StrategyError::Preflight("matching cargo-dist receipt is unavailable".into())
-    })?;
-    run_command_status_with_env(
-        launcher,
-        &[
-            "-NoProfile",
-            "-NonInteractive",
-            "-ExecutionPolicy",
-            "Bypass",
-            "-Command",
-            &script,
-        ],
-        "CARGO_DIST_FORCE_INSTALL_DIR",
-        &prefix,
-    )
-}
-
-#[cfg(not(windows))]
-fn try_installer_powershell(_launcher: &str, _latest: &str) -> Result<(), StrategyError> {
-    Err(StrategyError::Preflight(
-        "PowerShell installer is Windows-only".into(),
-    ))
-}
-
 // ── Windows MSI / EXE installer strategies (v3.15.0+) ──────────────
 
 /// msiexec exit code returned when the install completed successfully
@@ -1601,18 +2796,15 @@ const MSI_EXIT_REBOOT_INITIATED: i32 = 1641;
 /// Windows Installer policy explicitly forbids this installation.
 #[cfg(windows)]
 const MSI_EXIT_INSTALL_REJECTED_BY_POLICY: i32 = 1625;
-#[cfg(any(windows, target_os = "macos"))]
 const MAX_INSTALLER_BYTES: u64 = 256 * 1024 * 1024;
-#[cfg(any(windows, target_os = "macos"))]
 const MAX_SIDECAR_BYTES: u64 = 16 * 1024;
 
-/// Download a file from `url` to `path` over HTTPS. Used by the MSI/EXE
-/// strategies to fetch the matching installer into a private randomized
-/// staging directory before launching it. TLS validation is enforced by
-/// `ureq`; the caller then verifies the release sidecar to detect corruption
-/// or an installer/sidecar mismatch. The sidecar is not an independent
-/// signature and does not replace HTTPS origin authentication.
-#[cfg(any(windows, target_os = "macos"))]
+/// Download a file from `url` to `path` over HTTPS. Used by the release
+/// archive, MSI/EXE, and PKG strategies to fetch the matching asset into a
+/// private randomized staging directory before using it. TLS validation is
+/// enforced by `ureq`; the caller then verifies the `.sha256` sidecar to
+/// detect corruption and the `.minisig` signature to authenticate the asset
+/// independently of the release host.
 fn download_to_file(url: &str, path: &std::path::Path) -> Result<(), StrategyError> {
     use std::io::Read;
 
@@ -1675,11 +2867,17 @@ fn download_to_file(url: &str, path: &std::path::Path) -> Result<(), StrategyErr
 /// `dist-manifest.json` generation and the parallel implementation in
 /// `.github/workflows/windows-installers.yml`. Tolerant of trailing
 /// whitespace / missing asterisk via `parse_sha256_sidecar`.
-#[cfg(any(windows, target_os = "macos"))]
+#[cfg(any(unix, windows))]
 fn fetch_sha256_sidecar(url: &str) -> Result<String, String> {
+    fetch_sidecar(url, "sha256")
+}
+
+/// Fetch `<url>.<extension>`, capped at `MAX_SIDECAR_BYTES`.
+#[cfg(any(unix, windows))]
+fn fetch_sidecar(url: &str, extension: &str) -> Result<String, String> {
     use std::io::Read;
 
-    let sidecar_url = format!("{}.sha256", url);
+    let sidecar_url = format!("{}.{}", url, extension);
     let agent = ureq::AgentBuilder::new()
         .timeout(std::time::Duration::from_secs(30))
         .build();
@@ -1694,7 +2892,10 @@ fn fetch_sha256_sidecar(url: &str) -> Result<String, String> {
         .read_to_string(&mut body)
         .map_err(|e| format!("Failed to read sidecar body: {}", e))?;
     if body.len() as u64 > MAX_SIDECAR_BYTES {
-        return Err("SHA256 sidecar exceeded the 16 KiB safety limit".to_string());
+        return Err(format!(
+            ".{} sidecar exceeded the 16 KiB safety limit",
+            extension
+        ));
     }
     Ok(body)
 }
@@ -1702,7 +2903,6 @@ fn fetch_sha256_sidecar(url: &str) -> Result<String, String> {
 /// Extract the 64-char hex from a `.sha256` sidecar line. Returns
 /// `None` when the first whitespace-separated token is not exactly
 /// 64 hex characters.
-#[cfg(any(windows, target_os = "macos", test))]
 fn parse_sha256_sidecar(content: &str) -> Option<String> {
     content
         .split_whitespace()
@@ -1712,7 +2912,6 @@ fn parse_sha256_sidecar(content: &str) -> Option<String> {
 }
 
 /// Compute the SHA-256 of `path`, returning the lowercase hex.
-#[cfg(any(windows, target_os = "macos", test))]
 fn compute_sha256(path: &std::path::Path) -> std::io::Result<String> {
     use sha2::{Digest, Sha256};
     let mut file = std::fs::File::open(path)?;
@@ -1727,36 +2926,191 @@ fn compute_sha256(path: &std::path::Path) -> std::io::Result<String> {
 /// Detects a corrupted download or a mismatch between the installer and its
 /// published release sidecar. Because both files come from the same HTTPS
 /// release origin, this is an integrity check rather than independent artifact
-/// authentication; a future signed-release design would be a separate trust
-/// layer.
-#[cfg(any(windows, target_os = "macos"))]
+/// authentication; `verify_signature` is that separate trust layer.
+#[cfg(any(unix, windows))]
 fn verify_checksum(
     installer_path: &std::path::Path,
     installer_url: &str,
+) -> Result<(), StrategyError> {
+    let sidecar_content = fetch_sha256_sidecar(installer_url).map_err(StrategyError::Runtime)?;
+    check_sha256(installer_path, installer_url, &sidecar_content)
+}
+
+/// Compare the staged file against `.sha256` sidecar content published for
+/// `origin` (the asset URL, or the local file for `update --from`).
+fn check_sha256(
+    staged: &std::path::Path,
+    origin: &str,
+    sidecar_content: &str,
 ) -> Result<(), StrategyError> {
     eprintln!("  Verifying SHA256 checksum...");
-    let sidecar_content = fetch_sha256_sidecar(installer_url).map_err(StrategyError::Runtime)?;
-    let expected = parse_sha256_sidecar(&sidecar_content).ok_or_else(|| {
+    let expected = parse_sha256_sidecar(sidecar_content).ok_or_else(|| {
         StrategyError::Runtime(format!(
             "Malformed .sha256 sidecar from {}.sha256: {:?}",
-            installer_url, sidecar_content
+            origin, sidecar_content
         ))
     })?;
-    let actual = compute_sha256(installer_path).map_err(|error| {
+    let actual = compute_sha256(staged).map_err(|error| {
         prelaunch_installer_io_error(
             "reading the staged installer for checksum verification",
-            installer_path,
-            installer_url,
+            staged,
+            origin,
             error,
         )
     })?;
     checksum_verdict(&actual, &expected).map_err(StrategyError::Runtime)
 }
 
+/// Fetch `<url>.minisig` and verify it against the staged installer with the
+/// embedded release key and the source's extra trusted key. Fails closed:
+/// no trusted key, no signature, or a bad signature all refuse the installer.
+#[cfg(any(unix, windows))]
+fn verify_signature(
+    installer_path: &std::path::Path,
+    installer_url: &str,
+    source: &ReleaseSource,
+) -> Result<(), StrategyError> {
+    check_signature(
+        installer_path,
+        installer_url,
+        fetch_sidecar(installer_url, "minisig"),
+        source,
+    )
+}
+
+/// Verify the staged file against `.minisig` content published for `origin`.
+/// `minisig` is the sidecar fetch or read result; an error there is a missing
+/// signature and refuses the installer like a bad one.
+fn check_signature(
+    staged: &std::path::Path,
+    origin: &str,
+    minisig: Result<String, String>,
+    source: &ReleaseSource,
+) -> Result<(), StrategyError> {
+    eprintln!("  Verifying minisign signature...");
+    let keys = trusted_release_keys(source)?;
+    let minisig = minisig.map_err(|error| {
+        StrategyError::Unverified(format!(
+            "No usable signature for {} ({}); refusing to run an unsigned installer",
+            origin, error
+        ))
+    })?;
+    let installer = std::fs::read(staged).map_err(|error| {
+        prelaunch_installer_io_error(
+            "reading the staged installer for signature verification",
+            staged,
+            origin,
+            error,
+        )
+    })?;
+    let verified = crate::signature::verify(&installer, &minisig, &keys).map_err(|error| {
+        StrategyError::Unverified(format!(
+            "Signature check failed for {}: {}; refusing to run the installer",
+            origin, error
+        ))
+    })?;
+    eprintln!("  Signed by key {}", verified.key_id);
+    Ok(())
+}
+
+/// Put the release asset at `staged`: download it from `url` and verify its
+/// published sidecars, or copy the local `update --from` file and verify the
+/// sidecars beside it.
+#[cfg(any(windows, target_os = "macos"))]
+fn stage_asset(
+    source: &ReleaseSource,
+    payload: Payload,
+    url: &str,
+    staged: &std::path::Path,
+    what: &str,
+) -> Result<(), StrategyError> {
+    match payload {
+        Payload::Download => {
+            eprintln!("  Downloading {}...", what);
+            download_to_file(url, staged)?;
+            verify_checksum(staged, url)?;
+            verify_signature(staged, url, source)
+        }
+        Payload::Local(file) => stage_local_file(source, file, staged),
+    }
+}
+
+/// Copy a local release file into the private staging path and verify the
+/// copy against the `.sha256` and `.minisig` files beside the original. The
+/// sidecars are read before the copy is checked, so swapping the original
+/// afterwards cannot change what is launched.
+fn stage_local_file(
+    source: &ReleaseSource,
+    file: &std::path::Path,
+    staged: &std::path::Path,
+) -> Result<(), StrategyError> {
+    let origin = file.display().to_string();
+    eprintln!("  Copying {}...", origin);
+    let sha256 = read_local_sidecar(file, "sha256").map_err(StrategyError::Runtime)?;
+    let minisig = read_local_sidecar(file, "minisig");
+    std::fs::copy(file, staged).map_err(|error| {
+        prelaunch_installer_io_error("copying the local release file", staged, &origin, error)
+    })?;
+    check_sha256(staged, &origin, &sha256)?;
+    check_signature(staged, &origin, minisig, source)
+}
+
+/// Read `<file>.<extension>`, capped at `MAX_SIDECAR_BYTES`.
+fn read_local_sidecar(file: &std::path::Path, extension: &str) -> Result<String, String> {
+    use std::io::Read;
+
+    let mut path = file.as_os_str().to_owned();
+    path.push(".");
+    path.push(extension);
+    let path = std::path::PathBuf::from(path);
+    let sidecar =
+        std::fs::File::open(&path).map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    let mut body = String::new();
+    sidecar
+        .take(MAX_SIDECAR_BYTES + 1)
+        .read_to_string(&mut body)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    if body.len() as u64 > MAX_SIDECAR_BYTES {
+        return Err(format!(
+            ".{} sidecar exceeded the 16 KiB safety limit",
+            extension
+        ));
+    }
+    Ok(body)
+}
+
+/// The embedded release key plus any key trusted through the release source.
+fn trusted_release_keys(
+    source: &ReleaseSource,
+) -> Result<Vec<crate::signature::PublicKey>, StrategyError> {
+    trusted_keys_from(crate::signature::EMBEDDED_RELEASE_KEY, source)
+}
+
+fn trusted_keys_from(
+    embedded: Option<&str>,
+    source: &ReleaseSource,
+) -> Result<Vec<crate::signature::PublicKey>, StrategyError> {
+    let mut keys = Vec::new();
+    for key in embedded
+        .into_iter()
+        .chain(source.trusted_key.as_deref())
+        .filter(|key| !key.trim().is_empty())
+    {
+        keys.push(crate::signature::PublicKey::parse(key).map_err(|error| {
+            StrategyError::Unverified(format!("trusted release key is unusable: {}", error))
+        })?);
+    }
+    if keys.is_empty() {
+        return Err(StrategyError::Unverified(
+            "this build has no embedded release signing key and no --trusted-key was given, so installer signatures cannot be checked".to_string(),
+        ));
+    }
+    Ok(keys)
+}
+
 /// Compare a computed SHA-256 against the expected sidecar hash, refusing on
 /// mismatch. Separated from the network fetch + file read in `verify_checksum`
 /// so the load-bearing refusal-on-mismatch is unit-testable on any target.
-#[cfg(any(target_os = "windows", target_os = "macos", test))]
 fn checksum_verdict(actual: &str, expected: &str) -> Result<(), String> {
     if actual.eq_ignore_ascii_case(expected) {
         Ok(())
@@ -1768,13 +3122,11 @@ fn checksum_verdict(actual: &str, expected: &str) -> Result<(), String> {
     }
 }
 
-#[cfg(any(windows, target_os = "macos", test))]
 struct StagedInstaller {
     dir: tempfile::TempDir,
     path: std::path::PathBuf,
 }
 
-#[cfg(any(windows, target_os = "macos", test))]
 impl StagedInstaller {
     fn new(extension: &str) -> std::io::Result<Self> {
         let dir = tempfile::Builder::new().prefix("tr300-update-").tempdir()?;
@@ -1791,7 +3143,6 @@ impl StagedInstaller {
     }
 }
 
-#[cfg(any(windows, target_os = "macos", test))]
 fn finish_staged_attempt(
     staged: StagedInstaller,
     result: Result<(), StrategyError>,
@@ -1828,19 +3179,26 @@ fn finish_staged_attempt(
                 Err(StrategyError::PolicyBlocked(message)) => Err(StrategyError::PolicyBlocked(
                     format!("{}; {}", message, cleanup_note),
                 )),
+                Err(StrategyError::Unverified(message)) => Err(StrategyError::Unverified(format!(
+                    "{}; {}",
+                    message, cleanup_note
+                ))),
             }
         }
     }
 }
 
 /// Whether the freshly-installed `--version` string matches the expected
-/// release tag. Both sides are stripped of any prerelease/build-metadata
-/// suffix before comparison, and an empty `installed` never matches (covers
-/// the `--version` parse failing). Pure + platform-independent.
+/// release tag. Build metadata is ignored, but the prerelease must match, so
+/// `4.3.0-rc.2` still reporting itself after an update to `4.3.0` fails. An
+/// empty `installed` never matches (covers the `--version` parse failing).
+/// Pure + platform-independent.
 fn post_install_version_ok(installed: &str, expected: &str) -> bool {
     let installed_stripped = strip_prerelease_metadata(installed);
     let expected_stripped = strip_prerelease_metadata(expected);
-    !installed_stripped.is_empty() && installed_stripped == expected_stripped
+    !installed_stripped.is_empty()
+        && installed_stripped == expected_stripped
+        && prerelease_part(installed) == prerelease_part(expected)
 }
 
 /// Re-exec the running binary with `--version` and return the parsed version
@@ -1848,6 +3206,13 @@ fn post_install_version_ok(installed: &str, expected: &str) -> bool {
 /// process errors, or the output doesn't parse. Cross-platform — used by both
 /// the Windows installer verify and the cargo-path verify.
 fn reexec_installed_version() -> Option<String> {
+    binary_version(&installed_binary_path()?)
+}
+
+/// The installed `tr300` path. On Windows the running image may have been
+/// renamed to an update backup or the rollback copy; the installed binary is
+/// `tr300.exe` beside it.
+fn installed_binary_path() -> Option<std::path::PathBuf> {
     let exe = std::env::current_exe().ok()?;
     #[cfg(windows)]
     let exe = {
@@ -1855,13 +3220,22 @@ fn reexec_installed_version() -> Option<String> {
         if exe
             .file_name()
             .and_then(|name| name.to_str())
-            .is_some_and(is_windows_update_backup_name)
+            .is_some_and(|name| {
+                is_windows_update_backup_name(name)
+                    || name.eq_ignore_ascii_case(PREVIOUS_BINARY_NAME)
+            })
         {
             exe.set_file_name("tr300.exe");
         }
         exe
     };
-    let output = Command::new(&exe).arg("--version").output().ok()?;
+    Some(exe)
+}
+
+/// Run `<path> --version` and return the parsed version (the last whitespace
+/// token of `tr300 X.Y.Z`), or `None` when it cannot be run or parsed.
+fn binary_version(path: &std::path::Path) -> Option<String> {
+    let output = Command::new(path).arg("--version").output().ok()?;
     if !output.status.success() {
         return None;
     }
@@ -1897,9 +3271,10 @@ fn verify_cargo_post_install(expected: &str) -> Result<(), StrategyError> {
     }
 }
 
-/// Confirm a cargo-dist shell/PowerShell installer actually replaced the
-/// running installation. Installer exit code 0 alone is not sufficient: a
-/// PATH conflict or locked destination can leave the old executable in place.
+/// Confirm an installer actually replaced the running installation. Installer
+/// exit code 0 alone is not sufficient: a PATH conflict or locked destination
+/// can leave the old executable in place.
+#[cfg(target_os = "macos")]
 fn verify_installer_post_install(expected: &str, label: &str) -> Result<(), StrategyError> {
     match reexec_installed_version() {
         Some(installed) if post_install_version_ok(&installed, expected) => Ok(()),
@@ -1937,7 +3312,7 @@ fn verify_post_install(expected: &str) -> Result<(), String> {
     }
 }
 
-/// Download the matching MSI, verify its SHA256, re-run it via
+/// Download the matching MSI, verify its SHA256 and signature, re-run it via
 /// `msiexec /i /passive /norestart`, then re-exec the binary with
 /// `--version` to confirm the file replacement actually took effect.
 ///
@@ -1947,7 +3322,13 @@ fn verify_post_install(expected: &str) -> Result<(), String> {
 /// to terminate the updater to release the installed path. A deferred
 /// delete/reboot still surfaces as 3010/1641 without claiming success.
 #[cfg(windows)]
-fn try_msi_install(url: &str, latest: &str) -> Result<(), StrategyError> {
+fn try_msi_install(
+    source: &ReleaseSource,
+    payload: Payload,
+    asset: &str,
+    latest: &str,
+) -> Result<(), StrategyError> {
+    let url = &source.asset_url(latest, asset);
     let staged = StagedInstaller::new("msi").map_err(|error| {
         prelaunch_installer_io_error(
             "creating a private update staging directory",
@@ -1959,10 +3340,7 @@ fn try_msi_install(url: &str, latest: &str) -> Result<(), StrategyError> {
     let temp_path = staged.path();
 
     let result = (|| -> Result<(), StrategyError> {
-        eprintln!("  Downloading MSI installer...");
-        download_to_file(url, temp_path)?;
-
-        verify_checksum(temp_path, url)?;
+        stage_asset(source, payload, url, temp_path, "MSI installer")?;
 
         eprintln!("  Launching Windows Installer...");
         // /passive shows a progress dialog with no user interaction; /norestart
@@ -2008,9 +3386,9 @@ fn try_msi_install(url: &str, latest: &str) -> Result<(), StrategyError> {
     finish_staged_attempt(staged, result)
 }
 
-/// Download the matching Inno Setup EXE installer, verify its SHA256,
-/// re-run it with `/SILENT /SUPPRESSMSGBOXES /NORESTART`, then verify
-/// the post-install version.
+/// Download the matching Inno Setup EXE installer, verify its SHA256 and
+/// signature, re-run it with `/SILENT /SUPPRESSMSGBOXES /NORESTART`, then
+/// verify the post-install version.
 ///
 /// Inno Setup's AppId-based upgrade detection silently uninstalls the
 /// old version before installing the new one. For the Global perMachine
@@ -2018,7 +3396,13 @@ fn try_msi_install(url: &str, latest: &str) -> Result<(), StrategyError> {
 /// before any UI; the Corporate perUser EXE (`PrivilegesRequired=lowest`)
 /// installs without elevation.
 #[cfg(windows)]
-fn try_exe_install(url: &str, latest: &str) -> Result<(), StrategyError> {
+fn try_exe_install(
+    source: &ReleaseSource,
+    payload: Payload,
+    asset: &str,
+    latest: &str,
+) -> Result<(), StrategyError> {
+    let url = &source.asset_url(latest, asset);
     let staged = StagedInstaller::new("exe").map_err(|error| {
         prelaunch_installer_io_error(
             "creating a private update staging directory",
@@ -2030,10 +3414,7 @@ fn try_exe_install(url: &str, latest: &str) -> Result<(), StrategyError> {
     let temp_path = staged.path();
 
     let result = (|| -> Result<(), StrategyError> {
-        eprintln!("  Downloading EXE installer...");
-        download_to_file(url, temp_path)?;
-
-        verify_checksum(temp_path, url)?;
+        stage_asset(source, payload, url, temp_path, "EXE installer")?;
 
         eprintln!("  Launching Inno Setup installer...");
         // /SILENT shows a progress dialog but no wizard pages; /SUPPRESSMSGBOXES
@@ -2077,8 +3458,12 @@ fn suppress_stdout_for_json(command: &mut Command) {
 }
 
 #[cfg(target_os = "macos")]
-fn try_macos_pkg_install(latest: &str) -> Result<(), StrategyError> {
-    let url = release_asset_url(latest, MAC_PKG_ASSET);
+fn try_macos_pkg_install(
+    source: &ReleaseSource,
+    payload: Payload,
+    latest: &str,
+) -> Result<(), StrategyError> {
+    let url = source.asset_url(latest, MAC_PKG_ASSET);
     let staged = StagedInstaller::new("pkg").map_err(|error| {
         prelaunch_installer_io_error(
             "creating a private update staging directory",
@@ -2090,9 +3475,7 @@ fn try_macos_pkg_install(latest: &str) -> Result<(), StrategyError> {
     let pkg_path = staged.path().to_path_buf();
 
     let result = (|| -> Result<(), StrategyError> {
-        eprintln!("  Downloading signed macOS PKG...");
-        download_to_file(&url, &pkg_path)?;
-        verify_checksum(&pkg_path, &url)?;
+        stage_asset(source, payload, &url, &pkg_path, "signed macOS PKG")?;
         verify_macos_pkg_signature(&pkg_path)?;
         run_command_status(
             "xcrun",
@@ -2121,21 +3504,35 @@ fn try_macos_pkg_install(latest: &str) -> Result<(), StrategyError> {
 }
 
 #[cfg(not(target_os = "macos"))]
-fn try_macos_pkg_install(_latest: &str) -> Result<(), StrategyError> {
+fn try_macos_pkg_install(
+    _source: &ReleaseSource,
+    _payload: Payload,
+    _latest: &str,
+) -> Result<(), StrategyError> {
     Err(StrategyError::Preflight(
         "PKG installer is macOS-only".into(),
     ))
 }
 
 #[cfg(not(windows))]
-fn try_msi_install(_url: &str, _latest: &str) -> Result<(), StrategyError> {
+fn try_msi_install(
+    _source: &ReleaseSource,
+    _payload: Payload,
+    _asset: &str,
+    _latest: &str,
+) -> Result<(), StrategyError> {
     Err(StrategyError::Preflight(
         "MSI installer is Windows-only".into(),
     ))
 }
 
 #[cfg(not(windows))]
-fn try_exe_install(_url: &str, _latest: &str) -> Result<(), StrategyError> {
+fn try_exe_install(
+    _source: &ReleaseSource,
+    _payload: Payload,
+    _asset: &str,
+    _latest: &str,
+) -> Result<(), StrategyError> {
     Err(StrategyError::Preflight(
         "EXE installer is Windows-only".into(),
     ))
@@ -2215,13 +3612,35 @@ fn cargo_dist_receipt_evidence() -> Option<std::time::SystemTime> {
         .flatten()
 }
 
-fn cargo_dist_install_prefix() -> Option<String> {
-    let contents = std::fs::read_to_string(cargo_dist_receipt_path()?).ok()?;
-    let receipt: serde_json::Value = serde_json::from_str(&contents).ok()?;
-    let exe = std::env::current_exe().ok()?;
-    cargo_dist_receipt_matches(&receipt, &exe)
-        .then(|| find_json_string(&receipt, "install_prefix").map(str::to_string))
-        .flatten()
+/// Record `latest` in the matching cargo-dist receipt after the binary was
+/// replaced from the release archive, as a re-run of the installer would.
+/// Best-effort: the update itself already succeeded.
+fn refresh_cargo_dist_receipt(latest: &str) {
+    let Some(path) = cargo_dist_receipt_path() else {
+        return;
+    };
+    let Some(mut receipt) = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str::<serde_json::Value>(&contents).ok())
+    else {
+        return;
+    };
+    let Ok(exe) = std::env::current_exe() else {
+        return;
+    };
+    if !cargo_dist_receipt_matches(&receipt, &exe) {
+        return;
+    }
+    if let Some(map) = receipt.as_object_mut() {
+        map.insert("version".into(), latest.into());
+    }
+    if let Err(error) = std::fs::write(&path, receipt.to_string()) {
+        eprintln!(
+            "  Warning: could not update the install receipt at {}: {}",
+            path.display(),
+            error
+        );
+    }
 }
 
 fn cargo_dist_receipt_matches(receipt: &serde_json::Value, exe: &std::path::Path) -> bool {
@@ -2695,7 +4114,7 @@ mod tests {
     #[test]
     fn release_asset_urls_pin_the_resolved_tag_without_versioning_the_filename() {
         assert_eq!(
-            release_asset_url("4.1.0", MSI_GLOBAL_ASSET),
+            ReleaseSource::default().asset_url("4.1.0", MSI_GLOBAL_ASSET),
             "https://github.com/QubeTX/qube-machine-report/releases/download/v4.1.0/tr300-x86_64-pc-windows-msvc.msi"
         );
         assert!(!MSI_GLOBAL_ASSET.contains("4.1.0"));
@@ -2717,10 +4136,11 @@ mod tests {
         );
         assert_eq!(
             build_strategy_list(InstallChannel::PowerShellInstaller),
-            vec![
-                UpdateStrategy::InstallerPwsh,
-                UpdateStrategy::InstallerPowerShell
-            ]
+            vec![UpdateStrategy::ReleaseArchive]
+        );
+        assert_eq!(
+            build_strategy_list(InstallChannel::ShellInstaller),
+            vec![UpdateStrategy::ReleaseArchive]
         );
         assert!(build_strategy_list(InstallChannel::Unknown).is_empty());
     }
@@ -2848,13 +4268,7 @@ TeamIdentifier=M9D5379H93\n";
     #[test]
     fn json_method_maps_to_legacy_taxonomy() {
         assert_eq!(UpdateStrategy::Cargo.json_method(), "cargo");
-        assert_eq!(UpdateStrategy::InstallerCurl.json_method(), "installer");
-        assert_eq!(UpdateStrategy::InstallerWget.json_method(), "installer");
-        assert_eq!(
-            UpdateStrategy::InstallerPowerShell.json_method(),
-            "installer"
-        );
-        assert_eq!(UpdateStrategy::InstallerPwsh.json_method(), "installer");
+        assert_eq!(UpdateStrategy::ReleaseArchive.json_method(), "installer");
         // New v3.15.0+ MSI/EXE strategies map to "installer" in the legacy
         // `method` field. External consumers wanting the specific installer
         // type should read the precise `strategy` field instead.
@@ -2886,7 +4300,7 @@ TeamIdentifier=M9D5379H93\n";
             Some("tr300-universal-apple-darwin.pkg")
         );
         assert_eq!(
-            release_asset_url("4.2.0", MAC_PKG_ASSET),
+            ReleaseSource::default().asset_url("4.2.0", MAC_PKG_ASSET),
             "https://github.com/QubeTX/qube-machine-report/releases/download/v4.2.0/tr300-universal-apple-darwin.pkg"
         );
         assert!(!MAC_PKG_ASSET.contains("4.2.0"));
@@ -2920,10 +4334,8 @@ TeamIdentifier=M9D5379H93\n";
             UpdateStrategy::ExeGlobal.label(),
             UpdateStrategy::ExeCorporate.label(),
             UpdateStrategy::Cargo.label(),
-            UpdateStrategy::InstallerPowerShell.label(),
-            UpdateStrategy::InstallerPwsh.label(),
-            UpdateStrategy::InstallerCurl.label(),
-            UpdateStrategy::InstallerWget.label(),
+            UpdateStrategy::ReleaseArchive.label(),
+            UpdateStrategy::LocalArchive.label(),
             UpdateStrategy::MacPkg.label(),
         ];
         let unique: std::collections::HashSet<_> = labels.iter().collect();
@@ -3114,6 +4526,10 @@ TeamIdentifier=M9D5379H93\n";
         assert!(!post_install_version_ok("3.15.3", "3.16.0"));
         // Empty installed string (e.g. `--version` output failed to parse).
         assert!(!post_install_version_ok("", "3.16.0"));
+        // A beta install that is still on its prerelease has not updated.
+        assert!(post_install_version_ok("3.16.0-rc.2", "3.16.0-rc.2"));
+        assert!(!post_install_version_ok("3.16.0-rc.2", "3.16.0"));
+        assert!(!post_install_version_ok("3.16.0-rc.1", "3.16.0-rc.2"));
     }
 
     #[test]
@@ -3177,7 +4593,7 @@ TeamIdentifier=M9D5379H93\n";
 
     #[test]
     fn endpoint_policy_block_stops_fallback_chain() {
-        let strategies = [UpdateStrategy::Cargo, UpdateStrategy::InstallerCurl];
+        let strategies = [UpdateStrategy::Cargo, UpdateStrategy::ReleaseArchive];
         let mut calls = 0;
         let failure = execute_update_with("4.0.0", &strategies, |_strategy, _latest| {
             calls += 1;
@@ -3192,15 +4608,20 @@ TeamIdentifier=M9D5379H93\n";
         assert_eq!(failure.attempts[0].kind, AttemptKind::Blocked);
         assert!(failure.attempts[0].message.contains("endpoint policy"));
 
-        let payload =
-            update_failure_payload("3.17.0", "4.0.0", &failure, InstallChannel::MsiGlobal);
+        let payload = update_failure_payload(
+            &ReleaseSource::default(),
+            "3.17.0",
+            "4.0.0",
+            &failure,
+            InstallChannel::MsiGlobal,
+        );
         assert_eq!(payload["success"], false);
         assert_eq!(payload["attempts"][0]["result"], "blocked");
         assert_eq!(payload["manual_install_url"], MANUAL_INSTALL_URL);
         assert_eq!(payload["official_releases_url"], RELEASES_PAGE);
         assert_eq!(
             payload["exact_installer_url"],
-            release_asset_url("4.0.0", MSI_GLOBAL_ASSET)
+            ReleaseSource::default().asset_url("4.0.0", MSI_GLOBAL_ASSET)
         );
     }
 
@@ -3213,17 +4634,597 @@ TeamIdentifier=M9D5379H93\n";
                 message: "simulated ordinary failure".to_string(),
             }],
         };
-        let payload = update_failure_payload("3.17.0", "4.0.0", &failure, InstallChannel::Cargo);
+        let payload = update_failure_payload(
+            &ReleaseSource::default(),
+            "3.17.0",
+            "4.0.0",
+            &failure,
+            InstallChannel::Cargo,
+        );
         assert_eq!(payload["attempts"][0]["result"], "failed");
         assert_eq!(payload["manual_install_url"], MANUAL_INSTALL_URL);
         assert!(payload.get("official_releases_url").is_none());
         assert!(payload.get("exact_installer_url").is_none());
     }
 
+    #[test]
+    fn update_check_lists_would_run_strategies_without_attempting_them() {
+        let current = update_check_payload(
+            &ReleaseSource::default(),
+            "4.2.2",
+            "4.2.2",
+            InstallChannel::Cargo,
+        );
+        assert_eq!(current["action"], "update-check");
+        assert_eq!(current["update_available"], false);
+        assert_eq!(current["requires_user_action"], false);
+
+        let shell = update_check_payload(
+            &ReleaseSource::default(),
+            "4.2.2",
+            "4.3.0",
+            InstallChannel::ShellInstaller,
+        );
+        assert_eq!(shell["update_available"], true);
+        assert_eq!(shell["install_channel"], "shell-installer");
+        assert_eq!(shell["strategies"], serde_json::json!(["release_archive"]));
+        assert_eq!(shell["requires_user_action"], false);
+        assert!(shell.get("attempts").is_none());
+
+        let unknown = update_check_payload(
+            &ReleaseSource::default(),
+            "4.2.2",
+            "4.3.0",
+            InstallChannel::Unknown,
+        );
+        assert_eq!(unknown["strategies"], serde_json::json!([]));
+        assert_eq!(unknown["requires_user_action"], true);
+        assert_eq!(unknown["release_channel"], "stable");
+
+        let beta = update_check_payload(
+            &ReleaseSource::default().with_channel(ReleaseChannel::Beta),
+            "4.2.2",
+            "4.3.0-rc.1",
+            InstallChannel::Cargo,
+        );
+        assert_eq!(beta["update_available"], true);
+        assert_eq!(beta["release_channel"], "beta");
+        // The channel alone does not make the host a mirror.
+        assert!(beta.get("release_source").is_none());
+    }
+
+    #[test]
+    fn pinned_targets_install_when_different_latest_only_when_newer() {
+        assert!(needs_install("4.2.2", "4.3.0", false));
+        assert!(!needs_install("4.2.2", "4.1.0", false));
+        assert!(needs_install("4.2.2", "4.1.0", true));
+        assert!(needs_install("4.2.2", "4.3.0", true));
+        assert!(!needs_install("4.2.2", "4.2.2", true));
+
+        let mut payload = serde_json::json!({
+            "action": "update",
+            "current_version": "4.2.2",
+            "latest_version": "4.1.0",
+        });
+        mark_pinned(&mut payload);
+        assert_eq!(payload["target_version"], "4.1.0");
+        assert_eq!(payload["pinned"], true);
+        assert!(payload.get("latest_version").is_none());
+    }
+
+    #[test]
+    fn previous_binary_is_kept_only_after_a_verified_update() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = dir.path().join("tr300");
+        let previous = previous_binary_path(&live).unwrap();
+        assert_eq!(previous, dir.path().join(PREVIOUS_BINARY_NAME));
+
+        std::fs::write(&live, "v1").unwrap();
+        let failed = keep_previous_around(&live, || {
+            Err(StrategyError::Runtime("simulated".to_string()))
+        });
+        assert!(failed.is_err());
+        assert!(!previous.exists());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+
+        keep_previous_around(&live, || {
+            std::fs::write(&live, "v2").map_err(|e| StrategyError::Runtime(e.to_string()))
+        })
+        .unwrap();
+        assert_eq!(std::fs::read_to_string(&live).unwrap(), "v2");
+        assert_eq!(std::fs::read_to_string(&previous).unwrap(), "v1");
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn rollback_swap_exchanges_live_and_previous_and_is_reversible() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = dir.path().join("tr300");
+        let previous = previous_binary_path(&live).unwrap();
+        let parking = dir.path().join(".tr300-rollback-test");
+        std::fs::write(&live, "new").unwrap();
+        std::fs::write(&previous, "old").unwrap();
+
+        swap_with_previous(&live, &previous, &parking).unwrap();
+        assert_eq!(std::fs::read_to_string(&live).unwrap(), "old");
+        assert_eq!(std::fs::read_to_string(&previous).unwrap(), "new");
+        assert!(!parking.exists());
+
+        swap_with_previous(&live, &previous, &parking).unwrap();
+        assert_eq!(std::fs::read_to_string(&live).unwrap(), "new");
+
+        std::fs::remove_file(&previous).unwrap();
+        let error = swap_with_previous(&live, &previous, &parking).unwrap_err();
+        assert!(error.contains("Nothing was changed"), "{error}");
+        assert_eq!(std::fs::read_to_string(&live).unwrap(), "new");
+        assert!(!parking.exists());
+    }
+
+    /// Minimal HTTP/1.1 stand-in for a release mirror: serves fixed bodies by
+    /// path (404 otherwise) on a loopback port until the test process exits.
+    fn release_stand_in<R: AsRef<str> + Send + 'static>(routes: Vec<(R, Vec<u8>)>) -> String {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(&stream);
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                loop {
+                    let mut header = String::new();
+                    match reader.read_line(&mut header) {
+                        Ok(n) if n > 0 && header != "\r\n" => {}
+                        _ => break,
+                    }
+                }
+                let path = request_line.split_whitespace().nth(1).unwrap_or("");
+                let (status, body) = routes
+                    .iter()
+                    .find(|(route, _)| route.as_ref() == path)
+                    .map_or(("404 Not Found", &[][..]), |(_, body)| {
+                        ("200 OK", body.as_slice())
+                    });
+                let mut stream = &stream;
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(body);
+            }
+        });
+        base
+    }
+
+    #[test]
+    fn pinned_version_mismatch_names_the_mirror_that_answered() {
+        let base = release_stand_in(vec![("/tags/v4.1.0", br#"{"tag_name":"v4.2.0"}"#.to_vec())]);
+        let source = ReleaseSource::default().with_api_url(&base).unwrap();
+        let error = fetch_pinned_version(&source, "4.1.0").unwrap_err();
+        assert_eq!(
+            error,
+            format!("The release mirror at {base} returned release v4.2.0 when asked for v4.1.0")
+        );
+    }
+
+    #[test]
+    fn release_source_urls_require_https_except_on_loopback() {
+        let github = ReleaseSource::default();
+        assert!(github.is_default() && github.is_github_api());
+        assert_eq!(
+            github.latest_url(),
+            "https://api.github.com/repos/QubeTX/qube-machine-report/releases/latest"
+        );
+
+        let mirror = ReleaseSource::default()
+            .with_api_url("https://artifacts.corp/tr300/releases/")
+            .unwrap();
+        assert_eq!(
+            mirror.tag_url("4.1.0"),
+            "https://artifacts.corp/tr300/releases/tags/v4.1.0"
+        );
+        assert!(!mirror.is_default() && !mirror.is_github_api());
+
+        for ok in [
+            "http://127.0.0.1:8080/dl",
+            "http://localhost:3000",
+            "http://[::1]:9000/x",
+        ] {
+            assert!(
+                ReleaseSource::default().with_asset_base_url(ok).is_ok(),
+                "{ok}"
+            );
+        }
+        for bad in [
+            "http://artifacts.corp/dl",
+            "http://127.evil.example/dl",
+            "http://localhost.evil.example/dl",
+            "ftp://artifacts.corp/dl",
+            "https://artifacts.corp/$(reboot)",
+            "https://artifacts.corp/a b",
+            "https://",
+        ] {
+            assert!(
+                ReleaseSource::default().with_asset_base_url(bad).is_err(),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn release_checks_and_sidecar_verification_work_against_a_local_mirror() {
+        use sha2::{Digest, Sha256};
+
+        let asset = b"installer bytes".to_vec();
+        let digest = format!("{:x}", Sha256::digest(&asset));
+        let base = release_stand_in(vec![
+            ("/api/latest", br#"{"tag_name":"v9.1.0"}"#.to_vec()),
+            (
+                "/api?per_page=100",
+                br#"[{"tag_name":"v9.2.0-rc.1","prerelease":true},{"tag_name":"v9.1.0"}]"#.to_vec(),
+            ),
+            ("/api/tags/v9.0.0", br#"{"tag_name":"v9.0.0"}"#.to_vec()),
+            ("/dl/v9.1.0/tr300-installer.sh", asset.clone()),
+            (
+                "/dl/v9.1.0/tr300-installer.sh.sha256",
+                format!("{digest}  *tr300-installer.sh\n").into_bytes(),
+            ),
+            ("/dl/v9.1.0/tampered.msi", asset.clone()),
+            (
+                "/dl/v9.1.0/tampered.msi.sha256",
+                format!("{}  *tampered.msi\n", "0".repeat(64)).into_bytes(),
+            ),
+        ]);
+        let source = ReleaseSource::default()
+            .with_api_url(&format!("{base}/api"))
+            .unwrap()
+            .with_asset_base_url(&format!("{base}/dl/"))
+            .unwrap();
+
+        assert_eq!(fetch_latest_version(&source).unwrap(), "9.1.0");
+        let beta = source.clone().with_channel(ReleaseChannel::Beta);
+        assert_eq!(fetch_latest_version(&beta).unwrap(), "9.2.0-rc.1");
+        assert_eq!(fetch_pinned_version(&source, "v9.0.0").unwrap(), "9.0.0");
+        let missing = fetch_pinned_version(&source, "8.0.0").unwrap_err();
+        assert!(missing.contains("HTTP 404"), "{missing}");
+
+        let good = StagedInstaller::new("sh").unwrap();
+        let url = source.asset_url("9.1.0", SHELL_INSTALLER_ASSET);
+        download_to_file(&url, good.path()).unwrap();
+        verify_checksum(good.path(), &url).unwrap();
+
+        let tampered = StagedInstaller::new("msi").unwrap();
+        let url = source.asset_url("9.1.0", "tampered.msi");
+        download_to_file(&url, tampered.path()).unwrap();
+        match verify_checksum(tampered.path(), &url) {
+            Err(StrategyError::Runtime(message)) => {
+                assert!(message.contains("SHA256 mismatch"), "{message}")
+            }
+            other => panic!("expected a checksum refusal, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn installer_signatures_fail_closed_against_a_local_mirror() {
+        use crate::signature::tests::Signer;
+
+        let release = Signer::new([1; 8]);
+        let stranger = Signer::new([2; 8]);
+        let asset = b"installer bytes".to_vec();
+        let base = release_stand_in(vec![
+            ("/dl/v9.1.0/signed.msi", asset.clone()),
+            (
+                "/dl/v9.1.0/signed.msi.minisig",
+                release.sign(&asset).into_bytes(),
+            ),
+            ("/dl/v9.1.0/foreign.msi", asset.clone()),
+            (
+                "/dl/v9.1.0/foreign.msi.minisig",
+                stranger.sign(&asset).into_bytes(),
+            ),
+            ("/dl/v9.1.0/unsigned.msi", asset.clone()),
+        ]);
+        let source = ReleaseSource::default()
+            .with_asset_base_url(&format!("{base}/dl"))
+            .unwrap()
+            .with_trusted_key(&release.public_key())
+            .unwrap();
+
+        let staged = StagedInstaller::new("msi").unwrap();
+        std::fs::write(staged.path(), &asset).unwrap();
+        let url = source.asset_url("9.1.0", "signed.msi");
+        verify_signature(staged.path(), &url, &source).unwrap();
+
+        for (asset, expected) in [
+            ("foreign.msi", "untrusted key"),
+            ("unsigned.msi", "No usable signature"),
+        ] {
+            let url = source.asset_url("9.1.0", asset);
+            match verify_signature(staged.path(), &url, &source) {
+                Err(StrategyError::Unverified(message)) => {
+                    assert!(message.contains(expected), "{message}")
+                }
+                other => panic!("expected a signature refusal for {asset}, got {other:?}"),
+            }
+        }
+
+        std::fs::write(staged.path(), b"installer bytez").unwrap();
+        assert!(matches!(
+            verify_signature(staged.path(), &url, &source),
+            Err(StrategyError::Unverified(_))
+        ));
+
+        assert!(matches!(
+            trusted_keys_from(None, &ReleaseSource::default()),
+            Err(StrategyError::Unverified(message)) if message.contains("no embedded release signing key")
+        ));
+        assert_eq!(
+            trusted_keys_from(Some(&stranger.public_key()), &source)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(ReleaseSource::default()
+            .with_trusted_key("RWQnot-a-key")
+            .is_err());
+    }
+
+    #[test]
+    fn release_archive_is_used_only_when_signed() {
+        use crate::signature::tests::Signer;
+        use sha2::{Digest, Sha256};
+
+        let release = Signer::new([1; 8]);
+        let archive = b"not really an archive".to_vec();
+        let sha256 = format!(
+            "{:x}  *{}\n",
+            Sha256::digest(&archive),
+            RELEASE_ARCHIVE_ASSET
+        );
+        let path = |version: &str, suffix: &str| {
+            format!("/dl/v{}/{}{}", version, RELEASE_ARCHIVE_ASSET, suffix)
+        };
+        let base = release_stand_in(vec![
+            (path("9.1.0", ""), archive.clone()),
+            (path("9.1.0", ".sha256"), sha256.clone().into_bytes()),
+            (path("9.2.0", ""), archive.clone()),
+            (path("9.2.0", ".sha256"), sha256.into_bytes()),
+            (
+                path("9.2.0", ".minisig"),
+                release.sign(&archive).into_bytes(),
+            ),
+        ]);
+        let source = ReleaseSource::default()
+            .with_asset_base_url(&format!("{base}/dl"))
+            .unwrap()
+            .with_trusted_key(&release.public_key())
+            .unwrap();
+
+        let strategies = build_strategy_list(InstallChannel::ShellInstaller);
+        let mut calls = 0;
+        let failure = execute_update_with("9.1.0", &strategies, |_strategy, latest| {
+            calls += 1;
+            try_release_archive(&source, latest)
+        })
+        .expect_err("an unsigned release archive must fail the update");
+        assert_eq!(calls, 1, "a missing signature stops the fallback chain");
+        assert_eq!(failure.attempts[0].kind, AttemptKind::Unverified);
+        let payload = update_failure_payload(
+            &source,
+            "9.0.0",
+            "9.1.0",
+            &failure,
+            InstallChannel::ShellInstaller,
+        );
+        assert_eq!(payload["attempts"][0]["result"], "unverified");
+        assert_eq!(payload["signature_rejected"], true);
+
+        // The signed archive comes from the mirror and verifies; unpacking it
+        // would replace the test binary, so stop after staging.
+        let dir = tempfile::tempdir().unwrap();
+        let staged = dir.path().join(RELEASE_ARCHIVE_ASSET);
+        let url = source.asset_url("9.2.0", RELEASE_ARCHIVE_ASSET);
+        assert!(url.starts_with(&base));
+        download_release_archive(&source, &url, &staged)
+            .expect("a signed archive verifies against the trusted key");
+        assert_eq!(std::fs::read(&staged).unwrap(), archive);
+    }
+
+    #[test]
+    fn offline_files_only_install_through_their_own_channel() {
+        use std::path::Path;
+
+        let archive = Path::new("/media/usb/tr300-x86_64-unknown-linux-gnu.tar.xz");
+        assert_eq!(archive_suffix(archive), Some("tar.xz"));
+        assert_eq!(archive_suffix(Path::new("TR300.ZIP")), Some("zip"));
+        assert_eq!(archive_suffix(Path::new(MSI_GLOBAL_ASSET)), None);
+
+        for channel in [
+            InstallChannel::ShellInstaller,
+            InstallChannel::PowerShellInstaller,
+            InstallChannel::Cargo,
+        ] {
+            assert_eq!(
+                local_strategies(channel, archive).unwrap(),
+                vec![UpdateStrategy::LocalArchive]
+            );
+            assert!(local_strategies(channel, Path::new(MSI_GLOBAL_ASSET)).is_err());
+        }
+        assert_eq!(
+            local_strategies(InstallChannel::MsiGlobal, Path::new(MSI_GLOBAL_ASSET)).unwrap(),
+            vec![UpdateStrategy::MsiGlobal]
+        );
+        let wrong_edition =
+            local_strategies(InstallChannel::MsiGlobal, Path::new(MSI_CORPORATE_ASSET))
+                .unwrap_err();
+        assert!(wrong_edition.contains(MSI_GLOBAL_ASSET), "{wrong_edition}");
+        assert!(local_strategies(InstallChannel::MacPkg, archive).is_err());
+        assert!(local_strategies(InstallChannel::Unknown, archive)
+            .unwrap()
+            .is_empty());
+
+        let mut payload = serde_json::json!({ "latest_version": "4.1.0" });
+        mark_local(&mut payload, "/media/usb/x.tar.xz");
+        assert_eq!(payload["target_version"], "4.1.0");
+        assert_eq!(payload["from"], "/media/usb/x.tar.xz");
+        assert!(payload.get("latest_version").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn offline_archive_is_verified_before_its_version_is_read() {
+        use crate::signature::tests::Signer;
+        use sha2::{Digest, Sha256};
+        use std::os::unix::fs::PermissionsExt;
+
+        let release = Signer::new([3; 8]);
+        let dir = tempfile::tempdir().unwrap();
+        let inner = dir.path().join("tr300-x86_64-unknown-linux-gnu");
+        std::fs::create_dir(&inner).unwrap();
+        std::fs::write(inner.join("tr300"), "#!/bin/sh\necho 'tr300 9.9.9'\n").unwrap();
+        std::fs::set_permissions(inner.join("tr300"), std::fs::Permissions::from_mode(0o755))
+            .unwrap();
+        let archive = dir.path().join("tr300-x86_64-unknown-linux-gnu.tar.gz");
+        let status = Command::new("tar")
+            .arg("-czf")
+            .arg(&archive)
+            .arg("-C")
+            .arg(dir.path())
+            .arg("tr300-x86_64-unknown-linux-gnu")
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let bytes = std::fs::read(&archive).unwrap();
+        let sidecar = |extension: &str| {
+            let mut path = archive.clone().into_os_string();
+            path.push(format!(".{extension}"));
+            std::path::PathBuf::from(path)
+        };
+        std::fs::write(
+            sidecar("sha256"),
+            format!("{:x}  *archive\n", Sha256::digest(&bytes)),
+        )
+        .unwrap();
+        let source = ReleaseSource::default()
+            .with_trusted_key(&release.public_key())
+            .unwrap();
+
+        match local_release_version(&source, &archive, None) {
+            Err(StrategyError::Unverified(message)) => {
+                assert!(message.contains("No usable signature"), "{message}")
+            }
+            other => panic!("expected an unsigned archive to be refused, got {other:?}"),
+        }
+
+        std::fs::write(sidecar("minisig"), release.sign(&bytes)).unwrap();
+        assert_eq!(
+            local_release_version(&source, &archive, None).unwrap(),
+            "9.9.9"
+        );
+        assert_eq!(
+            local_release_version(&source, &archive, Some("v9.9.9")).unwrap(),
+            "9.9.9"
+        );
+        assert!(matches!(
+            local_release_version(&source, &archive, Some("9.9.8")),
+            Err(StrategyError::Preflight(message)) if message.contains("holds v9.9.9")
+        ));
+
+        std::fs::write(sidecar("sha256"), format!("{}  *archive\n", "0".repeat(64))).unwrap();
+        assert!(matches!(
+            local_release_version(&source, &archive, None),
+            Err(StrategyError::Runtime(message)) if message.contains("SHA256 mismatch")
+        ));
+
+        let installer = dir.path().join(MSI_GLOBAL_ASSET);
+        std::fs::write(&installer, b"msi").unwrap();
+        assert!(matches!(
+            local_release_version(&source, &installer, None),
+            Err(StrategyError::Preflight(message)) if message.contains("--pin X.Y.Z")
+        ));
+        assert_eq!(
+            local_release_version(&source, &installer, Some("9.9.9")).unwrap(),
+            "9.9.9"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn binary_swap_restores_the_old_binary_when_the_new_one_is_wrong() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let script = |name: &str, version: &str| {
+            let path = dir.path().join(name);
+            std::fs::write(&path, format!("#!/bin/sh\necho 'tr300 {version}'\n")).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            path
+        };
+        let live = script("tr300", "1.0.0");
+        let good = script("good", "2.0.0");
+        let bad = script("bad", "1.5.0");
+
+        assert!(matches!(
+            replace_installed_binary(&live, &bad, "2.0.0"),
+            Err(StrategyError::Runtime(message)) if message.contains("reports v1.5.0")
+        ));
+        assert_eq!(binary_version(&live).as_deref(), Some("1.0.0"));
+
+        replace_installed_binary(&live, &good, "2.0.0").unwrap();
+        assert_eq!(binary_version(&live).as_deref(), Some("2.0.0"));
+        let leftovers: Vec<_> = std::fs::read_dir(dir.path())
+            .unwrap()
+            .flatten()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with(".tr300-update-")
+            })
+            .collect();
+        assert!(leftovers.is_empty(), "{leftovers:?}");
+    }
+
+    #[test]
+    fn signature_refusal_stops_fallback_chain_and_is_reported() {
+        let strategies = [UpdateStrategy::MsiGlobal, UpdateStrategy::ExeGlobal];
+        let mut calls = 0;
+        let failure = execute_update_with("4.0.0", &strategies, |_strategy, _latest| {
+            calls += 1;
+            Err(StrategyError::Unverified(
+                "Signature check failed".to_string(),
+            ))
+        })
+        .expect_err("a bad signature must fail the update");
+
+        assert_eq!(calls, 1);
+        assert_eq!(failure.attempts[0].kind, AttemptKind::Unverified);
+        let payload = update_failure_payload(
+            &ReleaseSource::default(),
+            "3.17.0",
+            "4.0.0",
+            &failure,
+            InstallChannel::MsiGlobal,
+        );
+        assert_eq!(payload["attempts"][0]["result"], "unverified");
+        assert_eq!(payload["signature_rejected"], true);
+        assert!(payload.get("official_releases_url").is_none());
+    }
+
     #[test]
     fn unknown_channel_failure_explains_safe_no_mutation() {
         let failure = UpdateFailure { attempts: vec![] };
-        let payload = update_failure_payload("3.17.0", "4.0.0", &failure, InstallChannel::Unknown);
+        let payload = update_failure_payload(
+            &ReleaseSource::default(),
+            "3.17.0",
+            "4.0.0",
+            &failure,
+            InstallChannel::Unknown,
+        );
         assert!(payload["message"]
             .as_str()
             .unwrap()
@@ -3252,6 +5253,19 @@ TeamIdentifier=M9D5379H93\n";
         assert!(!post_install_version_ok("3.15.3", "3.16.0"));
     }
 
+    #[test]
+    fn update_notice_only_names_a_newer_cached_release() {
+        assert_eq!(
+            newer_cached_release("4.2.2", "4.3.0\n").as_deref(),
+            Some("4.3.0")
+        );
+        assert_eq!(newer_cached_release("4.2.2", "4.2.2"), None);
+        // A cache written before this binary was updated is stale, not news.
+        assert_eq!(newer_cached_release("4.3.0", "4.2.2"), None);
+        assert_eq!(newer_cached_release("4.2.2", ""), None);
+        assert_eq!(newer_cached_release("4.2.2", "<html>"), None);
+    }
+
     #[test]
     fn is_newer_treats_prerelease_of_higher_triple_as_newer() {
         // Stable user on 3.15.1, prerelease of 3.15.2 published. The
@@ -3272,12 +5286,48 @@ TeamIdentifier=M9D5379H93\n";
     }
 
     #[test]
-    fn is_newer_treats_two_prereleases_of_same_triple_as_equal() {
-        // Theoretical case — GitHub /releases/latest filters prereleases
-        // out so we shouldn't see this in practice. The conservative
-        // "equal → not newer" verdict means tr300 reports "already on
-        // latest" and the user can manually pick.
-        assert!(!is_newer("3.15.2-rc.1", "3.15.2-rc.2"));
+    fn is_newer_orders_prereleases_of_the_same_triple() {
+        // The beta channel walks a prerelease line, so identifiers are
+        // compared per semver §11 rather than treated as equal.
+        assert!(is_newer("3.15.2-rc.1", "3.15.2-rc.2"));
+        assert!(!is_newer("3.15.2-rc.2", "3.15.2-rc.1"));
+        assert!(is_newer("3.15.2-rc.9", "3.15.2-rc.10"));
+        assert!(is_newer("3.15.2-beta.2", "3.15.2-rc.1"));
+        assert!(is_newer("3.15.2-rc", "3.15.2-rc.1"));
+        assert!(is_newer("3.15.2-1", "3.15.2-alpha"));
+        assert!(!is_newer("3.15.2-rc.1", "3.15.2-rc.1"));
+        // A prerelease of the running stable triple is not an update.
+        assert!(!is_newer("3.15.2", "3.15.2-rc.1"));
+    }
+
+    #[test]
+    fn beta_channel_takes_the_newest_release_without_going_back() {
+        let list = |tags: &[(&str, bool, bool)]| -> Vec<serde_json::Value> {
+            tags.iter()
+                .map(|(tag, prerelease, draft)| {
+                    serde_json::json!({"tag_name": tag, "prerelease": prerelease, "draft": draft})
+                })
+                .collect()
+        };
+        let releases = list(&[
+            ("v4.3.0-rc.2", true, false),
+            ("v4.3.0-rc.1", true, false),
+            ("v4.2.2", false, false),
+        ]);
+        assert_eq!(
+            newest_release_version(&releases).as_deref(),
+            Some("4.3.0-rc.2")
+        );
+
+        // Once the stable release ships it outranks its own prereleases.
+        let releases = list(&[
+            ("v4.3.0", false, false),
+            ("v4.3.0-rc.2", true, false),
+            ("v4.4.0-rc.1", true, true),
+            ("nightly", true, false),
+        ]);
+        assert_eq!(newest_release_version(&releases).as_deref(), Some("4.3.0"));
+        assert_eq!(newest_release_version(&[]), None);
     }
 
     #[test]
@@ -3376,16 +5426,33 @@ TeamIdentifier=M9D5379H93\n";
 
     #[test]
     fn elevated_worker_accepts_only_plain_three_part_release_versions() {
-        assert!(is_worker_release_version("4.1.3"));
-        assert!(is_worker_release_version("10.20.300"));
-        assert!(!is_worker_release_version("4.1"));
-        assert!(!is_worker_release_version("v4.1.3"));
-        assert!(!is_worker_release_version("4.1.3-rc.1"));
-        assert!(!is_worker_release_version(
+        assert!(is_plain_release_version("4.1.3"));
+        assert!(is_plain_release_version("10.20.300"));
+        assert!(!is_plain_release_version("4.1"));
+        assert!(!is_plain_release_version("v4.1.3"));
+        assert!(!is_plain_release_version("4.1.3-rc.1"));
+        assert!(!is_plain_release_version(
             "4.1.3 --update-strategy exe_global"
         ));
     }
 
+    #[test]
+    fn elevated_worker_accepts_beta_prereleases_but_nothing_looser() {
+        assert!(is_release_version("4.1.3"));
+        assert!(is_release_version("4.1.3-rc.1"));
+        assert!(is_release_version("4.1.3-beta"));
+        for bad in [
+            "4.1.3-",
+            "4.1.3-rc..1",
+            "4.1.3+sha.1",
+            "4.1-rc.1",
+            "4.1.3-rc.1 --update-strategy exe_global",
+            "4.1.3-rc\"",
+        ] {
+            assert!(!is_release_version(bad), "{bad}");
+        }
+    }
+
     #[test]
     fn elevated_worker_quotes_windows_paths_without_changing_argument_boundaries() {
         assert_eq!(
@@ -69,6 +69,126 @@ fn test_json_flag() {
         .stdout(predicate::str::contains("\"memory\":"));
 }
 
+#[test]
+fn test_html_format_prints_a_standalone_page() {
+    tr300()
+        .args(["--format", "html", "--fast"])
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("<!DOCTYPE html>"))
+        .stdout(predicate::str::contains("<h2>Memory</h2>"))
+        .stdout(predicate::str::ends_with("</html>\n"));
+
+    tr300()
+        .args(["--format", "pdf"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown output format"));
+}
+
+#[test]
+fn test_flat_formats_share_the_json_fields() {
+    tr300()
+        .args(["--format", "env", "--fast"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("TR300_SCHEMA_VERSION=1\n"))
+        .stdout(predicate::str::contains("TR300_CPU_CORES="));
+
+    let output = tr300()
+        .args(["--format", "csv", "--fast"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let csv = String::from_utf8(output).expect("utf-8 csv");
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 2, "csv should be a header and one row");
+    assert!(lines[0].contains(",memory.total_bytes,"));
+
+    tr300()
+        .args(["--format", "yaml", "--fast"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("schema_version: 1\n"))
+        .stdout(predicate::str::contains("\ncpu:\n"))
+        .stdout(predicate::str::contains("\n  cores: "));
+}
+
+#[test]
+fn test_get_prints_a_single_value() {
+    let output = tr300()
+        .args(["--get", "memory.total_bytes", "--fast"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let value = String::from_utf8(output).expect("utf-8");
+    assert!(
+        value.trim().parse::<u64>().is_ok_and(|bytes| bytes > 0),
+        "expected a byte count, got {value:?}"
+    );
+
+    tr300()
+        .args(["--get", "memory.no_such_field"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no field `memory.no_such_field`"));
+}
+
+#[test]
+fn test_top_env_var_is_ignored_in_fast_mode() {
+    let output = tr300()
+        .args(["--json", "--fields", "cpu"])
+        .env("TR300_TOP", "2")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("valid JSON");
+    let processes = json["processes"].as_array().expect("processes array");
+    let cpu_ranked = processes.iter().filter(|p| p["cpu_rank"].is_u64()).count();
+    assert_eq!(cpu_ranked, 2);
+
+    let output = tr300()
+        .args(["--json", "--fields", "cpu", "--fast"])
+        .env("TR300_TOP", "2")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("valid JSON");
+    assert_eq!(json["processes"], serde_json::json!([]));
+}
+
+#[test]
+fn test_fields_limits_json_sections() {
+    let output = tr300()
+        .args(["--fields", "memory", "--json", "--fast"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let json: Value = serde_json::from_slice(&output).expect("valid JSON");
+    assert!(json["memory"]["total_bytes"].is_u64());
+    assert!(json.get("cpu").is_none() && json.get("os").is_none());
+    assert!(json["memory"].get("ram_slots").is_none());
+    assert_eq!(json["schema_version"], 1);
+}
+
+#[test]
+fn test_get_collects_platform_fields() {
+    tr300()
+        .args(["--get", "memory.ram_slots", "--fast"])
+        .assert()
+        .success();
+}
+
 #[test]
 fn test_json_output_parses() {
     let output = tr300()
@@ -204,7 +324,7 @@ fn test_help_documents_positional_actions() {
         .success()
         .stdout(predicate::str::contains("[ACTION]"))
         .stdout(predicate::str::contains(
-            "[possible values: update, install, uninstall]",
+            "[possible values: update, install, uninstall, check, assert]",
         ));
 }
 
@@ -233,3 +353,56 @@ fn test_json_includes_session_uptime_seconds_key() {
         .success()
         .stdout(predicate::str::contains("\"session_uptime_seconds\":"));
 }
+
+#[test]
+fn test_assert_evaluates_a_saved_report_and_writes_junit() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let report = tr300()
+        .args(["--json", "--fast"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+    let report_path = dir.path().join("report.json");
+    std::fs::write(&report_path, report).expect("write report");
+
+    let passing = dir.path().join("pass.toml");
+    std::fs::write(&passing, "[hardware]\nmin_cores = 1\n").expect("write policy");
+    tr300()
+        .arg("assert")
+        .arg(&passing)
+        .arg("--input")
+        .arg(&report_path)
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("PASS   hardware.min_cores"));
+
+    let failing = dir.path().join("fail.toml");
+    std::fs::write(&failing, "[hardware]\nmin_cores = 100000\n").expect("write policy");
+    let junit = dir.path().join("junit.xml");
+    tr300()
+        .arg("assert")
+        .arg(&failing)
+        .arg("--input")
+        .arg(&report_path)
+        .arg("--junit")
+        .arg(&junit)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("0 of 1 assertions passed"));
+    let xml = std::fs::read_to_string(&junit).expect("junit written");
+    assert!(xml.contains("failures=\"1\""));
+
+    // Policy errors are distinct from failed assertions.
+    let typo = dir.path().join("typo.toml");
+    std::fs::write(&typo, "[hardware]\nmin_core = 1\n").expect("write policy");
+    tr300()
+        .arg("assert")
+        .arg(&typo)
+        .arg("--input")
+        .arg(&report_path)
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("min_core"));
+}